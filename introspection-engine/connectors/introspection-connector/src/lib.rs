@@ -42,21 +42,23 @@ pub struct IntrospectionResult {
     /// Datamodel
     pub data_model: Datamodel,
     /// warnings
-    pub warnings: Vec<Warning>,
+    pub warnings: Vec<IntrospectionWarning>,
     /// version
     pub version: Version,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Warning {
+/// A warning emitted during introspection. The `code` identifies the kind of warning, and
+/// `affected` lists the models, fields or enums it applies to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IntrospectionWarning {
     pub code: i8,
     pub message: String,
     pub affected: Value,
 }
 
-impl Warning {
+impl IntrospectionWarning {
     pub fn new_datamodel_parsing() -> Self {
-        Warning {
+        IntrospectionWarning {
             code: 0,
             message:
             "The input datamodel could not be parsed. This means it was not used to enrich the introspected datamodel with previous manual changes."
@@ -71,7 +73,7 @@ pub struct IntrospectionResultOutput {
     /// Datamodel
     pub datamodel: String,
     /// warnings
-    pub warnings: Vec<Warning>,
+    pub warnings: Vec<IntrospectionWarning>,
     /// version
     pub version: Version,
 }
@@ -6,26 +6,57 @@ use crate::sanitize_datamodel_names::sanitize_datamodel_names;
 use crate::version_checker::VersionChecker;
 use crate::SqlIntrospectionResult;
 use datamodel::Datamodel;
-use introspection_connector::{IntrospectionResult, Warning};
+use introspection_connector::{IntrospectionResult, IntrospectionWarning};
 use quaint::connector::SqlFamily;
 use sql_schema_describer::*;
 use tracing::debug;
 
+/// Settings for the data model calculation.
+#[derive(Debug, Clone, Default)]
+pub struct IntrospectionOptions {
+    /// Prepended to the names of the relations derived from foreign keys, e.g. to avoid clashes with
+    /// existing models when the introspected data model is merged into another schema. Many-to-many
+    /// relations keep their names, because those name the join table.
+    pub relation_name_prefix: Option<String>,
+    /// Introspect Postgres `hstore` columns as `Json` fields instead of `String` fields. The fields
+    /// can be read, but writes must still use the hstore text format.
+    pub hstore_as_json: bool,
+}
+
+impl IntrospectionOptions {
+    pub(crate) fn relation_name(&self, name: String) -> String {
+        match &self.relation_name_prefix {
+            Some(prefix) => format!("{}{}", prefix, name),
+            None => name,
+        }
+    }
+}
+
 /// Calculate a data model from a database schema.
 pub fn calculate_datamodel(schema: &SqlSchema, family: &SqlFamily) -> SqlIntrospectionResult<IntrospectionResult> {
+    calculate_datamodel_with_options(schema, family, &IntrospectionOptions::default())
+}
+
+/// Calculate a data model from a database schema, with the given options.
+pub fn calculate_datamodel_with_options(
+    schema: &SqlSchema,
+    family: &SqlFamily,
+    options: &IntrospectionOptions,
+) -> SqlIntrospectionResult<IntrospectionResult> {
     debug!("Calculating data model.");
 
     let mut version_check = VersionChecker::new(family.clone(), schema);
     let mut data_model = Datamodel::new();
+    let mut warnings: Vec<IntrospectionWarning> = Vec::new();
 
     // 1to1 translation of the sql schema
-    introspect(schema, &mut version_check, &mut data_model)?;
+    introspect(schema, options, &mut version_check, &mut data_model, &mut warnings)?;
 
     // our opinionation about valid names
     sanitize_datamodel_names(&mut data_model);
 
     // commenting out models, fields, enums, enum values
-    let mut warnings: Vec<Warning> = commenting_out_guardrails(&mut data_model);
+    warnings.append(&mut commenting_out_guardrails(&mut data_model));
 
     // deduplicating relation field names
     deduplicate_relation_field_names(&mut data_model);
@@ -3,9 +3,9 @@ use crate::warnings::{
     warning_unsupported_types, EnumAndValue, Model, ModelAndField, ModelAndFieldAndType,
 };
 use datamodel::{Datamodel, FieldType};
-use introspection_connector::Warning;
+use introspection_connector::IntrospectionWarning;
 
-pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
+pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<IntrospectionWarning> {
     let mut models_without_identifiers = vec![];
     let mut fields_with_empty_names = vec![];
     let mut enum_values_with_empty_names = vec![];
@@ -77,10 +77,12 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
     for model in datamodel.models_mut() {
         if model.strict_unique_criterias().is_empty() {
             model.is_commented_out = true;
-            model.documentation = Some(
-                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
-                    .to_string(),
-            );
+            let comment = "The underlying table does not contain a valid unique identifier and can therefore currently not be handled.";
+            // Views keep their marker and tables the documentation of their indexes.
+            model.documentation = Some(match model.documentation.take() {
+                Some(documentation) => format!("{}\n{}", documentation, comment),
+                None => comment.to_string(),
+            });
             models_without_identifiers.push(Model {
                 model: model.name.clone(),
             })
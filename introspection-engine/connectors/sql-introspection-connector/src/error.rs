@@ -67,8 +67,18 @@ pub enum SqlError {
         cause: QuaintKind,
     },
 
-    #[error("An Error occurred because the schema was inconsistent: '{}'", explanation)]
-    SchemaInconsistent { explanation: String },
+    #[error("An Error occurred because the schema was inconsistent: '{}'", _0)]
+    SchemaInconsistent(SchemaInconsistent),
+}
+
+/// A reference in the described schema that does not resolve.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum SchemaInconsistent {
+    #[error("Table {} not found.", table)]
+    TableNotFound { table: String },
+
+    #[error("Column {} not found in table {}.", column, table)]
+    ColumnNotFound { table: String, column: String },
 }
 
 impl SqlError {
@@ -130,13 +140,17 @@ impl SqlError {
                     },
                 }
             }
-            SqlError::SchemaInconsistent { explanation } => ConnectorError {
-                user_facing_error: KnownError::new(DatabaseSchemaInconsistent {
-                    explanation: explanation.to_owned(),
-                })
-                .ok(),
-                kind: ErrorKind::DatabaseSchemaInconsistent { explanation },
-            },
+            SqlError::SchemaInconsistent(inconsistency) => {
+                let explanation = inconsistency.to_string();
+
+                ConnectorError {
+                    user_facing_error: KnownError::new(DatabaseSchemaInconsistent {
+                        explanation: explanation.clone(),
+                    })
+                    .ok(),
+                    kind: ErrorKind::DatabaseSchemaInconsistent { explanation },
+                }
+            }
             error => ConnectorError::from_kind(ErrorKind::QueryError(error.into())),
         }
     }
@@ -186,6 +200,12 @@ impl From<sql_schema_describer::SqlSchemaDescriberError> for SqlError {
     }
 }
 
+impl From<SchemaInconsistent> for SqlError {
+    fn from(inconsistency: SchemaInconsistent) -> Self {
+        SqlError::SchemaInconsistent(inconsistency)
+    }
+}
+
 impl From<String> for SqlError {
     fn from(error: String) -> Self {
         SqlError::Generic(anyhow::anyhow!(error))
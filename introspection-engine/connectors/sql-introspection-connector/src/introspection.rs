@@ -1,19 +1,32 @@
+use crate::calculate_datamodel::IntrospectionOptions;
 use crate::misc_helpers::{
     calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_migration_table, is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table,
-    is_relay_table,
+    calculate_scalar_field, has_zero_date_default, index_documentation, is_migration_table,
+    is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table, non_default_primary_key_name,
+    VIEW_DOCUMENTATION,
 };
 use crate::version_checker::VersionChecker;
-use crate::SqlError;
+use crate::warnings::{
+    warning_relations_to_non_unique_fields, warning_relations_to_other_schemas, warning_zero_date_defaults,
+    ModelAndField, ModelAndForeignKey,
+};
+use crate::{SchemaInconsistent, SqlError};
 use datamodel::{dml, Datamodel, Field, FieldType, Model};
-use sql_schema_describer::SqlSchema;
+use introspection_connector::IntrospectionWarning;
+use sql_schema_describer::{SqlSchema, Table};
 use tracing::debug;
 
 pub fn introspect(
     schema: &SqlSchema,
+    options: &IntrospectionOptions,
     version_check: &mut VersionChecker,
     data_model: &mut Datamodel,
+    warnings: &mut Vec<IntrospectionWarning>,
 ) -> Result<(), SqlError> {
+    let mut relations_to_non_unique_fields = vec![];
+    let mut relations_to_other_schemas = vec![];
+    let mut zero_date_defaults = vec![];
+
     for table in schema
         .tables
         .iter()
@@ -27,7 +40,12 @@ pub fn introspect(
 
         for column in &table.columns {
             version_check.check_column_for_type_and_default_value(&column);
-            let field = calculate_scalar_field(&table, &column);
+
+            if has_zero_date_default(column) {
+                zero_date_defaults.push(ModelAndField::new(&table.name, &column.name));
+            }
+
+            let field = calculate_scalar_field(schema, &table, &column);
             model.add_field(Field::ScalarField(field));
         }
 
@@ -35,20 +53,64 @@ pub fn introspect(
         let model_copy = model.clone();
         foreign_keys_copy.clear_duplicates();
 
-        for foreign_key in foreign_keys_copy.iter().filter(|fk| {
-            !fk.columns.iter().any(|c| {
-                matches!(
-                    model_copy.find_scalar_field(c).unwrap().field_type,
-                    FieldType::Unsupported(_)
-                )
-            })
-        }) {
+        for foreign_key in foreign_keys_copy.iter() {
+            let fields = foreign_key
+                .columns
+                .iter()
+                .map(|c| {
+                    model_copy
+                        .find_scalar_field(c)
+                        .ok_or_else(|| SchemaInconsistent::ColumnNotFound {
+                            table: table.name.clone(),
+                            column: c.clone(),
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Relations on columns of unsupported types can't be represented, so we only keep the scalar fields.
+            if fields
+                .iter()
+                .any(|field| matches!(field.field_type, FieldType::Unsupported(_)))
+            {
+                continue;
+            }
+
+            // The referenced table is not part of the datamodel, so we only keep the scalar fields.
+            if let Some(referenced_schema) = &foreign_key.referenced_schema {
+                relations_to_other_schemas.push(ModelAndForeignKey {
+                    model: table.name.clone(),
+                    fields: foreign_key.columns.clone(),
+                    referenced_model: format!("{}.{}", referenced_schema, foreign_key.referenced_table),
+                    referenced_fields: foreign_key.referenced_columns.clone(),
+                });
+
+                continue;
+            }
+
+            // A relation to fields that are not unique can't be represented, so we only keep the scalar fields.
+            let references_unique_fields = schema
+                .table(&foreign_key.referenced_table)
+                .map(|referenced_table| referenced_table.columns_are_unique(&foreign_key.referenced_columns))
+                .unwrap_or(true);
+
+            if !references_unique_fields {
+                relations_to_non_unique_fields.push(ModelAndForeignKey {
+                    model: table.name.clone(),
+                    fields: foreign_key.columns.clone(),
+                    referenced_model: foreign_key.referenced_table.clone(),
+                    referenced_fields: foreign_key.referenced_columns.clone(),
+                });
+
+                continue;
+            }
+
             version_check.has_inline_relations(table);
             version_check.uses_on_delete(foreign_key, table);
             model.add_field(Field::RelationField(calculate_relation_field(
                 schema,
                 table,
                 foreign_key,
+                options,
             )?));
         }
 
@@ -60,8 +122,16 @@ pub fn introspect(
             model.add_index(calculate_index(index));
         }
 
+        for documentation in table.indices.iter().filter_map(index_documentation) {
+            model.documentation = Some(match model.documentation.take() {
+                Some(existing) => format!("{}\n{}", existing, documentation),
+                None => documentation,
+            });
+        }
+
         if table.primary_key_columns().len() > 1 {
             model.id_fields = table.primary_key_columns();
+            model.id_database_name = non_default_primary_key_name(table);
         }
 
         version_check.always_has_created_at_updated_at(table, &model);
@@ -70,6 +140,33 @@ pub fn introspect(
         data_model.add_model(model);
     }
 
+    // Views have no keys or indexes, their columns are calculated like those of a bare table.
+    for view in schema.views.iter() {
+        debug!("Calculating model for view: {}", view.name);
+        let mut model = Model::new(view.name.clone(), None);
+        let table = Table {
+            name: view.name.clone(),
+            columns: view.columns.clone(),
+            indices: Vec::new(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
+        };
+
+        for column in &table.columns {
+            let field = calculate_scalar_field(schema, &table, &column);
+            model.add_field(Field::ScalarField(field));
+        }
+
+        model.documentation = Some(VIEW_DOCUMENTATION.to_owned());
+
+        data_model.add_model(model);
+    }
+
     for e in schema.enums.iter() {
         data_model.add_enum(dml::Enum::new(
             &e.name,
@@ -103,7 +200,6 @@ pub fn introspect(
     {
         if let (Some(f), Some(s)) = (table.foreign_keys.get(0), table.foreign_keys.get(1)) {
             let is_self_relation = f.referenced_table == s.referenced_table;
-
             fields_to_be_added.push((
                 s.referenced_table.clone(),
                 calculate_many_to_many_field(f, table.name[1..].to_string(), is_self_relation),
@@ -119,6 +215,18 @@ pub fn introspect(
         data_model.find_model_mut(&model).add_field(Field::RelationField(field));
     }
 
+    if !zero_date_defaults.is_empty() {
+        warnings.push(warning_zero_date_defaults(&zero_date_defaults));
+    }
+
+    if !relations_to_non_unique_fields.is_empty() {
+        warnings.push(warning_relations_to_non_unique_fields(&relations_to_non_unique_fields));
+    }
+
+    if !relations_to_other_schemas.is_empty() {
+        warnings.push(warning_relations_to_other_schemas(&relations_to_other_schemas));
+    }
+
     Ok(())
 }
 
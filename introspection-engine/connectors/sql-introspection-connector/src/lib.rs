@@ -18,6 +18,7 @@ use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
 use std::future::Future;
 use tracing_futures::Instrument;
 
+use crate::calculate_datamodel::IntrospectionOptions;
 use crate::re_introspection::enrich;
 use datamodel::Datamodel;
 pub use error::*;
@@ -27,11 +28,20 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    options: IntrospectionOptions,
 }
 
 impl SqlIntrospectionConnector {
     pub async fn new(url: &str) -> ConnectorResult<SqlIntrospectionConnector> {
-        let (describer, connection_info) = schema_describer_loading::load_describer(&url)
+        Self::new_with_options(url, IntrospectionOptions::default()).await
+    }
+
+    /// Like `new`, but describing the database and calculating the data model with the given options.
+    pub async fn new_with_options(
+        url: &str,
+        options: IntrospectionOptions,
+    ) -> ConnectorResult<SqlIntrospectionConnector> {
+        let (describer, connection_info) = schema_describer_loading::load_describer(&url, &options)
             .instrument(tracing::debug_span!("Loading describer"))
             .await
             .map_err(|error| {
@@ -45,6 +55,7 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            options,
         })
     }
 
@@ -98,8 +109,10 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
 
         let family = self.connection_info.sql_family();
 
-        let mut introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, &family)
-            .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))?;
+        let mut introspection_result =
+            calculate_datamodel::calculate_datamodel_with_options(&sql_schema, &family, &self.options).map_err(
+                |sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info),
+            )?;
 
         tracing::debug!("Calculating datamodel is done: {:?}", sql_schema);
 
@@ -1,13 +1,18 @@
-use crate::SqlError;
+use crate::calculate_datamodel::IntrospectionOptions;
+use crate::{SchemaInconsistent, SqlError};
 use datamodel::{
     Datamodel, DefaultNames, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy,
     RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
 };
+use prisma_value::PrismaValue;
 use sql_schema_describer::{
     Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType, SqlSchema, Table,
 };
 use tracing::debug;
 
+/// The documentation marking the models introspected from views.
+pub(crate) const VIEW_DOCUMENTATION: &str = "@view";
+
 //checks
 pub fn is_migration_table(table: &Table) -> bool {
     table.name == "_Migration"
@@ -109,7 +114,9 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     debug!("Handling index  {:?}", index);
     let tpe = match index.tpe {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
-        IndexType::Normal => datamodel::dml::IndexType::Normal,
+        // Fulltext and spatial indexes have no representation in the datamodel yet, the model documentation
+        // explains what they really are.
+        IndexType::Normal | IndexType::Fulltext | IndexType::Spatial => datamodel::dml::IndexType::Normal,
     };
 
     IndexDefinition {
@@ -119,12 +126,43 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     }
 }
 
-pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> ScalarField {
+/// The name of the primary key constraint of the table, unless it is the name the database picks by
+/// default: `<table>_pkey` on Postgres, and always `PRIMARY` on MySQL.
+pub(crate) fn non_default_primary_key_name(table: &Table) -> Option<String> {
+    let constraint_name = table.primary_key.as_ref()?.constraint_name.as_ref()?;
+
+    if constraint_name == "PRIMARY" || *constraint_name == format!("{}_pkey", table.name) {
+        return None;
+    }
+
+    Some(constraint_name.clone())
+}
+
+pub(crate) fn index_documentation(index: &Index) -> Option<String> {
+    let tpe = match index.tpe {
+        IndexType::Fulltext => "fulltext",
+        IndexType::Spatial => "spatial",
+        IndexType::Unique | IndexType::Normal => return None,
+    };
+
+    Some(format!(
+        "The index {} is a {} index in the database. This is not supported by Prisma yet, it is represented as a normal index.",
+        index.name, tpe
+    ))
+}
+
+pub(crate) fn calculate_scalar_field(schema: &SqlSchema, table: &Table, column: &Column) -> ScalarField {
     debug!("Handling column {:?}", column);
     let field_type = calculate_scalar_field_type(&column);
     let (is_commented_out, documentation) = match field_type {
-        FieldType::Unsupported(_) => (true, Some("This type is currently not supported.".to_string())),
-        _ => (false, None),
+        FieldType::Unsupported(_) => (true, Some(unsupported_type_documentation(schema, column))),
+        _ => (false, native_type_documentation(table, column)),
+    };
+    let documentation = match (documentation, domain_documentation(column)) {
+        (Some(documentation), Some(domain_documentation)) => {
+            Some(format!("{}\n{}", documentation, domain_documentation))
+        }
+        (documentation, domain_documentation) => documentation.or(domain_documentation),
     };
 
     let arity = match column.tpe.arity {
@@ -153,15 +191,97 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> ScalarFi
     }
 }
 
+/// Composite types can't be represented in the datamodel, but we can at least describe their structure.
+fn unsupported_type_documentation(schema: &SqlSchema, column: &Column) -> String {
+    let composite_type = schema.get_composite_type(column.tpe.full_data_type.trim_start_matches('_'));
+
+    match composite_type {
+        Some(composite_type) => {
+            let fields: Vec<String> = composite_type
+                .fields
+                .iter()
+                .map(|field| format!("{} {}", field.name, field.data_type))
+                .collect();
+
+            format!(
+                "This type is currently not supported. It is the composite type {} ({}).",
+                composite_type.name,
+                fields.join(", ")
+            )
+        }
+        None => match column.tpe.srid {
+            Some(srid) => format!(
+                "This type is currently not supported. The column is restricted to the spatial reference system {}.",
+                srid
+            ),
+            None => "This type is currently not supported.".to_string(),
+        },
+    }
+}
+
+const NATIVE_STRING_TYPES: &[&str] = &["inet", "cidr", "macaddr", "xml", "hstore"];
+
+/// Network address, xml and hstore types are introspected as strings and decimals as floats, the comment records the
+/// native type information that is lost when the datamodel is applied to a database again. Unique
+/// `citext` columns are commented too, since `@unique` and `@@unique` don't convey case-insensitivity.
+/// MySQL `set` columns are introspected as strings, so their allowed values are listed.
+fn native_type_documentation(table: &Table, column: &Column) -> Option<String> {
+    let native_type = column.tpe.full_data_type.trim_start_matches('_');
+
+    match column.tpe.family {
+        ColumnTypeFamily::String if native_type == "citext" && is_part_of_unique_index(table, column) => Some(
+            "Uniqueness is case-insensitive for this field, because it was introspected from the native type citext."
+                .to_string(),
+        ),
+        ColumnTypeFamily::Decimal => Some(
+            "This field was introspected from a fixed precision decimal type, its precision is not preserved."
+                .to_string(),
+        ),
+        ColumnTypeFamily::String if column.tpe.data_type == "set" => {
+            let values = sql_schema_describer::mysql::parse_value_list(&column.tpe.full_data_type)?;
+            let values: Vec<String> = values.iter().map(|value| format!("`{}`", value)).collect();
+
+            Some(format!(
+                "This field was introspected from the native type set, with the allowed values {}.",
+                values.join(", ")
+            ))
+        }
+        ColumnTypeFamily::String | ColumnTypeFamily::Json if NATIVE_STRING_TYPES.contains(&native_type) => Some(
+            format!("This field was introspected from the native type {}.", native_type),
+        ),
+        _ => None,
+    }
+}
+
+/// Columns of a domain type are introspected as the base type of the domain, so the check
+/// constraints of the domain would be lost without a comment.
+fn domain_documentation(column: &Column) -> Option<String> {
+    let domain = column.tpe.domain.as_ref().filter(|domain| !domain.checks.is_empty())?;
+
+    Some(format!(
+        "This field was introspected from the domain {}, with the check constraints {}.",
+        domain.name,
+        domain.checks.join(", ")
+    ))
+}
+
+fn is_part_of_unique_index(table: &Table, column: &Column) -> bool {
+    table
+        .indices
+        .iter()
+        .any(|index| index.tpe == IndexType::Unique && index.columns.contains(&column.name))
+}
+
 pub(crate) fn calculate_relation_field(
     schema: &SqlSchema,
     table: &Table,
     foreign_key: &ForeignKey,
+    options: &IntrospectionOptions,
 ) -> Result<RelationField, SqlError> {
     debug!("Handling foreign key  {:?}", foreign_key);
 
     let relation_info = RelationInfo {
-        name: calculate_relation_name(schema, foreign_key, table)?,
+        name: calculate_relation_name(schema, foreign_key, table, options)?,
         fields: foreign_key.columns.clone(),
         to: foreign_key.referenced_table.clone(),
         to_fields: foreign_key.referenced_columns.clone(),
@@ -171,8 +291,17 @@ pub(crate) fn calculate_relation_field(
     let columns: Vec<&Column> = foreign_key
         .columns
         .iter()
-        .map(|c| table.columns.iter().find(|tc| tc.name == *c).unwrap())
-        .collect();
+        .map(|c| {
+            table
+                .columns
+                .iter()
+                .find(|tc| tc.name == *c)
+                .ok_or_else(|| SchemaInconsistent::ColumnNotFound {
+                    table: table.name.clone(),
+                    column: c.clone(),
+                })
+        })
+        .collect::<Result<_, _>>()?;
 
     let arity = match !columns.iter().any(|c| c.is_required()) {
         true => FieldArity::Optional,
@@ -190,9 +319,7 @@ pub(crate) fn calculate_backrelation_field(
     relation_info: &RelationInfo,
 ) -> Result<RelationField, SqlError> {
     match schema.table(&model.name) {
-        Err(table_name) => Err(SqlError::SchemaInconsistent {
-            explanation: format!("Table {} not found.", table_name),
-        }),
+        Err(table_name) => Err(SchemaInconsistent::TableNotFound { table: table_name }.into()),
         Ok(table) => {
             let new_relation_info = RelationInfo {
                 name: relation_info.name.clone(),
@@ -233,17 +360,61 @@ pub(crate) fn calculate_backrelation_field(
 
 pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldArity) -> Option<DMLDef> {
     match (&column.default, &column.tpe.family) {
+        (Some(SQLDef::LIST(values)), _) if *arity == FieldArity::List => {
+            Some(DMLDef::Single(PrismaValue::List(values.clone())))
+        }
         (_, _) if *arity == FieldArity::List => None,
-        (_, ColumnTypeFamily::Int) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
-        (_, ColumnTypeFamily::Int) if is_sequence(column, table) => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (_, ColumnTypeFamily::Int) | (_, ColumnTypeFamily::BigInt) if column.auto_increment => {
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
+        (_, ColumnTypeFamily::Int) | (_, ColumnTypeFamily::BigInt) if is_sequence(column, table) => {
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
         (Some(SQLDef::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (Some(SQLDef::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
+        (Some(SQLDef::DBGENERATED(expr)), _) if is_computed_default(expr) => {
+            Some(DMLDef::Expression(VG::new_dbgenerated_expression(expr.clone())))
+        }
         (Some(SQLDef::DBGENERATED(_)), _) => Some(DMLDef::Expression(VG::new_dbgenerated())),
         (Some(SQLDef::VALUE(val)), _) => Some(DMLDef::Single(val.clone())),
         _ => None,
     }
 }
 
+/// Defaults computed from the session by the database, like `CURRENT_USER`. Their expression can be
+/// kept in the datamodel, as it can be rendered back as-is.
+fn is_computed_default(expr: &str) -> bool {
+    const COMPUTED_DEFAULTS: &[&str] = &[
+        "current_user",
+        "session_user",
+        "user",
+        "current_role",
+        "current_schema",
+        "current_catalog",
+    ];
+
+    let expr = expr
+        .split("::")
+        .next()
+        .unwrap_or(expr)
+        .trim_matches(|c| c == '(' || c == ')');
+
+    COMPUTED_DEFAULTS
+        .iter()
+        .any(|computed_default| expr.eq_ignore_ascii_case(computed_default))
+}
+
+/// MySQL accepts `0000-00-00` dates as defaults in some SQL modes. They are kept as database
+/// generated defaults by the describer, since they can't be parsed.
+pub(crate) fn has_zero_date_default(column: &Column) -> bool {
+    match (&column.default, &column.tpe.family) {
+        (Some(SQLDef::DBGENERATED(default)), ColumnTypeFamily::DateTime) => {
+            default.trim_matches('\'').starts_with("0000-00-00")
+        }
+        _ => false,
+    }
+}
+
 pub(crate) fn is_id(column: &Column, table: &Table) -> bool {
     table
         .primary_key
@@ -260,7 +431,12 @@ pub(crate) fn is_sequence(column: &Column, table: &Table) -> bool {
         .unwrap_or(false)
 }
 
-pub(crate) fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -> Result<String, SqlError> {
+pub(crate) fn calculate_relation_name(
+    schema: &SqlSchema,
+    fk: &ForeignKey,
+    table: &Table,
+    options: &IntrospectionOptions,
+) -> Result<String, SqlError> {
     //this is not called for prisma many to many relations. for them the name is just the name of the join table.
     let referenced_model = &fk.referenced_table;
     let model_with_fk = &table.name;
@@ -273,9 +449,7 @@ pub(crate) fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table
         .collect();
 
     match schema.table(referenced_model) {
-        Err(table_name) => Err(SqlError::SchemaInconsistent {
-            explanation: format!("Table {} not found.", table_name),
-        }),
+        Err(table_name) => Err(SchemaInconsistent::TableNotFound { table: table_name }.into()),
         Ok(other_table) => {
             let fk_from_other_model_to_this: Vec<&ForeignKey> = other_table
                 .foreign_keys
@@ -289,7 +463,7 @@ pub(crate) fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table
                 DefaultNames::name_for_ambiguous_relation(model_with_fk, referenced_model, &fk_column_name)
             };
 
-            Ok(name)
+            Ok(options.relation_name(name))
         }
     }
 }
@@ -301,17 +475,23 @@ pub(crate) fn calculate_scalar_field_type(column: &Column) -> FieldType {
         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
+        // There is no native Decimal scalar yet, the precision loss is documented on the field.
+        ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Float, None),
         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
+        ColumnTypeFamily::BigInt => FieldType::Base(ScalarType::BigInt, None),
         ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
         ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
+        ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes, None),
         x => FieldType::Unsupported(x.to_string()),
     }
 }
 
 // misc
 
+/// Relation fields named like another field of their model are suffixed with their relation name.
+/// Relation fields have no column to `@map`, so the original name is kept in the field documentation.
 pub fn deduplicate_relation_field_names(datamodel: &mut Datamodel) {
     let mut duplicated_relation_fields = vec![];
 
@@ -332,7 +512,16 @@ pub fn deduplicate_relation_field_names(datamodel: &mut Datamodel) {
         .for_each(|(model, field, relation_name)| {
             let mut field = datamodel.find_model_mut(model).find_relation_field_mut(field);
             //todo self vs normal relation?
+            let renaming = format!(
+                "This relation field was renamed from {}, because the model has another field with that name.",
+                field.name
+            );
+
             field.name = format!("{}_{}", field.name, &relation_name);
+            field.documentation = Some(match field.documentation.take() {
+                Some(documentation) => format!("{}\n{}", documentation, renaming),
+                None => renaming,
+            });
         });
 }
 /// Returns whether the elements of the two slices match, regardless of ordering.
@@ -1,6 +1,6 @@
 use crate::warnings::{warning_default_cuid_warning, warning_default_uuid_warning, ModelAndField};
 use datamodel::{dml, Datamodel, ValueGenerator};
-use introspection_connector::{Version, Warning};
+use introspection_connector::{IntrospectionWarning, Version};
 use quaint::connector::SqlFamily;
 use sql_schema_describer::SqlSchema;
 
@@ -15,7 +15,7 @@ pub fn add_prisma_1_id_defaults(
     version: &Version,
     data_model: &mut Datamodel,
     schema: &SqlSchema,
-    warnings: &mut Vec<Warning>,
+    warnings: &mut Vec<IntrospectionWarning>,
 ) {
     let mut needs_to_be_changed = vec![];
 
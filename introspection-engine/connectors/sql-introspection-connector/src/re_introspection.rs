@@ -319,6 +319,18 @@ pub fn enrich(old_data_model: &Datamodel, introspection_result: &mut Introspecti
         re_order_putting_new_ones_last(enum_a_idx, enum_b_idx)
     });
 
+    // restore old field order within the models that existed before
+    for model in new_data_model.models_mut() {
+        if let Some(old_model) = old_data_model.find_model(&model.name) {
+            model.fields.sort_by(|field_a, field_b| {
+                let field_a_idx = old_model.fields().position(|field| field.name() == field_a.name());
+                let field_b_idx = old_model.fields().position(|field| field.name() == field_b.name());
+
+                re_order_putting_new_ones_last(field_a_idx, field_b_idx)
+            });
+        }
+    }
+
     // println!("{:#?}", new_data_model);
 
     //warnings
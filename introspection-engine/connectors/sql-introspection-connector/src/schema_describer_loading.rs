@@ -1,3 +1,4 @@
+use crate::calculate_datamodel::IntrospectionOptions;
 use crate::SqlError;
 use quaint::error::ErrorKind;
 use quaint::{
@@ -10,7 +11,10 @@ use std::time::Duration;
 
 const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
 
-pub async fn load_describer(url: &str) -> Result<(Box<dyn SqlSchemaDescriberBackend>, ConnectionInfo), SqlError> {
+pub async fn load_describer(
+    url: &str,
+    options: &IntrospectionOptions,
+) -> Result<(Box<dyn SqlSchemaDescriberBackend>, ConnectionInfo), SqlError> {
     let wrapper_fut = async {
         let connection = Quaint::new(&url).await?;
         connection.query_raw("SELECT 1", &[]).await?;
@@ -25,9 +29,10 @@ pub async fn load_describer(url: &str) -> Result<(Box<dyn SqlSchemaDescriberBack
     let connection_info = wrapper.connection_info().to_owned();
 
     let describer: Box<dyn SqlSchemaDescriberBackend> = match connection_info.sql_family() {
-        SqlFamily::Postgres => Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(Arc::new(
-            wrapper,
-        ))),
+        SqlFamily::Postgres => Box::new(
+            sql_schema_describer::postgres::SqlSchemaDescriber::new(Arc::new(wrapper))
+                .with_hstore_as_json(options.hstore_as_json),
+        ),
         SqlFamily::Mysql => Box::new(sql_schema_describer::mysql::SqlSchemaDescriber::new(Arc::new(wrapper))),
         SqlFamily::Sqlite => Box::new(sql_schema_describer::sqlite::SqlSchemaDescriber::new(Arc::new(wrapper))),
         SqlFamily::Mssql => todo!("Greetings from Redmond"),
@@ -3,7 +3,7 @@ use crate::misc_helpers::{
     is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use datamodel::{Datamodel, Model};
-use introspection_connector::{Version, Warning};
+use introspection_connector::{IntrospectionWarning, Version};
 use quaint::connector::SqlFamily;
 use sql_schema_describer::{Column, ForeignKey, ForeignKeyAction, PrimaryKey, SqlSchema, Table};
 use tracing::debug;
@@ -151,7 +151,7 @@ impl VersionChecker {
         }
     }
 
-    fn is_prisma_2(&self, warnings: &Vec<Warning>) -> bool {
+    fn is_prisma_2(&self, warnings: &Vec<IntrospectionWarning>) -> bool {
         !self.has_relay_table
             && !self.uses_on_delete
             && !self.uses_non_prisma_types
@@ -159,7 +159,7 @@ impl VersionChecker {
             && warnings.is_empty()
     }
 
-    fn is_prisma_1_1(&self, warnings: &Vec<Warning>) -> bool {
+    fn is_prisma_1_1(&self, warnings: &Vec<IntrospectionWarning>) -> bool {
         !self.has_migration_table
             && !self.has_relay_table
             && !self.uses_on_delete
@@ -170,7 +170,7 @@ impl VersionChecker {
             && warnings.is_empty()
     }
 
-    fn is_prisma_1(&self, warnings: &Vec<Warning>) -> bool {
+    fn is_prisma_1(&self, warnings: &Vec<IntrospectionWarning>) -> bool {
         !self.has_migration_table
             && !self.uses_on_delete
             && !self.uses_default_values
@@ -183,7 +183,7 @@ impl VersionChecker {
             && warnings.is_empty()
     }
 
-    pub fn version(&self, warnings: &Vec<Warning>, data_model: &Datamodel) -> Version {
+    pub fn version(&self, warnings: &Vec<IntrospectionWarning>, data_model: &Datamodel) -> Version {
         debug!("{:?}", &self);
         match self.sql_family {
             _ if data_model.is_empty() => Version::NonPrisma,
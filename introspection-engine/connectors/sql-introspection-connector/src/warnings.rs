@@ -1,4 +1,4 @@
-use introspection_connector::Warning;
+use introspection_connector::IntrospectionWarning;
 use serde::Serialize;
 
 #[derive(Serialize, Debug, Clone)]
@@ -62,16 +62,24 @@ impl EnumAndValue {
     }
 }
 
-pub fn warning_models_without_identifier(affected: &Vec<Model>) -> Warning {
-    Warning {
+#[derive(Serialize, Debug, Clone)]
+pub struct ModelAndForeignKey {
+    pub(crate) model: String,
+    pub(crate) fields: Vec<String>,
+    pub(crate) referenced_model: String,
+    pub(crate) referenced_fields: Vec<String>,
+}
+
+pub fn warning_models_without_identifier(affected: &Vec<Model>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 1,
         message: "The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by Prisma.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
 
-pub fn warning_fields_with_empty_names(affected: &Vec<ModelAndField>) -> Warning {
-    Warning {
+pub fn warning_fields_with_empty_names(affected: &Vec<ModelAndField>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 2,
         message: "These fields were commented out because their names are currently not supported by Prisma. Please provide valid ones that match [a-zA-Z][a-zA-Z0-9_]* using the `@map` directive."
             .into(),
@@ -79,16 +87,16 @@ pub fn warning_fields_with_empty_names(affected: &Vec<ModelAndField>) -> Warning
     }
 }
 
-pub fn warning_unsupported_types(affected: &Vec<ModelAndFieldAndType>) -> Warning {
-    Warning {
+pub fn warning_unsupported_types(affected: &Vec<ModelAndFieldAndType>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 3,
         message: "These fields were commented out because Prisma currently does not support their types.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
 
-pub fn warning_enum_values_with_empty_names(affected: &Vec<EnumAndValue>) -> Warning {
-    Warning {
+pub fn warning_enum_values_with_empty_names(affected: &Vec<EnumAndValue>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 4,
         message: "These enum values were commented out because their names are currently not supported by Prisma. Please provide valid ones that match [a-zA-Z][a-zA-Z0-9_]* using the `@map` directive."
             .into(),
@@ -96,8 +104,8 @@ pub fn warning_enum_values_with_empty_names(affected: &Vec<EnumAndValue>) -> War
     }
 }
 
-pub fn warning_default_cuid_warning(affected: &Vec<ModelAndField>) -> Warning {
-    Warning {
+pub fn warning_default_cuid_warning(affected: &Vec<ModelAndField>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 5,
         message:
             "These id fields had a `@default(cuid())` added because we believe the schema was created by Prisma 1."
@@ -106,8 +114,8 @@ pub fn warning_default_cuid_warning(affected: &Vec<ModelAndField>) -> Warning {
     }
 }
 
-pub fn warning_default_uuid_warning(affected: &Vec<ModelAndField>) -> Warning {
-    Warning {
+pub fn warning_default_uuid_warning(affected: &Vec<ModelAndField>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 6,
         message:
             "These id fields had a `@default(uuid())` added because we believe the schema was created by Prisma 1."
@@ -116,35 +124,59 @@ pub fn warning_default_uuid_warning(affected: &Vec<ModelAndField>) -> Warning {
     }
 }
 
-pub fn warning_enriched_with_map_on_model(affected: &Vec<Model>) -> Warning {
-    Warning {
+pub fn warning_enriched_with_map_on_model(affected: &Vec<Model>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 7,
         message: "These models were enriched with `@@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
 
-pub fn warning_enriched_with_map_on_field(affected: &Vec<ModelAndField>) -> Warning {
-    Warning {
+pub fn warning_enriched_with_map_on_field(affected: &Vec<ModelAndField>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 8,
         message: "These fields were enriched with `@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
 
-pub fn warning_enriched_with_map_on_enum(affected: &Vec<Enum>) -> Warning {
-    Warning {
+pub fn warning_enriched_with_map_on_enum(affected: &Vec<Enum>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 9,
         message: "These enums were enriched with `@@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
 
-pub fn warning_enriched_with_map_on_enum_value(affected: &Vec<EnumAndValue>) -> Warning {
-    Warning {
+pub fn warning_enriched_with_map_on_enum_value(affected: &Vec<EnumAndValue>) -> IntrospectionWarning {
+    IntrospectionWarning {
         code: 10,
         message: "These enum values were enriched with `@map` information taken from the previous Prisma schema."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
+
+pub fn warning_zero_date_defaults(affected: &Vec<ModelAndField>) -> IntrospectionWarning {
+    IntrospectionWarning {
+        code: 12,
+        message: "These fields have a zero date (`0000-00-00`) default value, which is not a valid datetime. The default was introspected as dbgenerated().".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_relations_to_non_unique_fields(affected: &Vec<ModelAndForeignKey>) -> IntrospectionWarning {
+    IntrospectionWarning {
+        code: 11,
+        message: "These foreign keys were not turned into relations because they reference fields that are not covered by a unique constraint or the primary key. The scalar fields were kept.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_relations_to_other_schemas(affected: &Vec<ModelAndForeignKey>) -> IntrospectionWarning {
+    IntrospectionWarning {
+        code: 13,
+        message: "These foreign keys were not turned into relations because they reference tables in another schema. The scalar fields were kept.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
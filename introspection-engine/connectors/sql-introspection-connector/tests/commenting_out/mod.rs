@@ -85,6 +85,24 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out_mysql(api:
     assert_eq!(&result, dm);
 }
 
+#[test_each_connector(tags("mysql"))]
+async fn commenting_out_a_table_keeps_the_documentation_of_its_indexes(api: &TestApi) {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::integer());
+                t.add_column("body", types::text());
+                t.inject_custom("FULLTEXT INDEX body_idx (body)");
+            });
+        })
+        .await;
+
+    let documentation = "// The index body_idx is a fulltext index in the database. This is not supported by Prisma yet, it is represented as a normal index.\n// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {";
+
+    let result = dbg!(api.introspect().await);
+    assert!(result.contains(documentation), "{}", result);
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn introspecting_an_enum_with_an_invalid_value_should_work(api: &TestApi) {
     let sql = format!("CREATE Type status as ENUM ( '1', 'UNDEFINED')");
@@ -107,6 +125,31 @@ async fn introspecting_an_enum_with_an_invalid_value_should_work(api: &TestApi)
     assert_eq!(&result, "model News {\n  id     Int    @default(autoincrement()) @id\n  status status @default(UNDEFINED)\n}\n\nenum status {\n  // 1 @map(\"1\")\n  UNDEFINED\n}\n");
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn introspection_warnings_for_invalid_enum_values_should_be_typed(api: &TestApi) {
+    let sql = format!("CREATE Type status as ENUM ( '1', 'UNDEFINED')");
+
+    api.database().execute_raw(&sql, &[]).await.unwrap();
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("News", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("status  status Not Null default 'UNDEFINED'");
+            });
+        })
+        .await;
+
+    let warnings = dbg!(api.typed_introspection_warnings().await);
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].code, 4);
+    assert_eq!(
+        warnings[0].affected,
+        serde_json::json!([{ "enm": "status", "value": "1" }])
+    );
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn introspecting_an_enum_with_an_invalid_value_as_default_should_work(api: &TestApi) {
     let sql = format!("CREATE Type status as ENUM ( '1', 'UNDEFINED')");
@@ -137,10 +180,10 @@ async fn introspecting_an_unsupported_type_should_and_commenting_it_out_should_a
             migration.create_table("Test", |t| {
                 t.add_column("id", types::integer().unique(true));
                 t.add_column("dummy", types::integer());
-                t.inject_custom("network_mac  macaddr");
-                t.add_index("unique", types::index(vec!["network_mac", "dummy"]).unique(true));
-                t.add_index("non_unique", types::index(vec!["network_mac", "dummy"]).unique(false));
-                t.inject_custom("Primary Key (\"network_mac\", \"dummy\")");
+                t.inject_custom("time_period  tsrange");
+                t.add_index("unique", types::index(vec!["time_period", "dummy"]).unique(true));
+                t.add_index("non_unique", types::index(vec!["time_period", "dummy"]).unique(false));
+                t.inject_custom("Primary Key (\"time_period\", \"dummy\")");
             });
         })
         .await;
@@ -148,11 +191,11 @@ async fn introspecting_an_unsupported_type_should_and_commenting_it_out_should_a
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"time_period\",\"tpe\":\"tsrange\"}]}]"
     );
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "model Test {\n  id             Int     @unique\n  dummy          Int\n  // This type is currently not supported.\n  // network_mac macaddr\n}\n");
+    assert_eq!(&result, "model Test {\n  id             Int     @unique\n  dummy          Int\n  // This type is currently not supported.\n  // time_period tsrange\n}\n");
 }
 
 #[test_each_connector(tags("postgres"))]
@@ -162,7 +205,7 @@ async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_i
         .execute(|migration| {
             migration.create_table("Test", |t| {
                 t.add_column("dummy", types::integer());
-                t.inject_custom("network_mac  macaddr Primary Key");
+                t.inject_custom("time_period  tsrange Primary Key");
             });
         })
         .await;
@@ -170,11 +213,11 @@ async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_i
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":1,\"message\":\"The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by Prisma.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":1,\"message\":\"The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by Prisma.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"time_period\",\"tpe\":\"tsrange\"}]}]"
     );
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Test {\n  // dummy       Int\n  // This type is currently not supported.\n  // network_mac macaddr @id\n// }\n");
+    assert_eq!(&result, "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Test {\n  // dummy       Int\n  // This type is currently not supported.\n  // time_period tsrange @id\n// }\n");
 }
 
 #[test_each_connector(tags("postgres"))]
@@ -185,7 +228,7 @@ async fn introspecting_an_unsupported_type_should_comment_it_out(api: &TestApi)
             migration.create_table("Test", |t| {
                 t.add_column("id", types::primary());
                 t.inject_custom("network_inet inet");
-                t.inject_custom("network_mac  macaddr");
+                t.inject_custom("time_period  tsrange");
             });
         })
         .await;
@@ -193,11 +236,11 @@ async fn introspecting_an_unsupported_type_should_comment_it_out(api: &TestApi)
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"time_period\",\"tpe\":\"tsrange\"}]}]"
     );
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "model Test {\n  id             Int      @default(autoincrement()) @id\n  network_inet   String?\n  // This type is currently not supported.\n  // network_mac macaddr?\n}\n");
+    assert_eq!(&result, "model Test {\n  id             Int      @default(autoincrement()) @id\n  /// This field was introspected from the native type inet.\n  network_inet   String?\n  // This type is currently not supported.\n  // time_period tsrange?\n}\n");
 }
 
 #[test_each_connector(tags("postgres"))]
@@ -242,3 +285,36 @@ async fn remapping_field_names_to_empty_should_comment_them_out(api: &TestApi) {
 //     let result = dbg!(api.introspect().await);
 //     assert_eq!(&result, "model Post {\n  id     Int   @default(autoincrement()) @id\n  user_1 Int?\n  User   User? @relation(fields: [user_1], references: [1])\n}\n\nmodel User {\n  id   Int    @default(autoincrement()) @id\n  // This field was commented out because of an invalid name. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*\n  // 1 Int    @map(\"1\") @unique\n  Post Post[]\n}\n");
 // }
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_composite_type_column_should_describe_its_fields(api: &TestApi) {
+    api.database()
+        .execute_raw(
+            &format!(
+                "CREATE TYPE \"{}\".\"address\" AS (street text, zip integer);",
+                api.schema_name()
+            ),
+            &[],
+        )
+        .await
+        .unwrap();
+
+    let location_column = format!("location \"{}\".\"address\" NOT NULL", api.schema_name());
+
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(move |migration| {
+            let location_column = location_column.clone();
+
+            migration.create_table("Test", move |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom(location_column.as_str());
+            });
+        })
+        .await;
+
+    let result = dbg!(api.introspect().await);
+    assert!(result.contains(
+        "// This type is currently not supported. It is the composite type address (street text, zip integer).\n  // location address\n"
+    ));
+}
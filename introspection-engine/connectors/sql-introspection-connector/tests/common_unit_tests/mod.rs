@@ -5,14 +5,19 @@ use datamodel::{
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
 use quaint::connector::SqlFamily;
-use sql_introspection_connector::calculate_datamodel::calculate_datamodel;
+use sql_introspection_connector::calculate_datamodel::{
+    calculate_datamodel, calculate_datamodel_with_options, IntrospectionOptions,
+};
+use sql_introspection_connector::{SchemaInconsistent, SqlError};
 use sql_schema_describer::*;
 
 #[test]
 fn a_data_model_can_be_generated_from_a_schema() {
     let col_types = &[
         ColumnTypeFamily::Int,
+        ColumnTypeFamily::BigInt,
         ColumnTypeFamily::Float,
+        ColumnTypeFamily::Decimal,
         ColumnTypeFamily::Boolean,
         ColumnTypeFamily::String,
         ColumnTypeFamily::DateTime,
@@ -38,6 +43,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
             is_commented_out: true,
             indices: vec![],
             id_fields: vec![],
+            id_database_name: None,
             fields: col_types
                 .iter()
                 .map(|col_type| {
@@ -45,11 +51,21 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Boolean => (FieldType::Base(ScalarType::Boolean, None), false, None),
                         ColumnTypeFamily::DateTime => (FieldType::Base(ScalarType::DateTime, None), false, None),
                         ColumnTypeFamily::Float => (FieldType::Base(ScalarType::Float, None), false, None),
+                        ColumnTypeFamily::Decimal => (
+                            FieldType::Base(ScalarType::Float, None),
+                            false,
+                            Some(
+                                "This field was introspected from a fixed precision decimal type, its precision is not preserved."
+                                    .to_string(),
+                            ),
+                        ),
                         ColumnTypeFamily::Int => (FieldType::Base(ScalarType::Int, None), false, None),
+                        ColumnTypeFamily::BigInt => (FieldType::Base(ScalarType::BigInt, None), false, None),
                         ColumnTypeFamily::String => (FieldType::Base(ScalarType::String, None), false, None),
                         ColumnTypeFamily::Enum(name) => (FieldType::Enum(name.clone()), false, None),
                         ColumnTypeFamily::Uuid => (FieldType::Base(ScalarType::String, None), false, None),
                         ColumnTypeFamily::Json => (FieldType::Base(ScalarType::Json, None), false, None),
+                        ColumnTypeFamily::Binary => (FieldType::Base(ScalarType::Bytes, None), false, None),
                         x => (
                             FieldType::Unsupported(x.to_string()),
                             true,
@@ -90,9 +106,16 @@ fn a_data_model_can_be_generated_from_a_schema() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -136,6 +159,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            id_database_name: None,
         }],
         enums: vec![],
     };
@@ -168,11 +192,19 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 columns: vec!["required".to_string()],
                 sequence: None,
                 constraint_name: None,
+                autoincrement: false,
             }),
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -254,6 +286,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 tpe: dml::IndexType::Unique,
             }],
             id_fields: vec![],
+            id_database_name: None,
         }],
         enums: vec![],
     };
@@ -297,12 +330,23 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["no_default".into(), "int_default".into()],
                 tpe: IndexType::Unique,
+                is_constraint: false,
+                predicate: None,
+                storage_parameters: Vec::new(),
+                deferrable: None,
             }],
             primary_key: None,
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -336,6 +380,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_database_name: None,
             },
             // Model with non-auto-incrementing primary key
             Model {
@@ -360,6 +405,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_database_name: None,
             },
             // Model with primary key seeded by sequence
             Model {
@@ -384,6 +430,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_database_name: None,
             },
         ],
         enums: vec![],
@@ -399,6 +446,11 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -410,8 +462,14 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["primary".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
             Table {
                 name: "Table2".to_string(),
@@ -421,6 +479,11 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -432,8 +495,14 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["primary".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
             Table {
                 name: "Table3".to_string(),
@@ -443,6 +512,11 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -459,12 +533,20 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         allocation_size: 1,
                     }),
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
         ],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -503,6 +585,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            id_database_name: None,
         }],
         enums: vec![],
     };
@@ -528,12 +611,23 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["unique".to_string()],
                 tpe: IndexType::Unique,
+                is_constraint: false,
+                predicate: None,
+                storage_parameters: Vec::new(),
+                deferrable: None,
             }],
             primary_key: None,
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -584,6 +678,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_database_name: None,
             },
             Model {
                 database_name: None,
@@ -646,6 +741,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_database_name: None,
             },
         ],
         enums: vec![],
@@ -662,6 +758,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -675,6 +776,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -688,8 +794,14 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
             Table {
                 name: "User".to_string(),
@@ -700,6 +812,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -713,6 +830,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -726,6 +848,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -739,19 +866,28 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![ForeignKey {
                     // what does this mean? the from columns are not targeting a specific to column?
                     constraint_name: None,
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
         ],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -799,6 +935,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 tpe: datamodel::dml::IndexType::Unique,
             }],
             id_fields: vec![],
+            id_database_name: None,
         }],
         enums: vec![],
     };
@@ -813,6 +950,11 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -826,6 +968,11 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "text".to_string(),
                         full_data_type: "text".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::String,
                         arity: ColumnArity::Required,
@@ -839,6 +986,11 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "text".to_string(),
                         full_data_type: "text".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::String,
                         arity: ColumnArity::Required,
@@ -851,16 +1003,28 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: "name_last_name_unique".to_string(),
                 columns: vec!["name".to_string(), "lastname".to_string()],
                 tpe: IndexType::Unique,
+                is_constraint: false,
+                predicate: None,
+                storage_parameters: Vec::new(),
+                deferrable: None,
             }],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                autoincrement: false,
             }),
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -911,6 +1075,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_database_name: None,
             },
             Model {
                 database_name: None,
@@ -952,6 +1117,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_database_name: None,
             },
         ],
         enums: vec![],
@@ -968,6 +1134,11 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -981,6 +1152,11 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -994,8 +1170,14 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
             Table {
                 name: "User".to_string(),
@@ -1006,6 +1188,11 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1019,6 +1206,11 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1032,24 +1224,203 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
                     columns: vec!["city_id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
         ],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
 
+#[test]
+fn the_relation_name_prefix_is_applied_to_relation_names() {
+    let int_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: "integer".to_string(),
+            full_data_type: "integer".to_string(),
+            character_maximum_length: None,
+            datetime_precision: None,
+            timezone_aware: false,
+            srid: None,
+            array_elements_required: false,
+            domain: None,
+
+            family: ColumnTypeFamily::Int,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+    };
+    let table = |name: &str, columns: Vec<Column>, foreign_keys: Vec<ForeignKey>| Table {
+        name: name.to_string(),
+        columns,
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+            constraint_name: None,
+            autoincrement: false,
+        }),
+        foreign_keys,
+        engine: None,
+        default_character_set: None,
+        default_collation: None,
+        description: None,
+        storage_parameters: Vec::new(),
+    };
+    let foreign_key = |column: &str, referenced_table: &str| ForeignKey {
+        constraint_name: None,
+        columns: vec![column.to_string()],
+        referenced_table: referenced_table.to_string(),
+        referenced_schema: None,
+        on_delete_action: ForeignKeyAction::NoAction,
+        referenced_columns: vec!["id".to_string()],
+    };
+    let index = |name: &str, columns: &[&str], tpe: IndexType| Index {
+        name: name.to_string(),
+        columns: columns.iter().map(|column| column.to_string()).collect(),
+        tpe,
+        is_constraint: false,
+        predicate: None,
+        storage_parameters: Vec::new(),
+        deferrable: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            table("City", vec![int_column("id")], vec![]),
+            table("Tag", vec![int_column("id")], vec![]),
+            table(
+                "User",
+                vec![int_column("id"), int_column("city_id")],
+                vec![foreign_key("city_id", "City")],
+            ),
+            Table {
+                indices: vec![
+                    index("_TagToUser_AB_unique", &["A", "B"], IndexType::Unique),
+                    index("_TagToUser_B_index", &["B"], IndexType::Normal),
+                ],
+                primary_key: None,
+                ..table(
+                    "_TagToUser",
+                    vec![int_column("A"), int_column("B")],
+                    vec![foreign_key("A", "Tag"), foreign_key("B", "User")],
+                )
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
+    };
+    let options = IntrospectionOptions {
+        relation_name_prefix: Some("legacy_".to_string()),
+        ..IntrospectionOptions::default()
+    };
+
+    let introspection_result =
+        calculate_datamodel_with_options(&schema, &SqlFamily::Postgres, &options).expect("calculate data model");
+    let data_model = introspection_result.data_model;
+
+    let expected_relation_names: &[(&str, &[&str])] = &[
+        ("City", &["legacy_CityToUser"]),
+        ("Tag", &["TagToUser"]),
+        ("User", &["legacy_CityToUser", "TagToUser"]),
+    ];
+
+    for (model_name, expected) in expected_relation_names {
+        let relation_names: Vec<&str> = data_model
+            .find_model(model_name)
+            .unwrap()
+            .relation_fields()
+            .map(|field| field.relation_info.name.as_str())
+            .collect();
+
+        assert_eq!(relation_names, *expected);
+    }
+}
+
+#[test]
+fn list_defaults_are_preserved_for_list_fields() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Post".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                },
+                Column {
+                    name: "tags".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::List),
+                    default: Some(DefaultValue::LIST(vec![
+                        PrismaValue::String("draft".to_string()),
+                        PrismaValue::String("unread".to_string()),
+                    ])),
+                    auto_increment: false,
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                autoincrement: false,
+            }),
+            foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
+        }],
+        enums: vec![],
+        sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
+    };
+
+    let data_model = calculate_datamodel(&schema, &SqlFamily::Postgres)
+        .expect("calculate data model")
+        .data_model;
+    let tags = data_model
+        .find_model("Post")
+        .unwrap()
+        .find_scalar_field("tags")
+        .unwrap();
+
+    assert_eq!(tags.arity, FieldArity::List);
+    assert_eq!(
+        tags.default_value,
+        Some(DMLDefault::Single(PrismaValue::List(vec![
+            PrismaValue::String("draft".to_string()),
+            PrismaValue::String("unread".to_string()),
+        ])))
+    );
+}
+
 #[test]
 fn enums_are_preserved_when_generating_data_model_from_a_schema() {
     let ref_data_model = Datamodel {
@@ -1084,8 +1455,154 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
             values: enum_values,
         }],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
+
+#[test]
+fn a_foreign_key_to_a_missing_table_is_a_typed_schema_inconsistency() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Post".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                },
+                Column {
+                    name: "author_id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                autoincrement: false,
+            }),
+            foreign_keys: vec![ForeignKey {
+                constraint_name: None,
+                columns: vec!["author_id".to_string()],
+                referenced_table: "User".to_string(),
+                referenced_schema: None,
+                on_delete_action: ForeignKeyAction::NoAction,
+                referenced_columns: vec!["id".to_string()],
+            }],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
+        }],
+        enums: vec![],
+        sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
+    };
+
+    let error = calculate_datamodel(&schema, &SqlFamily::Postgres).unwrap_err();
+
+    match error {
+        SqlError::SchemaInconsistent(inconsistency) => {
+            assert_eq!(
+                inconsistency,
+                SchemaInconsistent::TableNotFound {
+                    table: "User".to_string()
+                }
+            );
+            assert_eq!(inconsistency.to_string(), "Table User not found.");
+        }
+        other => panic!("Expected a schema inconsistency, got {:?}", other),
+    }
+}
+
+#[test]
+fn a_foreign_key_on_a_missing_column_is_a_typed_schema_inconsistency() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Post".to_string(),
+            columns: vec![Column {
+                name: "id".to_string(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                default: None,
+                auto_increment: false,
+            }],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                autoincrement: false,
+            }),
+            foreign_keys: vec![ForeignKey {
+                constraint_name: None,
+                columns: vec!["author_id".to_string()],
+                referenced_table: "Post".to_string(),
+                referenced_schema: None,
+                on_delete_action: ForeignKeyAction::NoAction,
+                referenced_columns: vec!["id".to_string()],
+            }],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
+        }],
+        enums: vec![],
+        sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
+    };
+
+    let error = calculate_datamodel(&schema, &SqlFamily::Postgres).unwrap_err();
+
+    match error {
+        SqlError::SchemaInconsistent(inconsistency) => assert_eq!(
+            inconsistency,
+            SchemaInconsistent::ColumnNotFound {
+                table: "Post".to_string(),
+                column: "author_id".to_string(),
+            }
+        ),
+        other => panic!("Expected a schema inconsistency, got {:?}", other),
+    }
+}
+
+#[test]
+fn views_are_introspected_as_view_models() {
+    let schema = SqlSchema {
+        views: vec![View {
+            name: "UserName".to_string(),
+            definition: r#"CREATE VIEW "UserName" AS SELECT name FROM "User""#.to_string(),
+            columns: vec![Column {
+                name: "name".to_string(),
+                tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                default: None,
+                auto_increment: false,
+            }],
+        }],
+        ..SqlSchema::empty()
+    };
+    let introspection_result = calculate_datamodel(&schema, &SqlFamily::Sqlite).expect("calculate data model");
+    let model = introspection_result
+        .data_model
+        .find_model("UserName")
+        .expect("UserName model");
+
+    // Views have no unique identifier, so the model is commented out but keeps its marker.
+    assert!(model.is_commented_out);
+    assert_eq!(
+        model.documentation.as_deref(),
+        Some("@view\nThe underlying table does not contain a valid unique identifier and can therefore currently not be handled.")
+    );
+    assert!(model.find_scalar_field("name").is_some());
+}
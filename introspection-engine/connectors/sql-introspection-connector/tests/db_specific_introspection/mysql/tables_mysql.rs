@@ -263,6 +263,38 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
     custom_assert(&result, dm);
 }
 
+#[test_each_connector(tags("mysql"))]
+async fn introspecting_a_zero_date_default_should_work(api: &TestApi) {
+    // Zero dates are rejected in the default strict SQL mode.
+    api.database()
+        .execute_raw("SET SESSION sql_mode = ''", &[])
+        .await
+        .unwrap();
+
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("created_at DATETIME NOT NULL DEFAULT '0000-00-00 00:00:00'");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+                id         Int      @default(autoincrement()) @id
+                created_at DateTime @default(dbgenerated())
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+
+    let warnings = dbg!(api.introspection_warnings().await);
+    assert_eq!(&warnings, "[{\"code\":12,\"message\":\"These fields have a zero date (`0000-00-00`) default value, which is not a valid datetime. The default was introspected as dbgenerated().\",\"affected\":[{\"model\":\"Test\",\"field\":\"created_at\"}]}]");
+}
+
 //todo maybe need to split due to
 // no function default values on mysql 5.7 and 8.0 -.-
 // maria db allows this
@@ -311,3 +343,58 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn introspecting_bit_columns_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Flags", |t| {
+                    t.add_column("id", types::primary());
+                    t.inject_custom("is_active BIT(1) NOT NULL");
+                    t.inject_custom("permissions BIT(8) NOT NULL");
+                });
+            },
+            api.db_name(),
+        )
+        .await;
+
+    let dm = r#"
+            model Flags {
+                id          Int     @id @default(autoincrement())
+                is_active   Boolean
+                permissions Bytes
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn introspecting_set_columns_should_document_the_allowed_values(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Pet", |t| {
+                    t.add_column("id", types::primary());
+                    t.inject_custom("traits SET('can''t fly', 'naps a lot') NOT NULL");
+                });
+            },
+            api.db_name(),
+        )
+        .await;
+
+    let dm = r#"
+            model Pet {
+                id     Int    @id @default(autoincrement())
+                /// This field was introspected from the native type set, with the allowed values `can't fly`, `naps a lot`.
+                traits String
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
@@ -258,3 +258,34 @@ async fn introspecting_a_table_with_an_enum_default_value_that_is_an_empty_strin
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_table_with_an_enum_array_default_value_should_work(api: &TestApi) {
+    let sql = format!("CREATE Type color as ENUM ( 'black', 'white')");
+
+    api.database().execute_raw(&sql, &[]).await.unwrap();
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Book", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("colors  color[] Not Null default ARRAY['black','white']::color[]");
+            });
+        })
+        .await;
+
+    let dm = r#"
+        model Book {
+            id      Int     @default(autoincrement()) @id
+            colors  color[] @default([black, white])
+        }
+
+        enum color{
+            black
+            white
+        }
+    "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
@@ -617,12 +617,14 @@ async fn introspecting_relations_should_avoid_name_clashes(api: &TestApi) {
             model x {
                 id String @id
                 y  String
+                /// This relation field was renamed from y, because the model has another field with that name.
                 y_xToy  y      @relation(fields: [y], references: [id])
             }
 
             model y {
                 id String @id
                 x  String
+                /// This relation field was renamed from x, because the model has another field with that name.
                 x_xToy  x[]
             }
         "#;
@@ -686,7 +688,9 @@ async fn introspecting_relations_should_avoid_name_clashes_2(api: &TestApi) {
             model x {
                 id                   String @id
                 y                    String
+                /// This relation field was renamed from y, because the model has another field with that name.
                 y_x_yToy             y      @relation("x_yToy", fields: [y], references: [id])
+                /// This relation field was renamed from y, because the model has another field with that name.
                 y_xToy_fk_x_1_fk_x_2 y[]    @relation("xToy_fk_x_1_fk_x_2")
 
                 @@unique([id, y], name: "unique_y_id")
@@ -697,10 +701,73 @@ async fn introspecting_relations_should_avoid_name_clashes_2(api: &TestApi) {
                x                    String
                fk_x_1               String
                fk_x_2               String
+               /// This relation field was renamed from x, because the model has another field with that name.
                x_xToy_fk_x_1_fk_x_2 x      @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [y, id])
+               /// This relation field was renamed from x, because the model has another field with that name.
                x_x_yToy             x[]    @relation("x_yToy")
             }
         "#;
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_renamed_self_relation_fields_should_document_the_original_name(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("\"User\" INTEGER REFERENCES \"User\"(\"id\")");
+            });
+        })
+        .await;
+
+    let result = dbg!(api.introspect().await);
+
+    assert!(
+        result.contains(
+            "/// This relation field was renamed from User, because the model has another field with that name."
+        ),
+        "{}",
+        result
+    );
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_foreign_key_to_another_schema_should_warn_and_keep_the_scalar_field(api: &TestApi) {
+    let other_schema = format!("{}_referenced", api.schema_name());
+
+    api.database()
+        .raw_cmd(&format!(
+            "DROP SCHEMA IF EXISTS \"{other_schema}\" CASCADE;
+             CREATE SCHEMA \"{other_schema}\";
+             CREATE TABLE \"{other_schema}\".\"User\" (id SERIAL PRIMARY KEY);
+             CREATE TABLE \"{schema}\".\"Post\" (
+                 id SERIAL PRIMARY KEY,
+                 user_id INTEGER REFERENCES \"{other_schema}\".\"User\"(id)
+             );",
+            schema = api.schema_name(),
+            other_schema = other_schema,
+        ))
+        .await
+        .unwrap();
+
+    let dm = r#"
+            model Post {
+               id      Int  @default(autoincrement()) @id
+               user_id Int?
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+
+    let warnings = dbg!(api.introspection_warnings().await);
+    assert_eq!(
+        warnings,
+        format!(
+            "[{{\"code\":13,\"message\":\"These foreign keys were not turned into relations because they reference tables in another schema. The scalar fields were kept.\",\"affected\":[{{\"model\":\"Post\",\"fields\":[\"user_id\"],\"referenced_model\":\"{}.User\",\"referenced_fields\":[\"id\"]}}]}}]",
+            other_schema
+        )
+    );
+}
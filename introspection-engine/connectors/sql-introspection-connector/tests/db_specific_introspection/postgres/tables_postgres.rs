@@ -1,6 +1,7 @@
 use crate::*;
 use barrel::types;
 use pretty_assertions::assert_eq;
+use sql_introspection_connector::calculate_datamodel::IntrospectionOptions;
 use test_harness::*;
 
 #[test_each_connector(tags("postgres"))]
@@ -108,6 +109,54 @@ async fn introspecting_a_table_with_compound_primary_keys_must_work(api: &TestAp
     custom_assert(&result, dm);
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_table_with_a_named_compound_primary_key_must_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Blog", |t| {
+                t.add_column("id", types::integer());
+                t.add_column("authorId", types::text());
+                t.inject_custom("CONSTRAINT \"custom_pk\" PRIMARY KEY (\"id\", \"authorId\")");
+            });
+        })
+        .await;
+    let dm = r#"
+            model Blog {
+                id Int
+                authorId String
+                @@id([id, authorId], map: "custom_pk")
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_table_with_an_oid_column_must_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Blog", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("object oid NOT NULL");
+            });
+        })
+        .await;
+    let dm = r#"
+            model Blog {
+                id     Int    @id @default(autoincrement())
+                object Int
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+
+    for system_column in &["ctid", "xmin", "xmax", "cmin", "cmax", "tableoid"] {
+        assert!(!result.contains(system_column), "{}", result);
+    }
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let barrel = api.barrel();
@@ -236,6 +285,31 @@ async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
     custom_assert(&result, dm);
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_boolean_defaults_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Toggle", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("\"on\" Boolean NOT NULL DEFAULT true");
+                t.inject_custom("\"off\" Boolean NOT NULL DEFAULT false");
+                t.inject_custom("\"cast\" Boolean NOT NULL DEFAULT 't'::boolean");
+            });
+        })
+        .await;
+    let dm = r#"
+            model Toggle {
+                id      Int @id @default(autoincrement())
+                on      Boolean @default(true)
+                off     Boolean @default(false)
+                cast    Boolean @default(true)
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn introspecting_a_table_with_a_non_unique_index_should_work(api: &TestApi) {
     let barrel = api.barrel();
@@ -367,7 +441,7 @@ async fn introspecting_default_values_should_work(api: &TestApi) {
                 id                  Int         @id @default(autoincrement())
                 numeric_int2        Int?            @default(2)
                 numeric_int4        Int?            @default(4)
-                numeric_int8        Int?            @default(8)
+                numeric_int8        BigInt?         @default(8)
                 numeric_decimal     Float?          @default(1234.1234)
                 numeric_float4      Float?          @default(123.1234)
                 numeric_float8      Float?          @default(123.1234)
@@ -516,3 +590,352 @@ async fn introspecting_default_values_on_lists_should_be_ignored(api: &TestApi)
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_an_inet_column_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("network_inet inet");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id           Int     @id @default(autoincrement())
+               /// This field was introspected from the native type inet.
+               network_inet String?
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_cidr_column_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("network_cidr cidr");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id           Int     @id @default(autoincrement())
+               /// This field was introspected from the native type cidr.
+               network_cidr String?
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_column_of_a_domain_with_a_check_must_work(api: &TestApi) {
+    let sql = "CREATE DOMAIN positive_int AS integer CONSTRAINT positive CHECK (VALUE > 0)";
+    api.database().execute_raw(sql, &[]).await.unwrap();
+
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("amount positive_int NOT NULL CHECK (amount < 1000)");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id     Int @id @default(autoincrement())
+               /// This field was introspected from the domain positive_int, with the check constraints CHECK ((VALUE > 0)).
+               amount Int
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_macaddr_column_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("network_mac macaddr");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id          Int     @id @default(autoincrement())
+               /// This field was introspected from the native type macaddr.
+               network_mac String?
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_an_xml_column_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("document xml");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id       Int     @id @default(autoincrement())
+               /// This field was introspected from the native type xml.
+               document String?
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_numeric_column_should_not_make_it_unsupported(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("price numeric(10,2) NOT NULL");
+            });
+        })
+        .await;
+
+    let warnings = dbg!(api.introspection_warnings().await);
+    assert_eq!(&warnings, "[]");
+
+    let dm = r#"
+            model Test {
+               id    Int   @id @default(autoincrement())
+               price Float
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_unique_citext_column_should_comment_on_case_insensitivity(api: &TestApi) {
+    api.database()
+        .execute_raw("CREATE EXTENSION IF NOT EXISTS citext SCHEMA public", &[])
+        .await
+        .unwrap();
+
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("email public.citext UNIQUE NOT NULL");
+                t.inject_custom("nickname public.citext");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id       Int     @id @default(autoincrement())
+               /// Uniqueness is case-insensitive for this field, because it was introspected from the native type citext.
+               email    String  @unique
+               nickname String?
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_bytea_column_should_return_a_bytes_field(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("data bytea");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id   Int    @id @default(autoincrement())
+               data Bytes?
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_serial_with_a_sequence_in_another_schema_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await;
+
+    let other_schema = format!("{}_sequences", api.schema_name());
+    let drop_schema = format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", other_schema);
+    let create_schema = format!("CREATE SCHEMA \"{}\"", other_schema);
+    let move_sequence = format!(
+        "ALTER SEQUENCE \"{}\".\"Test_id_seq\" SET SCHEMA \"{}\"",
+        api.schema_name(),
+        other_schema
+    );
+
+    api.database().execute_raw(&drop_schema, &[]).await.unwrap();
+    api.database().execute_raw(&create_schema, &[]).await.unwrap();
+    api.database().execute_raw(&move_sequence, &[]).await.unwrap();
+
+    let dm = r#"
+            model Test {
+               id Int @id @default(autoincrement())
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_bigint_column_should_return_a_bigint_field(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.inject_custom("id bigserial primary key");
+                t.inject_custom("counter BIGINT not null");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id      BigInt @id @default(autoincrement())
+               counter BigInt
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_computed_defaults_should_keep_the_expression(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("created_by text not null DEFAULT CURRENT_USER");
+                t.inject_custom("session_owner text DEFAULT session_user");
+                t.inject_custom("string_function text DEFAULT 'Concatenated'||E'\n'");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id              Int     @id @default(autoincrement())
+               created_by      String  @default(dbgenerated("CURRENT_USER"))
+               session_owner   String? @default(dbgenerated("SESSION_USER"))
+               string_function String? @default(dbgenerated())
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_an_hstore_column_should_return_a_documented_string_field(api: &TestApi) {
+    api.database()
+        .execute_raw("CREATE EXTENSION IF NOT EXISTS hstore SCHEMA public", &[])
+        .await
+        .unwrap();
+
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("attributes public.hstore");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id         Int     @id @default(autoincrement())
+               /// This field was introspected from the native type hstore.
+               attributes String?
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_an_hstore_column_with_hstore_as_json_should_return_a_documented_json_field(api: &TestApi) {
+    api.database()
+        .execute_raw("CREATE EXTENSION IF NOT EXISTS hstore SCHEMA public", &[])
+        .await
+        .unwrap();
+
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Test", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("attributes public.hstore");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Test {
+               id         Int     @id @default(autoincrement())
+               /// This field was introspected from the native type hstore.
+               attributes Json?
+            }
+        "#;
+
+    let options = IntrospectionOptions {
+        hstore_as_json: true,
+        ..IntrospectionOptions::default()
+    };
+    let result = dbg!(api.introspect_with_options(options).await);
+    custom_assert(&result, dm);
+}
@@ -542,3 +542,40 @@ async fn introspecting_id_fields_with_foreign_key_should_work(api: &TestApi) {
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn introspecting_a_foreign_key_to_a_non_unique_column_should_warn_and_keep_the_scalar_field(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom(
+                    "user_name TEXT NOT NULL,
+                        FOREIGN KEY(user_name) REFERENCES User(name)",
+                )
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model User {
+               id        Int    @id @default(autoincrement())
+               name      String
+            }
+
+            model Post {
+               id        Int    @id @default(autoincrement())
+               user_name String
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+
+    let warnings = dbg!(api.introspection_warnings().await);
+    assert_eq!(&warnings, "[{\"code\":11,\"message\":\"These foreign keys were not turned into relations because they reference fields that are not covered by a unique constraint or the primary key. The scalar fields were kept.\",\"affected\":[{\"model\":\"Post\",\"fields\":[\"user_name\"],\"referenced_model\":\"User\",\"referenced_fields\":[\"name\"]}]}]");
+}
@@ -690,6 +690,44 @@ async fn re_introspecting_custom_model_order(api: &TestApi) {
     custom_assert(&result, final_dm);
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn re_introspecting_custom_field_order(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("email", types::text());
+                t.add_column("name", types::text());
+                t.add_column("age", types::integer());
+            });
+        })
+        .await;
+
+    let input_dm = r#"
+            model User {
+               name             String
+               id               Int @id @default(autoincrement())
+               email            String
+               nickname         String
+            }
+        "#;
+
+    let final_dm = r#"
+            model User {
+               name             String
+               id               Int @id @default(autoincrement())
+               email            String
+               age              Int
+            }
+        "#;
+    let result = dbg!(api.re_introspect(input_dm).await);
+    custom_assert(&result, final_dm);
+
+    let result = dbg!(api.re_introspect(&result).await);
+    custom_assert(&result, final_dm);
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn re_introspecting_custom_enum_order(api: &TestApi) {
     let sql = format!("CREATE Type a as ENUM ( 'id')");
@@ -1,16 +1,17 @@
 use super::misc_helpers::*;
 use datamodel::Datamodel;
-use introspection_connector::{DatabaseMetadata, IntrospectionConnector, Version};
+use introspection_connector::{DatabaseMetadata, IntrospectionConnector, IntrospectionWarning, Version};
 use quaint::{
     prelude::{Queryable, SqlFamily},
     single::Quaint,
 };
-use sql_introspection_connector::SqlIntrospectionConnector;
+use sql_introspection_connector::{calculate_datamodel::IntrospectionOptions, SqlIntrospectionConnector};
 use std::sync::Arc;
 use test_setup::*;
 
 pub struct TestApi {
     db_name: &'static str,
+    url: String,
     connection_info: quaint::prelude::ConnectionInfo,
     sql_family: SqlFamily,
     database: Arc<dyn Queryable + Send + Sync + 'static>,
@@ -35,6 +36,18 @@ impl TestApi {
         datamodel::render_datamodel_to_string(&introspection_result.data_model).expect("Datamodel rendering failed")
     }
 
+    /// Introspect with a new connector using the given options.
+    pub async fn introspect_with_options(&self, options: IntrospectionOptions) -> String {
+        let introspection_connector = SqlIntrospectionConnector::new_with_options(&self.url, options)
+            .await
+            .unwrap();
+        let introspection_result = introspection_connector
+            .introspect(&Datamodel::new(), false)
+            .await
+            .unwrap();
+        datamodel::render_datamodel_to_string(&introspection_result.data_model).expect("Datamodel rendering failed")
+    }
+
     pub async fn re_introspect(&self, data_model_string: &str) -> String {
         let data_model = datamodel::parse_datamodel(data_model_string).unwrap();
         let introspection_result = self
@@ -73,6 +86,14 @@ impl TestApi {
         serde_json::to_string(&introspection_result.warnings).unwrap()
     }
 
+    pub async fn typed_introspection_warnings(&self) -> Vec<IntrospectionWarning> {
+        self.introspection_connector
+            .introspect(&Datamodel::new(), false)
+            .await
+            .unwrap()
+            .warnings
+    }
+
     pub async fn get_metadata(&self) -> DatabaseMetadata {
         self.introspection_connector.get_metadata().await.unwrap()
     }
@@ -116,6 +137,7 @@ pub async fn mysql_test_api(db_name: &'static str) -> TestApi {
     TestApi {
         connection_info: conn.connection_info().to_owned(),
         db_name,
+        url,
         database: Arc::new(conn),
         sql_family: SqlFamily::Mysql,
         introspection_connector,
@@ -132,6 +154,7 @@ pub async fn mysql_8_test_api(db_name: &'static str) -> TestApi {
     TestApi {
         connection_info: conn.connection_info().to_owned(),
         db_name,
+        url,
         database: Arc::new(conn),
         sql_family: SqlFamily::Mysql,
         introspection_connector,
@@ -148,6 +171,7 @@ pub async fn mysql_5_6_test_api(db_name: &'static str) -> TestApi {
     TestApi {
         connection_info: conn.connection_info().to_owned(),
         db_name,
+        url,
         database: Arc::new(conn),
         sql_family: SqlFamily::Mysql,
         introspection_connector,
@@ -163,6 +187,7 @@ pub async fn mysql_mariadb_test_api(db_name: &'static str) -> TestApi {
 
     TestApi {
         db_name,
+        url,
         connection_info: conn.connection_info().to_owned(),
         database: Arc::new(conn),
         sql_family: SqlFamily::Mysql,
@@ -207,6 +232,7 @@ pub async fn test_api_helper_for_postgres(url: String, db_name: &'static str) ->
     TestApi {
         connection_info,
         db_name,
+        url,
         database: Arc::new(database),
         sql_family: SqlFamily::Postgres,
         introspection_connector,
@@ -222,6 +248,7 @@ pub async fn sqlite_test_api(db_name: &'static str) -> TestApi {
 
     TestApi {
         db_name,
+        url: connection_string,
         connection_info: database.connection_info().to_owned(),
         database: Arc::new(database),
         sql_family: SqlFamily::Sqlite,
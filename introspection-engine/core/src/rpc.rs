@@ -4,7 +4,7 @@ use crate::error_rendering::render_jsonrpc_error;
 use datamodel::{Configuration, Datamodel};
 use futures::{FutureExt, TryFutureExt};
 use introspection_connector::{
-    ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput, Warning,
+    ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput, IntrospectionWarning,
 };
 use jsonrpc_derive::rpc;
 use serde_derive::*;
@@ -102,7 +102,9 @@ impl RpcImpl {
                     Err(Error::from(CommandError::IntrospectionResultEmpty(url.to_string())))
                 } else {
                     if could_not_parse_input_data_model && reintrospect {
-                        introspection_result.warnings.push(Warning::new_datamodel_parsing())
+                        introspection_result
+                            .warnings
+                            .push(IntrospectionWarning::new_datamodel_parsing())
                     };
 
                     match datamodel::render_datamodel_and_config_to_string(&introspection_result.data_model, &config) {
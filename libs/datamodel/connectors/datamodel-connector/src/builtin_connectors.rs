@@ -92,8 +92,8 @@ impl BuiltinConnectors {
         // Int4Range, Int8Range, NumRange, TSRange, TSTZRange, DateRange
         // TXIDSnapshot
         let field_type_constructors = vec![
-            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
-            FieldTypeConstructor::without_args("BigSerial", "bigserial", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::BigInt),
+            FieldTypeConstructor::without_args("BigSerial", "bigserial", ScalarType::BigInt),
             FieldTypeConstructor::without_args("DoublePrecision", "double precision", ScalarType::Float),
             FieldTypeConstructor::without_args("Integer", "integer", ScalarType::Int),
             FieldTypeConstructor::without_args("Real", "real", ScalarType::Float),
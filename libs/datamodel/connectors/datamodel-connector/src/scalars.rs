@@ -4,22 +4,26 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize, Eq, Hash)]
 pub enum ScalarType {
     Int,
+    BigInt,
     Float,
     Boolean,
     String,
     DateTime,
     Json,
+    Bytes,
 }
 
 impl ScalarType {
     pub fn from_str(s: &str) -> Result<Self, String> {
         match s {
             "Int" => Ok(ScalarType::Int),
+            "BigInt" => Ok(ScalarType::BigInt),
             "Float" => Ok(ScalarType::Float),
             "Boolean" => Ok(ScalarType::Boolean),
             "String" => Ok(ScalarType::String),
             "DateTime" => Ok(ScalarType::DateTime),
             "Json" => Ok(ScalarType::Json),
+            "Bytes" => Ok(ScalarType::Bytes),
             _ => Err(format!("type {} is not a known scalar type.", s)),
         }
     }
@@ -29,11 +33,13 @@ impl ToString for ScalarType {
     fn to_string(&self) -> String {
         match self {
             ScalarType::Int => String::from("Int"),
+            ScalarType::BigInt => String::from("BigInt"),
             ScalarType::Float => String::from("Float"),
             ScalarType::Boolean => String::from("Boolean"),
             ScalarType::String => String::from("String"),
             ScalarType::DateTime => String::from("DateTime"),
             ScalarType::Json => String::from("Json"),
+            ScalarType::Bytes => String::from("Bytes"),
         }
     }
 }
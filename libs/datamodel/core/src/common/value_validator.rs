@@ -53,11 +53,13 @@ impl ValueValidator {
     pub fn as_type(&self, scalar_type: ScalarType) -> Result<PrismaValue, DatamodelError> {
         match scalar_type {
             ScalarType::Int => self.as_int().map(PrismaValue::Int),
+            ScalarType::BigInt => self.as_int().map(PrismaValue::Int),
             ScalarType::Float => self.as_float().map(PrismaValue::Float),
             ScalarType::Boolean => self.as_bool().map(PrismaValue::Boolean),
             ScalarType::DateTime => self.as_date_time().map(PrismaValue::DateTime),
             ScalarType::String => self.as_str().map(PrismaValue::String),
             ScalarType::Json => self.as_str().map(PrismaValue::String),
+            ScalarType::Bytes => self.as_str().map(PrismaValue::String),
         }
     }
 
@@ -173,6 +175,17 @@ impl ValueValidator {
         }
     }
 
+    /// Unwraps the wrapped value as an array of constant literals, e.g. `[A, B]`.
+    pub fn as_constant_literal_array(&self) -> Result<Vec<String>, DatamodelError> {
+        match &self.value {
+            ast::Expression::Array(values, _) => values
+                .iter()
+                .map(|value| ValueValidator::new(value).as_constant_literal())
+                .collect(),
+            _ => Err(self.construct_type_mismatch_error("array")),
+        }
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_array(&self) -> Vec<ValueValidator> {
         match &self.value {
@@ -193,8 +206,8 @@ impl ValueValidator {
 
     pub fn as_default_value_for_scalar_type(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                let generator = self.get_value_generator(&name)?;
+            ast::Expression::Function(name, args, _) => {
+                let generator = self.get_value_generator(&name, &args)?;
                 generator
                     .check_compatibility_with_scalar_type(scalar_type)
                     .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))?;
@@ -207,15 +220,39 @@ impl ValueValidator {
         }
     }
 
+    /// Defaults of scalar lists are arrays of values of the scalar type, e.g. `@default([1, 2, 3])`.
+    pub fn as_default_value_for_scalar_list_type(
+        &self,
+        scalar_type: ScalarType,
+    ) -> Result<DefaultValue, DatamodelError> {
+        match &self.value {
+            ast::Expression::Function(_, _, _) => self.as_default_value_for_scalar_type(scalar_type),
+            ast::Expression::Array(values, _) => {
+                let values = values
+                    .iter()
+                    .map(|value| ValueValidator::new(value).as_type(scalar_type))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(DefaultValue::Single(PrismaValue::List(values)))
+            }
+            _ => Err(self.construct_type_mismatch_error("array")),
+        }
+    }
+
     pub fn as_value_generator(&self) -> Result<ValueGenerator, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => self.get_value_generator(&name),
+            ast::Expression::Function(name, args, _) => self.get_value_generator(&name, &args),
             _ => Err(self.construct_type_mismatch_error("function")),
         }
     }
 
-    fn get_value_generator(&self, name: &str) -> Result<ValueGenerator, DatamodelError> {
-        ValueGenerator::new(name.to_string(), vec![])
+    fn get_value_generator(&self, name: &str, args: &[ast::Expression]) -> Result<ValueGenerator, DatamodelError> {
+        let args = args
+            .iter()
+            .map(|arg| ValueValidator::new(arg).as_str().map(PrismaValue::String))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ValueGenerator::new(name.to_string(), args)
             .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))
     }
 }
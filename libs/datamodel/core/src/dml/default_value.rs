@@ -37,6 +37,14 @@ impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
         let generator = ValueGeneratorFn::new(name.as_ref())?;
 
+        match (generator, args.as_slice()) {
+            (_, []) | (ValueGeneratorFn::DbGenerated, [PrismaValue::String(_)]) => (),
+            (ValueGeneratorFn::DbGenerated, _) => {
+                return Err("The function `dbgenerated()` takes a single string argument.".to_owned())
+            }
+            _ => return Err(format!("The function `{}()` does not take any arguments.", name)),
+        }
+
         Ok(ValueGenerator { name, args, generator })
     }
 
@@ -48,6 +56,11 @@ impl ValueGenerator {
         ValueGenerator::new("dbgenerated".to_owned(), vec![]).unwrap()
     }
 
+    /// A `dbgenerated("...")` default keeping the database expression that computes the value.
+    pub fn new_dbgenerated_expression(expression: String) -> Self {
+        ValueGenerator::new("dbgenerated".to_owned(), vec![PrismaValue::String(expression)]).unwrap()
+    }
+
     pub fn new_now() -> Self {
         ValueGenerator::new("now".to_owned(), vec![]).unwrap()
     }
@@ -122,6 +135,7 @@ impl ValueGeneratorFn {
             (Self::CUID, ScalarType::String) => true,
             (Self::Now, ScalarType::DateTime) => true,
             (Self::Autoincrement, ScalarType::Int) => true,
+            (Self::Autoincrement, ScalarType::BigInt) => true,
             (Self::DbGenerated, _) => true,
             _ => false,
         }
@@ -18,6 +18,8 @@ pub struct Model {
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// The database name of the composite primary key constraint, from `@@id([...], map: "...")`.
+    pub id_database_name: Option<String>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
@@ -65,6 +67,7 @@ impl Model {
             fields: vec![],
             indices: vec![],
             id_fields: vec![],
+            id_database_name: None,
             documentation: None,
             database_name,
             is_embedded: false,
@@ -1,7 +1,7 @@
 use crate::error::DatamodelError;
 use crate::validator::directive::{Args, DirectiveValidator};
 use crate::validator::LowerDmlToAst;
-use crate::{ast, dml, ValueGenerator};
+use crate::{ast, dml};
 use prisma_value::PrismaValue;
 
 /// Prismas builtin `@default` directive.
@@ -16,27 +16,35 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
         if let dml::Field::RelationField(_) = field {
             return self.new_directive_validation_error("Cannot set a default value on a relation field.", args.span());
         } else if let dml::Field::ScalarField(sf) = field {
-            // If we allow list default values, we need to adjust the types below properly for that case.
-            if sf.arity == dml::FieldArity::List {
-                return self.new_directive_validation_error("Cannot set a default value on list field.", args.span());
-            }
+            let is_list = sf.arity == dml::FieldArity::List;
 
             if let dml::FieldType::Base(scalar_type, _) = sf.field_type {
-                let dv = args
-                    .default_arg("value")?
-                    .as_default_value_for_scalar_type(scalar_type)
-                    .map_err(|e| self.wrap_in_directive_validation_error(&e))?;
+                let default_arg = args.default_arg("value")?;
+                let dv = if is_list {
+                    default_arg.as_default_value_for_scalar_list_type(scalar_type)
+                } else {
+                    default_arg.as_default_value_for_scalar_type(scalar_type)
+                }
+                .map_err(|e| self.wrap_in_directive_validation_error(&e))?;
 
                 sf.default_value = Some(dv);
             } else if let dml::FieldType::Enum(_) = sf.field_type {
                 let default_arg = args.default_arg("value")?;
 
-                match default_arg.as_constant_literal() {
+                let value = if is_list {
+                    default_arg
+                        .as_constant_literal_array()
+                        .map(|values| PrismaValue::List(values.into_iter().map(PrismaValue::Enum).collect()))
+                } else {
+                    default_arg.as_constant_literal().map(PrismaValue::Enum)
+                };
+
+                match value {
                     // TODO: We should also check if this value is a valid enum value. For this we need the enums -.-
-                    Ok(value) => sf.default_value = Some(dml::DefaultValue::Single(PrismaValue::Enum(value))),
+                    Ok(value) => sf.default_value = Some(dml::DefaultValue::Single(value)),
                     Err(err) => {
                         let generator = default_arg.as_value_generator()?;
-                        if generator == ValueGenerator::new_dbgenerated() {
+                        if generator.name == "dbgenerated" {
                             sf.default_value = Some(dml::DefaultValue::Expression(generator));
                         } else {
                             return Err(self.wrap_in_directive_validation_error(&err));
@@ -59,6 +59,10 @@ impl DirectiveValidator<dml::Model> for ModelLevelIdDirectiveValidator {
             .map(|f| f.as_constant_literal().unwrap())
             .collect();
         obj.id_fields = fields;
+        obj.id_database_name = match args.optional_arg("map") {
+            Some(map_arg) => Some(map_arg.as_str()?),
+            None => None,
+        };
 
         let undefined_fields: Vec<String> = obj
             .id_fields
@@ -143,6 +147,10 @@ impl DirectiveValidator<dml::Model> for ModelLevelIdDirectiveValidator {
                     .collect(),
             ));
 
+            if let Some(id_database_name) = &model.id_database_name {
+                args.push(ast::Argument::new_string("map", id_database_name));
+            }
+
             return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
         }
 
@@ -127,6 +127,9 @@ impl LowerDmlToAst {
             PrismaValue::Null(_) => ast::Expression::ConstantValue("null".to_string(), ast::Span::empty()),
             PrismaValue::Uuid(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
             PrismaValue::Json(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
+            PrismaValue::Bytes(val) => {
+                ast::Expression::StringValue(prisma_value::encode_bytes(val), ast::Span::empty())
+            }
             PrismaValue::List(vec) => ast::Expression::Array(
                 vec.iter().map(|pv| Self::lower_prisma_value(pv)).collect(),
                 ast::Span::empty(),
@@ -40,6 +40,10 @@ impl<'a> ModelWalker<'a> {
         self.model.final_database_name()
     }
 
+    pub fn documentation(&self) -> Option<&'a str> {
+        self.model.documentation.as_deref()
+    }
+
     pub fn relation_fields<'b>(&'b self) -> impl Iterator<Item = RelationFieldWalker<'a>> + 'b {
         self.model.relation_fields().map(move |field| RelationFieldWalker {
             datamodel: self.datamodel,
@@ -72,6 +76,11 @@ impl<'a> ModelWalker<'a> {
         &self.model.name
     }
 
+    /// The database name of the composite primary key constraint, if the datamodel maps it.
+    pub fn id_database_name(&self) -> Option<&'a str> {
+        self.model.id_database_name.as_deref()
+    }
+
     pub fn id_fields<'b>(&'b self) -> impl Iterator<Item = ScalarFieldWalker<'a>> + 'b {
         // Single-id models
         self.model
@@ -24,20 +24,20 @@ fn must_error_if_default_value_for_relation_field() {
 }
 
 #[test]
-fn must_error_if_default_value_for_list() {
+fn must_error_if_default_value_for_list_is_not_an_array() {
     let dml = r#"
     model Model {
         id Int @id
-        rel String[] @default(["hello"])
+        rel String[] @default("hello")
     }
     "#;
 
     let errors = parse_error(dml);
 
     errors.assert_is(DatamodelError::new_directive_validation_error(
-        "Cannot set a default value on list field.",
+        "Expected a array value, but received string value \"hello\".",
         "default",
-        Span::new(60, 78),
+        Span::new(68, 75),
     ));
 }
 
@@ -118,3 +118,76 @@ fn db_generated_function_must_work_for_enum_fields() {
         .assert_enum_type("Role")
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated()));
 }
+
+#[test]
+fn db_generated_function_must_keep_its_expression() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        owner String @default(dbgenerated("CURRENT_USER"))
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("owner")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated_expression(
+            "CURRENT_USER".to_owned(),
+        )));
+}
+
+#[test]
+fn should_set_list_defaults_on_scalar_lists() {
+    let dml = r#"
+    model Model {
+        id      Int      @id
+        ints    Int[]    @default([1, 2, 3])
+        strings String[] @default(["a", "b"])
+        empty   Int[]    @default([])
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("ints")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(vec![
+            PrismaValue::Int(1),
+            PrismaValue::Int(2),
+            PrismaValue::Int(3),
+        ])));
+    user_model
+        .assert_has_scalar_field("strings")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(vec![
+            PrismaValue::String("a".to_owned()),
+            PrismaValue::String("b".to_owned()),
+        ])));
+    user_model
+        .assert_has_scalar_field("empty")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(Vec::new())));
+}
+
+#[test]
+fn should_set_list_defaults_on_enum_lists() {
+    let dml = r#"
+    model Model {
+        id    Int    @id
+        roles Role[] @default([USER, ADMIN])
+    }
+
+    enum Role {
+        USER
+        ADMIN
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("roles")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(vec![
+            PrismaValue::Enum("USER".to_owned()),
+            PrismaValue::Enum("ADMIN".to_owned()),
+        ])));
+}
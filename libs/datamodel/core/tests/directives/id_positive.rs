@@ -144,6 +144,25 @@ fn multi_field_ids_must_work() {
     user_model.assert_has_id_fields(&["a", "b"]);
 }
 
+#[test]
+fn multi_field_ids_can_map_their_constraint_name() {
+    let dml = r#"
+    model Model {
+        a String
+        b Int
+        @@id([a, b], map: "custom_pk")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model.assert_has_id_fields(&["a", "b"]);
+    assert_eq!(user_model.id_database_name.as_deref(), Some("custom_pk"));
+
+    let rendered = datamodel::render_datamodel_to_string(&datamodel).unwrap();
+    assert!(rendered.contains(r#"@@id([a, b], map: "custom_pk")"#), "{}", rendered);
+}
+
 #[test]
 fn relation_field_as_id_must_error() {
     let dml = r#"
@@ -119,7 +119,7 @@ fn should_handle_type_mappings() {
 
     user_model
         .assert_has_scalar_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::BigInt, "bigint"));
 }
 
 #[test]
@@ -143,5 +143,5 @@ fn should_handle_type_specifications() {
 
     user_model
         .assert_has_scalar_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::BigInt, "bigint"));
 }
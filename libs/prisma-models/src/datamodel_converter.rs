@@ -350,8 +350,11 @@ impl DatamodelFieldExtensions for dml::ScalarField {
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
                 dml::ScalarType::Float => TypeIdentifier::Float,
                 dml::ScalarType::Int => TypeIdentifier::Int,
+                // Query engine integers are 64 bits wide already.
+                dml::ScalarType::BigInt => TypeIdentifier::Int,
                 dml::ScalarType::String => TypeIdentifier::String,
                 dml::ScalarType::Json => TypeIdentifier::Json,
+                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
             },
             dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
             dml::FieldType::ConnectorSpecific { .. } => {
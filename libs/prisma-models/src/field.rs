@@ -67,6 +67,7 @@ pub enum TypeIdentifier {
     DateTime,
     UUID,
     Int,
+    Bytes,
 }
 
 impl From<TypeIdentifier> for TypeHint {
@@ -80,6 +81,7 @@ impl From<TypeIdentifier> for TypeHint {
             TypeIdentifier::DateTime => TypeHint::DateTime,
             TypeIdentifier::UUID => TypeHint::UUID,
             TypeIdentifier::Int => TypeHint::Int,
+            TypeIdentifier::Bytes => TypeHint::Bytes,
         }
     }
 }
@@ -182,10 +184,13 @@ impl From<ScalarType> for TypeIdentifier {
         match st {
             ScalarType::String => Self::String,
             ScalarType::Int => Self::Int,
+            // Query engine integers are 64 bits wide already.
+            ScalarType::BigInt => Self::Int,
             ScalarType::Float => Self::Float,
             ScalarType::Boolean => Self::Boolean,
             ScalarType::DateTime => Self::DateTime,
             ScalarType::Json => Self::Json,
+            ScalarType::Bytes => Self::Bytes,
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::{ModelRef, ScalarFieldRef};
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 use std::string::ToString;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -7,6 +7,20 @@ pub struct OrderBy {
     pub sort_order: SortOrder,
 }
 
+/// Ordering by an aggregation over the related records of a relation field,
+/// e.g. `orderBy: { posts: { _count: desc } }`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderByAggregate {
+    pub field: RelationFieldRef,
+    pub aggregation: AggregationKind,
+    pub sort_order: SortOrder,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum AggregationKind {
+    Count,
+}
+
 pub trait IntoOrderBy {
     fn into_order_by(self, model: ModelRef) -> OrderBy;
 }
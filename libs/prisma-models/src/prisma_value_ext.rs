@@ -21,6 +21,7 @@ impl PrismaValueExtensions for PrismaValue {
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
+            (val @ PrismaValue::Bytes(_), TypeIdentifier::Bytes) => val,
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
@@ -44,6 +45,17 @@ impl PrismaValueExtensions for PrismaValue {
             },
             (PrismaValue::Float(f), TypeIdentifier::Int) => PrismaValue::Int(f.trunc().to_i64().unwrap()),
 
+            // Valid Bytes coercions
+            (PrismaValue::String(s), TypeIdentifier::Bytes) => match prisma_value::decode_bytes(&s) {
+                Ok(bytes) => PrismaValue::Bytes(bytes),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
+
             // Todo other coercions here
 
             // Lists
@@ -44,7 +44,8 @@ impl ManyRecords {
         }
     }
 
-    pub fn order_by(&mut self, order_by: &OrderBy) {
+    /// Sort the records by the first ordering, then the next one for ties, and so on.
+    pub fn order_by(&mut self, order_by: &[OrderBy]) {
         let field_indices: HashMap<&str, usize> = self
             .field_names
             .iter()
@@ -53,12 +54,18 @@ impl ManyRecords {
             .collect();
 
         self.records.sort_by(|a, b| {
-            let index = field_indices[order_by.field.db_name()];
-
-            match order_by.sort_order {
-                SortOrder::Ascending => a.values[index].cmp(&b.values[index]),
-                SortOrder::Descending => b.values[index].cmp(&a.values[index]),
-            }
+            order_by
+                .iter()
+                .map(|order_by| {
+                    let index = field_indices[order_by.field.db_name()];
+
+                    match order_by.sort_order {
+                        SortOrder::Ascending => a.values[index].cmp(&b.values[index]),
+                        SortOrder::Descending => b.values[index].cmp(&a.values[index]),
+                    }
+                })
+                .find(|ordering| *ordering != std::cmp::Ordering::Equal)
+                .unwrap_or(std::cmp::Ordering::Equal)
         })
     }
 
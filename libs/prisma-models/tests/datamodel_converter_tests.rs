@@ -226,6 +226,23 @@ fn uuid_fields_must_work() {
         .assert_type_identifier(TypeIdentifier::String);
 }
 
+#[test]
+fn bytes_fields_must_work() {
+    let datamodel = convert(
+        r#"
+            model Test {
+                id    Int   @id
+                bytes Bytes
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Test");
+    model
+        .assert_scalar_field("bytes")
+        .assert_type_identifier(TypeIdentifier::Bytes);
+}
+
 #[test]
 fn cuid_fields_must_work() {
     let datamodel = convert(
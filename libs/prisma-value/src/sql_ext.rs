@@ -48,10 +48,11 @@ impl<'a> From<Value<'a>> for PrismaValue {
             Value::Char(c) => c
                 .map(|c| PrismaValue::String(c.to_string()))
                 .unwrap_or(PrismaValue::null(TypeHint::Char)),
+            // Text columns can come back as bytes, so only values that are not valid UTF-8 stay bytes.
             Value::Bytes(bytes) => bytes
-                .map(|bytes| {
-                    let s = String::from_utf8(bytes.into_owned()).expect("PrismaValue::String from Value::Bytes");
-                    PrismaValue::String(s)
+                .map(|bytes| match String::from_utf8(bytes.into_owned()) {
+                    Ok(s) => PrismaValue::String(s),
+                    Err(err) => PrismaValue::Bytes(err.into_bytes()),
                 })
                 .unwrap_or(PrismaValue::null(TypeHint::Bytes)),
         }
@@ -70,6 +71,7 @@ impl<'a> From<PrismaValue> for Value<'a> {
             PrismaValue::Uuid(u) => u.to_string().into(),
             PrismaValue::List(l) => Value::Array(Some(l.into_iter().map(|x| x.into()).collect())),
             PrismaValue::Json(s) => Value::Json(serde_json::from_str(&s).unwrap()),
+            PrismaValue::Bytes(b) => Value::Bytes(Some(b.into())),
             PrismaValue::Null(ident) => match ident {
                 TypeHint::String => Value::Text(None),
                 TypeHint::Float => Value::Real(None),
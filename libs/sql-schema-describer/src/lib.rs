@@ -5,10 +5,11 @@
 
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
+use quaint::prelude::SqlFamily;
 use regex::Regex;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::{fmt, str::FromStr};
+use std::{borrow::Cow, fmt, str::FromStr};
 use thiserror::Error;
 use tracing::debug;
 
@@ -27,6 +28,9 @@ pub enum SqlSchemaDescriberError {
 /// The result type.
 pub type SqlSchemaDescriberResult<T> = core::result::Result<T, SqlSchemaDescriberError>;
 
+/// The default for the number of describer queries in flight on the connection at the same time.
+pub const DEFAULT_MAX_CONCURRENT_TABLE_QUERIES: usize = 4;
+
 /// A database description connector.
 #[async_trait::async_trait]
 pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
@@ -36,6 +40,8 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata>;
     /// Describe a database schema.
     async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema>;
+    /// Describe a single table of a database schema, without describing the others.
+    async fn describe_table(&self, schema: &str, name: &str) -> SqlSchemaDescriberResult<Option<Table>>;
 }
 
 #[derive(Serialize, Deserialize)]
@@ -54,6 +60,12 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's composite types, unique to Postgres.
+    #[serde(default)]
+    pub composite_types: Vec<CompositeType>,
+    /// The schema's views. Only SQLite describes them.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -87,13 +99,48 @@ impl SqlSchema {
         self.sequences.iter().find(|x| x.name == name)
     }
 
+    /// Get a composite type.
+    pub fn get_composite_type(&self, name: &str) -> Option<&CompositeType> {
+        self.composite_types.iter().find(|x| x.name == name)
+    }
+
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     pub fn empty() -> SqlSchema {
         SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            composite_types: Vec::new(),
+            views: Vec::new(),
         }
     }
+
+    /// Compare two schemas, ignoring the order in which tables, columns, indexes, foreign keys, enums, sequences,
+    /// composite types and views were described. The order of the columns inside an index or a foreign key, and of the
+    /// values of an enum, is significant and still compared.
+    pub fn semantically_equals(&self, other: &SqlSchema) -> bool {
+        same_elements_by(&self.tables, &other.tables, Table::semantically_equals)
+            && same_elements_by(&self.enums, &other.enums, PartialEq::eq)
+            && same_elements_by(&self.sequences, &other.sequences, PartialEq::eq)
+            && same_elements_by(&self.composite_types, &other.composite_types, PartialEq::eq)
+            && same_elements_by(&self.views, &other.views, PartialEq::eq)
+    }
+}
+
+/// A view found in a schema.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The SQL statement defining the view, as stored by the database.
+    pub definition: String,
+    /// The view's columns.
+    pub columns: Vec<Column>,
 }
 
 /// A table found in a schema.
@@ -110,9 +157,35 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's storage engine, unique to MySQL.
+    pub engine: Option<String>,
+    /// The table's default character set, unique to MySQL.
+    pub default_character_set: Option<String>,
+    /// The table's default collation, unique to MySQL.
+    pub default_collation: Option<String>,
+    /// The table's comment (`COMMENT=` on MySQL, `COMMENT ON TABLE` on Postgres).
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The table's storage parameters, like `fillfactor`, as name-value pairs. Only Postgres sets this.
+    #[serde(default)]
+    pub storage_parameters: Vec<(String, String)>,
 }
 
 impl Table {
+    /// Compare two tables, ignoring the order of their columns, indexes and foreign keys.
+    pub fn semantically_equals(&self, other: &Table) -> bool {
+        self.name == other.name
+            && self.primary_key == other.primary_key
+            && self.engine == other.engine
+            && self.default_character_set == other.default_character_set
+            && self.default_collation == other.default_collation
+            && self.description == other.description
+            && self.storage_parameters == other.storage_parameters
+            && same_elements_by(&self.columns, &other.columns, PartialEq::eq)
+            && same_elements_by(&self.indices, &other.indices, PartialEq::eq)
+            && same_elements_by(&self.foreign_keys, &other.foreign_keys, PartialEq::eq)
+    }
+
     pub fn column_bang(&self, name: &str) -> &Column {
         self.column(name)
             .unwrap_or_else(|| panic!("Column {} not found in Table {}", name, self.name))
@@ -157,6 +230,26 @@ impl Table {
                 && index.columns.contains(&column_name.to_owned())
         })
     }
+
+    /// Whether the given set of columns is covered by the primary key or a unique index, in any order. Only
+    /// such columns can be referenced by a well-formed foreign key.
+    pub fn columns_are_unique(&self, column_names: &[String]) -> bool {
+        let same_columns = |columns: &[String]| {
+            columns.len() == column_names.len() && column_names.iter().all(|name| columns.contains(name))
+        };
+
+        let covered_by_primary_key = self
+            .primary_key
+            .as_ref()
+            .map(|pk| same_columns(&pk.columns))
+            .unwrap_or(false);
+
+        covered_by_primary_key
+            || self
+                .indices
+                .iter()
+                .any(|index| index.tpe == IndexType::Unique && same_columns(&index.columns))
+    }
 }
 /// The type of an index.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -166,6 +259,10 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Fulltext type (MySQL `FULLTEXT`).
+    Fulltext,
+    /// Spatial type (MySQL `SPATIAL`).
+    Spatial,
 }
 
 impl IndexType {
@@ -187,6 +284,31 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Whether the index was created implicitly to back a unique constraint. Such indexes are
+    /// dropped through their constraint. Only Postgres sets this.
+    #[serde(default)]
+    pub is_constraint: bool,
+    /// The filter of a partial index, as a SQL expression. Only Postgres sets this, MySQL does not
+    /// support partial indexes.
+    #[serde(default)]
+    pub predicate: Option<String>,
+    /// The index's storage parameters, like `fillfactor`, as name-value pairs. Only Postgres sets this.
+    #[serde(default)]
+    pub storage_parameters: Vec<(String, String)>,
+    /// Whether the unique constraint backed by the index is `DEFERRABLE`, and when it is checked by
+    /// default. Only Postgres sets this.
+    #[serde(default)]
+    pub deferrable: Option<Deferrable>,
+}
+
+/// When a deferrable constraint is checked, unless changed with `SET CONSTRAINTS` in a transaction.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Deferrable {
+    /// `DEFERRABLE INITIALLY IMMEDIATE`: after each statement.
+    InitiallyImmediate,
+    /// `DEFERRABLE INITIALLY DEFERRED`: at the end of the transaction.
+    InitiallyDeferred,
 }
 
 impl Index {
@@ -205,6 +327,10 @@ pub struct PrimaryKey {
     pub sequence: Option<Sequence>,
     /// The name of the primary key constraint, when available.
     pub constraint_name: Option<String>,
+    /// Whether the primary key was declared with the `AUTOINCREMENT` keyword on SQLite, so that rowids
+    /// are never reused, as opposed to a plain `INTEGER PRIMARY KEY` aliasing the rowid.
+    #[serde(default)]
+    pub autoincrement: bool,
 }
 
 impl PrimaryKey {
@@ -231,6 +357,14 @@ impl Column {
     pub fn is_required(&self) -> bool {
         self.tpe.arity == ColumnArity::Required
     }
+
+    /// The default of the column as a SQL expression for the given database, or `None` if the column
+    /// has no default or the database cannot express it.
+    pub fn default_sql(&self, flavour: SqlFamily) -> Option<String> {
+        self.default
+            .as_ref()
+            .and_then(|default| default.to_sql(flavour, &self.tpe.family))
+    }
 }
 
 /// The type of a column.
@@ -243,6 +377,21 @@ pub struct ColumnType {
     pub full_data_type: String,
     /// The maximum length for character or string bit types if specified.
     pub character_maximum_length: Option<i64>,
+    /// The fractional seconds precision for time and timestamp types if specified.
+    pub datetime_precision: Option<u32>,
+    /// Whether a time or timestamp type stores the time zone, like `timestamptz` on Postgres.
+    #[serde(default)]
+    pub timezone_aware: bool,
+    /// The spatial reference system identifier a geometry column is restricted to, if any (MySQL 8).
+    pub srid: Option<u32>,
+    /// Whether the elements of a list column can never be null. Postgres enforces this with an
+    /// `array_position(<column>, NULL) IS NULL` check constraint on the column.
+    #[serde(default)]
+    pub array_elements_required: bool,
+    /// The domain the column was declared with, on Postgres. The other fields describe the base type
+    /// of the domain.
+    #[serde(default)]
+    pub domain: Option<Domain>,
     /// The family of the raw type.
     pub family: ColumnTypeFamily,
     /// The arity of the column.
@@ -255,10 +404,56 @@ impl ColumnType {
             data_type: "".to_string(),
             full_data_type: "".to_string(),
             character_maximum_length: None,
+            datetime_precision: None,
+            timezone_aware: false,
+            srid: None,
+            array_elements_required: false,
+            domain: None,
             family,
             arity,
         }
     }
+
+    /// Whether values of this type can be converted to `other` on the given database without losing
+    /// data. This only looks at the type families and whether the columns are lists.
+    pub fn is_compatible_with(&self, other: &ColumnType, family: SqlFamily) -> Compatibility {
+        use ColumnTypeFamily::*;
+
+        if self.arity.is_list() != other.arity.is_list() {
+            return Compatibility::PotentiallyLossy;
+        }
+
+        match (&self.family, &other.family) {
+            (previous, next) if previous == next => Compatibility::Identical,
+            // SQLite stores both as 64-bit `INTEGER`.
+            (Int, BigInt) | (BigInt, Int) if family == SqlFamily::Sqlite => Compatibility::Identical,
+            (Int, BigInt) | (Int, Float) | (Int, Decimal) | (BigInt, Decimal) => Compatibility::SafeWidening,
+            (Boolean, Int) | (Boolean, BigInt) => Compatibility::SafeWidening,
+            (Binary, String) | (Unsupported(_), String) => Compatibility::PotentiallyLossy,
+            // `String` is a `varchar(191)` on MySQL, which only fits the textual form of fixed-width values.
+            (previous, String) if family == SqlFamily::Mysql => {
+                if matches!(previous, Int | BigInt | Float | Decimal | Boolean | DateTime | Uuid) {
+                    Compatibility::SafeWidening
+                } else {
+                    Compatibility::PotentiallyLossy
+                }
+            }
+            (_, String) => Compatibility::SafeWidening,
+            _ => Compatibility::PotentiallyLossy,
+        }
+    }
+}
+
+/// How safely the values of a column can be converted to another column type. See
+/// `ColumnType::is_compatible_with`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Compatibility {
+    /// The types are the same.
+    Identical,
+    /// Every value of the previous type can be represented in the next type.
+    SafeWidening,
+    /// Some values may be truncated, rounded or fail to convert.
+    PotentiallyLossy,
 }
 
 /// Enumeration of column type families.
@@ -268,15 +463,20 @@ impl ColumnType {
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
+    /// 64-bit integer types, e.g. `bigint`. Kept apart from `Int` because their values don't fit a 32-bit integer.
+    BigInt,
     /// Floating point types.
     Float,
+    /// Fixed precision decimal types.
+    Decimal,
     /// Boolean types.
     Boolean,
     /// String types.
     String,
     /// DateTime types.
     DateTime,
-    /// Binary types.
+    /// Binary types, both fixed length (e.g. `binary(n)`) and variable length (e.g. `blob`, `bytea`).
+    /// They correspond to the `Bytes` scalar type.
     Binary,
     /// JSON types.
     Json,
@@ -306,7 +506,9 @@ impl fmt::Display for ColumnTypeFamily {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Int => "int".to_string(),
+            Self::BigInt => "bigInt".to_string(),
             Self::Float => "float".to_string(),
+            Self::Decimal => "decimal".to_string(),
             Self::Boolean => "boolean".to_string(),
             Self::String => "string".to_string(),
             Self::DateTime => "dateTime".to_string(),
@@ -344,6 +546,10 @@ impl ColumnArity {
     pub fn is_nullable(&self) -> bool {
         matches!(self, ColumnArity::Nullable)
     }
+
+    pub fn is_list(&self) -> bool {
+        matches!(self, ColumnArity::List)
+    }
 }
 
 /// Foreign key action types (for ON DELETE|ON UPDATE).
@@ -378,6 +584,10 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// The schema of the referenced table, when it lives in another schema than the referencing
+    /// table. `None` for references within the same schema.
+    #[serde(default)]
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
@@ -388,6 +598,7 @@ impl PartialEq for ForeignKey {
     fn eq(&self, other: &Self) -> bool {
         self.columns == other.columns
             && self.referenced_table == other.referenced_table
+            && self.referenced_schema == other.referenced_schema
             && self.referenced_columns == other.referenced_columns
     }
 }
@@ -402,6 +613,37 @@ pub struct Enum {
     pub values: Vec<String>,
 }
 
+/// A composite type, created with `CREATE TYPE name AS (...)` on Postgres.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositeType {
+    /// Composite type name.
+    pub name: String,
+    /// The fields of the composite type, in order.
+    pub fields: Vec<CompositeTypeField>,
+}
+
+/// A field of a composite type.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositeTypeField {
+    /// Field name.
+    pub name: String,
+    /// The field's data type, as rendered by the database.
+    pub data_type: String,
+}
+
+/// A domain, created with `CREATE DOMAIN name AS base_type` on Postgres.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Domain {
+    /// Domain name.
+    pub name: String,
+    /// The check constraints of the domain, e.g. `CHECK ((VALUE > 0))`, sorted by constraint name.
+    /// They are distinct from the check constraints of the tables using the domain.
+    pub checks: Vec<String>,
+}
+
 /// A SQL sequence.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -419,12 +661,40 @@ pub struct Sequence {
 pub enum DefaultValue {
     /// A constant value, parsed as String
     VALUE(PrismaValue),
+    /// A list of constant values, for array columns.
+    LIST(Vec<PrismaValue>),
     /// An expression generating a current timestamp.
     NOW,
     /// An expression generating a sequence.
     SEQUENCE(String),
     /// An unrecognized Default Value
     DBGENERATED(String),
+    /// A Postgres identity column (`GENERATED ... AS IDENTITY`), with its sequence options.
+    IDENTITY(IdentityOptions),
+}
+
+/// The definition of a Postgres identity column.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityOptions {
+    /// `GENERATED ALWAYS` if true, `GENERATED BY DEFAULT` otherwise.
+    pub always: bool,
+    /// The `START WITH` option of the identity sequence.
+    pub start: i64,
+    /// The `INCREMENT BY` option of the identity sequence.
+    pub increment: i64,
+}
+
+impl IdentityOptions {
+    /// Render the `GENERATED ... AS IDENTITY (...)` clause of the column definition.
+    pub fn to_sql(&self) -> String {
+        format!(
+            "GENERATED {} AS IDENTITY (START WITH {} INCREMENT BY {})",
+            if self.always { "ALWAYS" } else { "BY DEFAULT" },
+            self.start,
+            self.increment
+        )
+    }
 }
 
 impl DefaultValue {
@@ -434,6 +704,68 @@ impl DefaultValue {
             _ => None,
         }
     }
+
+    /// Render the default as a SQL expression for a column of type `family`. Returns `None` for list
+    /// and sequence defaults on databases other than Postgres, and for identity columns, which are
+    /// not rendered as a `DEFAULT` (see `IdentityOptions::to_sql`). Database generated expressions are
+    /// parenthesized on MySQL and SQLite, which only accept literals as bare defaults.
+    pub fn to_sql(&self, sql_family: SqlFamily, family: &ColumnTypeFamily) -> Option<String> {
+        match (self, family, sql_family) {
+            (DefaultValue::DBGENERATED(expr), _, SqlFamily::Postgres) => Some(expr.clone()),
+            (DefaultValue::DBGENERATED(expr), _, _) => Some(format!("({})", expr)),
+            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String, SqlFamily::Postgres)
+            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_), SqlFamily::Postgres) => {
+                Some(format!("E'{}'", escape_postgres_string_literal(val)))
+            }
+            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String, _)
+            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_), _) => {
+                Some(format!("'{}'", escape_string_literal(val)))
+            }
+            (DefaultValue::NOW, _, _) => Some("CURRENT_TIMESTAMP".to_owned()),
+            (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime, _) => Some(format!("'{}'", val)),
+            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json, SqlFamily::Postgres) => {
+                Some(format!("'{}'", val))
+            }
+            (DefaultValue::VALUE(val), _, _) => Some(val.to_string()),
+            (DefaultValue::LIST(values), _, SqlFamily::Postgres) => {
+                let elements: Vec<String> = values.iter().map(postgres_array_literal_element).collect();
+
+                Some(format!("'{{{}}}'", escape_string_literal(&elements.join(","))))
+            }
+            (DefaultValue::SEQUENCE(nextval), _, SqlFamily::Postgres) => Some(nextval.clone()),
+            (DefaultValue::LIST(_), _, _) | (DefaultValue::SEQUENCE(_), _, _) | (DefaultValue::IDENTITY(_), _, _) => {
+                None
+            }
+        }
+    }
+}
+
+/// Render a list default element the way Postgres prints array literals, so that the default reads back
+/// unchanged: strings are double quoted, with double quotes and backslashes escaped.
+///
+/// See https://www.postgresql.org/docs/current/arrays.html#ARRAYS-IO
+fn postgres_array_literal_element(value: &PrismaValue) -> String {
+    match value {
+        PrismaValue::String(s) | PrismaValue::Enum(s) => {
+            format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+        PrismaValue::DateTime(_) => format!("\"{}\"", value),
+        value => value.to_string(),
+    }
+}
+
+/// Escape single quotes by doubling them, as in standard SQL.
+fn escape_string_literal(s: &str) -> Cow<'_, str> {
+    static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
+
+    STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "'$0")
+}
+
+/// Escape single quotes and backslashes for Postgres `E'...'` string literals.
+fn escape_postgres_string_literal(s: &str) -> Cow<'_, str> {
+    static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'|\\"#).unwrap());
+
+    STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "\\$0")
 }
 
 static RE_NUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"^'?(\d+)'?$").expect("compile regex"));
@@ -478,6 +810,20 @@ pub fn unquote_string(val: &str) -> String {
         .into()
 }
 
+/// Whether both slices contain the same elements according to `eq`, in any order.
+fn same_elements_by<T>(left: &[T], right: &[T], eq: impl Fn(&T, &T) -> bool) -> bool {
+    let mut unmatched: Vec<&T> = right.iter().collect();
+
+    left.len() == right.len()
+        && left.iter().all(|l| match unmatched.iter().position(|r| eq(l, r)) {
+            Some(idx) => {
+                unmatched.swap_remove(idx);
+                true
+            }
+            None => false,
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,4 +836,234 @@ mod tests {
 
         assert_eq!(unquote_string("heh ".into()), "heh ");
     }
+
+    fn column(name: &str) -> Column {
+        Column {
+            name: name.to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+            default: None,
+            auto_increment: false,
+        }
+    }
+
+    fn index(name: &str, columns: &[&str]) -> Index {
+        Index {
+            name: name.to_owned(),
+            columns: columns.iter().map(|c| (*c).to_owned()).collect(),
+            tpe: IndexType::Normal,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
+        }
+    }
+
+    fn schema_with_table(columns: Vec<Column>, indices: Vec<Index>) -> SqlSchema {
+        SqlSchema {
+            tables: vec![Table {
+                name: "Cat".to_owned(),
+                columns,
+                indices,
+                primary_key: None,
+                foreign_keys: Vec::new(),
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
+            }],
+            ..SqlSchema::empty()
+        }
+    }
+
+    #[test]
+    fn schemas_with_reordered_columns_and_indexes_are_semantically_equal() {
+        let left = schema_with_table(
+            vec![column("id"), column("age"), column("weight")],
+            vec![index("age_idx", &["age"]), index("weight_age_idx", &["weight", "age"])],
+        );
+        let right = schema_with_table(
+            vec![column("weight"), column("id"), column("age")],
+            vec![index("weight_age_idx", &["weight", "age"]), index("age_idx", &["age"])],
+        );
+
+        assert_ne!(left, right);
+        assert!(left.semantically_equals(&right));
+        assert!(right.semantically_equals(&left));
+    }
+
+    #[test]
+    fn schemas_with_different_index_column_order_are_not_semantically_equal() {
+        let left = schema_with_table(
+            vec![column("id"), column("age"), column("weight")],
+            vec![index("weight_age_idx", &["weight", "age"])],
+        );
+        let right = schema_with_table(
+            vec![column("id"), column("age"), column("weight")],
+            vec![index("weight_age_idx", &["age", "weight"])],
+        );
+
+        assert!(!left.semantically_equals(&right));
+    }
+
+    #[test]
+    fn schemas_with_duplicated_columns_are_not_semantically_equal() {
+        let left = schema_with_table(vec![column("id"), column("id"), column("age")], Vec::new());
+        let right = schema_with_table(vec![column("id"), column("age"), column("age")], Vec::new());
+
+        assert!(!left.semantically_equals(&right));
+    }
+
+    fn column_with_default(family: ColumnTypeFamily, arity: ColumnArity, default: DefaultValue) -> Column {
+        Column {
+            name: "col".to_owned(),
+            tpe: ColumnType::pure(family, arity),
+            default: Some(default),
+            auto_increment: false,
+        }
+    }
+
+    const ALL_FAMILIES: &[SqlFamily] = &[SqlFamily::Postgres, SqlFamily::Mysql, SqlFamily::Sqlite];
+
+    #[test]
+    fn columns_without_default_have_no_default_sql() {
+        for sql_family in ALL_FAMILIES {
+            assert_eq!(column("id").default_sql(*sql_family), None);
+        }
+    }
+
+    #[test]
+    fn now_defaults_render_as_current_timestamp() {
+        let column = column_with_default(ColumnTypeFamily::DateTime, ColumnArity::Required, DefaultValue::NOW);
+
+        for sql_family in ALL_FAMILIES {
+            assert_eq!(column.default_sql(*sql_family).as_deref(), Some("CURRENT_TIMESTAMP"));
+        }
+    }
+
+    #[test]
+    fn value_defaults_render_as_literals() {
+        let string = column_with_default(
+            ColumnTypeFamily::String,
+            ColumnArity::Required,
+            DefaultValue::VALUE(PrismaValue::String("it's".to_owned())),
+        );
+
+        assert_eq!(string.default_sql(SqlFamily::Postgres).as_deref(), Some(r#"E'it\'s'"#));
+        assert_eq!(string.default_sql(SqlFamily::Mysql).as_deref(), Some("'it''s'"));
+        assert_eq!(string.default_sql(SqlFamily::Sqlite).as_deref(), Some("'it''s'"));
+
+        let int = column_with_default(
+            ColumnTypeFamily::Int,
+            ColumnArity::Required,
+            DefaultValue::VALUE(PrismaValue::Int(3)),
+        );
+
+        for sql_family in ALL_FAMILIES {
+            assert_eq!(int.default_sql(*sql_family).as_deref(), Some("3"));
+        }
+    }
+
+    #[test]
+    fn list_defaults_only_render_on_postgres() {
+        let column = column_with_default(
+            ColumnTypeFamily::Int,
+            ColumnArity::List,
+            DefaultValue::LIST(vec![PrismaValue::Int(1), PrismaValue::Int(2)]),
+        );
+
+        assert_eq!(column.default_sql(SqlFamily::Postgres).as_deref(), Some("ARRAY[1, 2]"));
+        assert_eq!(column.default_sql(SqlFamily::Mysql), None);
+        assert_eq!(column.default_sql(SqlFamily::Sqlite), None);
+    }
+
+    #[test]
+    fn sequence_defaults_only_render_on_postgres() {
+        let nextval = r#"nextval('"Cat_id_seq"'::regclass)"#;
+        let column = column_with_default(
+            ColumnTypeFamily::Int,
+            ColumnArity::Required,
+            DefaultValue::SEQUENCE(nextval.to_owned()),
+        );
+
+        assert_eq!(column.default_sql(SqlFamily::Postgres).as_deref(), Some(nextval));
+        assert_eq!(column.default_sql(SqlFamily::Mysql), None);
+        assert_eq!(column.default_sql(SqlFamily::Sqlite), None);
+    }
+
+    #[test]
+    fn dbgenerated_defaults_render_the_raw_expression() {
+        let column = column_with_default(
+            ColumnTypeFamily::String,
+            ColumnArity::Required,
+            DefaultValue::DBGENERATED("uuid()".to_owned()),
+        );
+
+        for sql_family in ALL_FAMILIES {
+            assert_eq!(column.default_sql(*sql_family).as_deref(), Some("uuid()"));
+        }
+    }
+
+    #[test]
+    fn column_type_compatibility_matrix() {
+        use ColumnTypeFamily::*;
+        use Compatibility::*;
+
+        let required = |family: ColumnTypeFamily| ColumnType::pure(family, ColumnArity::Required);
+
+        let cases = &[
+            (Int, Int, SqlFamily::Postgres, Identical),
+            (Int, BigInt, SqlFamily::Postgres, SafeWidening),
+            (Int, BigInt, SqlFamily::Mysql, SafeWidening),
+            (Int, BigInt, SqlFamily::Sqlite, Identical),
+            (BigInt, Int, SqlFamily::Postgres, PotentiallyLossy),
+            (BigInt, Int, SqlFamily::Sqlite, Identical),
+            (Int, Float, SqlFamily::Mysql, SafeWidening),
+            (Int, Decimal, SqlFamily::Postgres, SafeWidening),
+            (Float, Int, SqlFamily::Postgres, PotentiallyLossy),
+            (Boolean, Int, SqlFamily::Mysql, SafeWidening),
+            (Int, Boolean, SqlFamily::Mysql, PotentiallyLossy),
+            (Int, String, SqlFamily::Postgres, SafeWidening),
+            (DateTime, String, SqlFamily::Mysql, SafeWidening),
+            (Enum("Mood".to_owned()), String, SqlFamily::Postgres, SafeWidening),
+            (String, Int, SqlFamily::Postgres, PotentiallyLossy),
+            (String, DateTime, SqlFamily::Sqlite, PotentiallyLossy),
+            (Binary, String, SqlFamily::Postgres, PotentiallyLossy),
+            (Json, String, SqlFamily::Postgres, SafeWidening),
+            (Json, String, SqlFamily::Mysql, PotentiallyLossy),
+            (Enum("Mood".to_owned()), String, SqlFamily::Mysql, PotentiallyLossy),
+            (Uuid, String, SqlFamily::Mysql, SafeWidening),
+            (String, Json, SqlFamily::Postgres, PotentiallyLossy),
+        ];
+
+        for (previous, next, sql_family, expected) in cases {
+            assert_eq!(
+                required(previous.clone()).is_compatible_with(&required(next.clone()), *sql_family),
+                *expected,
+                "{:?} -> {:?} on {:?}",
+                previous,
+                next,
+                sql_family
+            );
+        }
+    }
+
+    #[test]
+    fn changing_list_arity_is_potentially_lossy() {
+        let scalar = ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required);
+        let list = ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List);
+
+        assert_eq!(
+            scalar.is_compatible_with(&list, SqlFamily::Postgres),
+            Compatibility::PotentiallyLossy
+        );
+        assert_eq!(
+            list.is_compatible_with(&scalar, SqlFamily::Postgres),
+            Compatibility::PotentiallyLossy
+        );
+        assert_eq!(
+            list.is_compatible_with(&list, SqlFamily::Postgres),
+            Compatibility::Identical
+        );
+    }
 }
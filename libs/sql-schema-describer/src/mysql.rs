@@ -1,5 +1,9 @@
 use super::*;
-use quaint::prelude::Queryable;
+use futures::{
+    future::{BoxFuture, FutureExt},
+    stream::{self, StreamExt},
+};
+use quaint::{ast::Value, prelude::Queryable};
 use std::collections::{BTreeMap, HashMap};
 use std::{borrow::Cow, sync::Arc};
 use tracing::debug;
@@ -23,8 +27,35 @@ impl Flavour {
     }
 }
 
+/// The options of a table in `information_schema.tables`.
+#[derive(Debug, Default)]
+struct TableOptions {
+    engine: Option<String>,
+    character_set: Option<String>,
+    collation: Option<String>,
+    comment: Option<String>,
+}
+
+/// The per-table catalog information of a schema, keyed by table name.
+#[derive(Default)]
+struct TableCatalog {
+    columns: HashMap<String, (Vec<Column>, Vec<Enum>)>,
+    indexes: HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
+    foreign_keys: HashMap<String, Vec<ForeignKey>>,
+    table_options: HashMap<String, TableOptions>,
+}
+
+/// The result of one of the independent catalog queries behind a `TableCatalog`.
+enum TableCatalogPart {
+    Columns(HashMap<String, (Vec<Column>, Vec<Enum>)>),
+    Indexes(HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>),
+    ForeignKeys(HashMap<String, Vec<ForeignKey>>),
+    TableOptions(HashMap<String, TableOptions>),
+}
+
 pub struct SqlSchemaDescriber {
     conn: Arc<dyn Queryable + Send + Sync + 'static>,
+    max_concurrent_table_queries: usize,
 }
 
 #[async_trait::async_trait]
@@ -53,13 +84,11 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
-        let mut columns = get_all_columns(self.conn.as_ref(), schema, &flavour).await;
-        let mut indexes = get_all_indexes(self.conn.as_ref(), schema).await;
-        let mut fks = get_foreign_keys(self.conn.as_ref(), schema).await;
+        let mut catalog = self.get_table_catalog(schema, None, &flavour).await;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) = self.get_table(table_name, &mut catalog);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
@@ -68,14 +97,81 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables,
             enums,
             sequences: vec![],
+            composite_types: vec![],
+            views: vec![],
         })
     }
+
+    async fn describe_table(&self, schema: &str, name: &str) -> SqlSchemaDescriberResult<Option<Table>> {
+        debug!("describing table '{}' in schema '{}'", name, schema);
+        let table_names = self.get_table_names(schema).await;
+
+        if !table_names.iter().any(|table_name| table_name == name) {
+            return Ok(None);
+        }
+
+        let version = self.conn.version().await.ok().flatten();
+        let flavour = version
+            .as_ref()
+            .map(|s| Flavour::from_version(s))
+            .unwrap_or(Flavour::Mysql);
+
+        let mut catalog = self.get_table_catalog(schema, Some(name), &flavour).await;
+
+        let (table, _enums) = self.get_table(name, &mut catalog);
+
+        Ok(Some(table))
+    }
 }
 
 impl SqlSchemaDescriber {
     /// Constructor.
     pub fn new(conn: Arc<dyn Queryable + Send + Sync + 'static>) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+        SqlSchemaDescriber {
+            conn,
+            max_concurrent_table_queries: DEFAULT_MAX_CONCURRENT_TABLE_QUERIES,
+        }
+    }
+
+    /// Limit the number of catalog queries in flight on the connection. With a pooled connection,
+    /// this should not exceed the pool size, or the describer will wait on the pool and starve
+    /// other users of the connection.
+    pub fn with_max_concurrent_table_queries(mut self, limit: usize) -> Self {
+        self.max_concurrent_table_queries = limit.max(1);
+        self
+    }
+
+    /// Run the catalog queries for the tables of the schema (or only `table`) concurrently.
+    async fn get_table_catalog(&self, schema: &str, table: Option<&str>, flavour: &Flavour) -> TableCatalog {
+        let conn = self.conn.as_ref();
+        let queries: Vec<BoxFuture<'_, TableCatalogPart>> = vec![
+            get_all_columns(conn, schema, table, flavour)
+                .map(TableCatalogPart::Columns)
+                .boxed(),
+            get_all_indexes(conn, schema, table)
+                .map(TableCatalogPart::Indexes)
+                .boxed(),
+            get_foreign_keys(conn, schema, table)
+                .map(TableCatalogPart::ForeignKeys)
+                .boxed(),
+            self.get_table_options(schema, table)
+                .map(TableCatalogPart::TableOptions)
+                .boxed(),
+        ];
+
+        let mut parts = stream::iter(queries).buffer_unordered(self.max_concurrent_table_queries);
+        let mut catalog = TableCatalog::default();
+
+        while let Some(part) = parts.next().await {
+            match part {
+                TableCatalogPart::Columns(columns) => catalog.columns = columns,
+                TableCatalogPart::Indexes(indexes) => catalog.indexes = indexes,
+                TableCatalogPart::ForeignKeys(foreign_keys) => catalog.foreign_keys = foreign_keys,
+                TableCatalogPart::TableOptions(table_options) => catalog.table_options = table_options,
+            }
+        }
+
+        catalog
     }
 
     async fn get_databases(&self) -> Vec<String> {
@@ -120,6 +216,53 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_table_options(&self, schema: &str, table: Option<&str>) -> HashMap<String, TableOptions> {
+        debug!("Getting table options");
+        let sql = format!(
+            "
+            SELECT
+                tables.table_name table_name,
+                tables.engine engine,
+                tables.table_collation table_collation,
+                tables.table_comment table_comment,
+                collations.character_set_name character_set_name
+            FROM information_schema.tables tables
+            LEFT JOIN information_schema.collation_character_set_applicability collations
+                ON collations.collation_name = tables.table_collation
+            WHERE tables.table_schema = ?
+            AND tables.table_type = 'BASE TABLE'
+            {table_filter}
+        ",
+            table_filter = table_filter("tables.table_name", table),
+        );
+        let rows = self
+            .conn
+            .query_raw(&sql, &with_table_param(vec![schema.into()], table))
+            .await
+            .expect("get table options");
+        let options = rows
+            .into_iter()
+            .filter_map(|row| {
+                let table_name = row.get("table_name").and_then(|x| x.to_string())?;
+                let options = TableOptions {
+                    engine: row.get("engine").and_then(|x| x.to_string()),
+                    character_set: row.get("character_set_name").and_then(|x| x.to_string()),
+                    collation: row.get("table_collation").and_then(|x| x.to_string()),
+                    // Tables without a comment have an empty one.
+                    comment: row
+                        .get("table_comment")
+                        .and_then(|x| x.to_string())
+                        .filter(|comment| !comment.is_empty()),
+                };
+
+                Some((table_name, options))
+            })
+            .collect();
+
+        debug!("Found table options: {:?}", options);
+        options
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         use rust_decimal::prelude::*;
 
@@ -144,18 +287,13 @@ impl SqlSchemaDescriber {
         size as usize
     }
 
-    fn get_table(
-        &self,
-        name: &str,
-        columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
-        indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
-        foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
-    ) -> (Table, Vec<Enum>) {
+    fn get_table(&self, name: &str, catalog: &mut TableCatalog) -> (Table, Vec<Enum>) {
         debug!("Getting table '{}'", name);
-        let (columns, enums) = columns.remove(name).expect("table columns not found");
-        let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
+        let (columns, enums) = catalog.columns.remove(name).expect("table columns not found");
+        let (indices, primary_key) = catalog.indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
-        let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let foreign_keys = catalog.foreign_keys.remove(name).unwrap_or_default();
+        let table_options = catalog.table_options.remove(name).unwrap_or_default();
         (
             Table {
                 name: name.to_string(),
@@ -163,6 +301,11 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                engine: table_options.engine,
+                default_character_set: table_options.character_set,
+                default_collation: table_options.collation,
+                description: table_options.comment,
+                storage_parameters: Vec::new(),
             },
             enums,
         )
@@ -172,12 +315,14 @@ impl SqlSchemaDescriber {
 async fn get_all_columns(
     conn: &dyn Queryable,
     schema_name: &str,
+    table: Option<&str>,
     flavour: &Flavour,
 ) -> HashMap<String, (Vec<Column>, Vec<Enum>)> {
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
     // the result values by column name below to fail.
-    let sql = "
+    let sql = format!(
+        "
             SELECT
                 column_name column_name,
                 data_type data_type,
@@ -189,13 +334,17 @@ async fn get_all_columns(
                 table_name table_name
             FROM information_schema.columns
             WHERE table_schema = ?
+            {table_filter}
             ORDER BY ordinal_position
-        ";
+        ",
+        table_filter = table_filter("table_name", table),
+    );
 
     let mut map = HashMap::new();
+    let srids = get_column_srids(conn, schema_name, table, flavour).await;
 
     let rows = conn
-        .query_raw(sql, &[schema_name.into()])
+        .query_raw(&sql, &with_table_param(vec![schema_name.into()], table))
         .await
         .expect("querying for columns");
 
@@ -232,7 +381,7 @@ async fn get_all_columns(
             ColumnArity::Nullable
         };
 
-        let (tpe, enum_option) = get_column_type_and_enum(
+        let (mut tpe, enum_option) = get_column_type_and_enum(
             &table_name,
             &name,
             &data_type,
@@ -240,6 +389,7 @@ async fn get_all_columns(
             character_maximum_length,
             arity,
         );
+        tpe.srid = srids.get(&(table_name.clone(), name.clone())).copied();
         let extra = col
             .get("extra")
             .and_then(|x| x.to_string())
@@ -263,11 +413,11 @@ async fn get_all_columns(
                 Some(x) if x == "NULL" => None,
                 Some(default_string) => {
                     Some(match &tpe.family {
-                        ColumnTypeFamily::Int => match parse_int(&default_string) {
+                        ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                             Some(int_value) => DefaultValue::VALUE(int_value),
                             None => DefaultValue::DBGENERATED(default_string),
                         },
-                        ColumnTypeFamily::Float => match parse_float(&default_string) {
+                        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => match parse_float(&default_string) {
                             Some(float_value) => DefaultValue::VALUE(float_value),
                             None => DefaultValue::DBGENERATED(default_string),
                         },
@@ -313,29 +463,92 @@ async fn get_all_columns(
     map
 }
 
+/// The SRID constraints of geometry columns, by table and column name. Only MySQL 8 has SRID
+/// constraints and the `st_geometry_columns` view, the map is empty on older versions and MariaDB.
+async fn get_column_srids(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table: Option<&str>,
+    flavour: &Flavour,
+) -> HashMap<(String, String), u32> {
+    let mut srids = HashMap::new();
+
+    if let Flavour::MariaDb = flavour {
+        return srids;
+    }
+
+    let sql = format!(
+        "
+            SELECT
+                table_name table_name,
+                column_name column_name,
+                srs_id srs_id
+            FROM information_schema.st_geometry_columns
+            WHERE table_schema = ?
+            AND srs_id IS NOT NULL
+            {table_filter}
+        ",
+        table_filter = table_filter("table_name", table),
+    );
+
+    let rows = match conn
+        .query_raw(&sql, &with_table_param(vec![schema_name.into()], table))
+        .await
+    {
+        Ok(rows) => rows,
+        Err(err) => {
+            debug!("Could not query the SRIDs of geometry columns: {}", err);
+            return srids;
+        }
+    };
+
+    for row in rows {
+        let table_name = row
+            .get("table_name")
+            .and_then(|x| x.to_string())
+            .expect("get table name");
+        let column_name = row
+            .get("column_name")
+            .and_then(|x| x.to_string())
+            .expect("get column name");
+
+        if let Some(srid) = row.get("srs_id").and_then(|x| x.as_i64()) {
+            srids.insert((table_name, column_name), srid as u32);
+        }
+    }
+
+    srids
+}
+
 async fn get_all_indexes(
     conn: &dyn Queryable,
     schema_name: &str,
+    table: Option<&str>,
 ) -> HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)> {
     let mut map = HashMap::new();
 
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
     // the result values by column name below to fail.
-    let sql = "
+    let sql = format!(
+        "
             SELECT DISTINCT
                 index_name AS index_name,
                 non_unique AS non_unique,
+                index_type AS index_type,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
                 table_name AS table_name
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
+            {table_filter}
             ORDER BY index_name, seq_in_index
-            ";
+            ",
+        table_filter = table_filter("table_name", table),
+    );
     debug!("describing indices, SQL: {}", sql);
     let rows = conn
-        .query_raw(sql, &[schema_name.into()])
+        .query_raw(&sql, &with_table_param(vec![schema_name.into()], table))
         .await
         .expect("querying for indices");
 
@@ -346,6 +559,7 @@ async fn get_all_indexes(
         let pos = seq_in_index - 1;
         let index_name = row.get("index_name").and_then(|x| x.to_string()).expect("index_name");
         let is_unique = !row.get("non_unique").and_then(|x| x.as_bool()).expect("non_unique");
+        let index_type = row.get("index_type").and_then(|x| x.to_string()).expect("index_type");
         let column_name = row.get("column_name").and_then(|x| x.to_string()).expect("column_name");
 
         // Multi-column indices will return more than one row (with different column_name values).
@@ -376,6 +590,7 @@ async fn get_all_indexes(
                             columns: vec![column_name],
                             sequence: None,
                             constraint_name: None,
+                            autoincrement: false,
                         }),
                     );
                 }
@@ -390,10 +605,16 @@ async fn get_all_indexes(
                 Index {
                     name: index_name,
                     columns: vec![column_name],
-                    tpe: match is_unique {
-                        true => IndexType::Unique,
-                        false => IndexType::Normal,
+                    tpe: match (is_unique, index_type.as_str()) {
+                        (true, _) => IndexType::Unique,
+                        (false, "FULLTEXT") => IndexType::Fulltext,
+                        (false, "SPATIAL") => IndexType::Spatial,
+                        (false, _) => IndexType::Normal,
                     },
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
                 },
             );
         }
@@ -402,7 +623,11 @@ async fn get_all_indexes(
     map
 }
 
-async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<String, Vec<ForeignKey>> {
+async fn get_foreign_keys(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table: Option<&str>,
+) -> HashMap<String, Vec<ForeignKey>> {
     // Foreign keys covering multiple columns will return multiple rows, which we need to
     // merge.
     let mut map: HashMap<String, HashMap<String, ForeignKey>> = HashMap::new();
@@ -415,7 +640,8 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
     // the result values by column name below to fail.
-    let sql = "
+    let sql = format!(
+        "
         SELECT
             kcu.constraint_name constraint_name,
             kcu.column_name column_name,
@@ -431,13 +657,19 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
             kcu.table_schema = ?
             AND rc.constraint_schema = ?
             AND referenced_column_name IS NOT NULL
+            {table_filter}
         ORDER BY ordinal_position
-    ";
+    ",
+        table_filter = table_filter("kcu.table_name", table),
+    );
 
     debug!("describing table foreign keys, SQL: '{}'", sql);
 
     let result_set = conn
-        .query_raw(sql, &[schema_name.into(), schema_name.into()])
+        .query_raw(
+            &sql,
+            &with_table_param(vec![schema_name.into(), schema_name.into()], table),
+        )
         .await
         .expect("querying for foreign keys");
 
@@ -501,6 +733,7 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
                     constraint_name: Some(constraint_name.clone()),
                     columns: vec![column],
                     referenced_table,
+                    referenced_schema: None,
                     referenced_columns: vec![referenced_column],
                     on_delete_action,
                 };
@@ -520,6 +753,19 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
         .collect()
 }
 
+/// Restricts a query to a single table, when one is given. The table name is always the last parameter.
+fn table_filter(table_name_column: &str, table: Option<&str>) -> String {
+    match table {
+        Some(_) => format!("AND {} = ?", table_name_column),
+        None => String::new(),
+    }
+}
+
+fn with_table_param<'a>(mut params: Vec<Value<'a>>, table: Option<&'a str>) -> Vec<Value<'a>> {
+    params.extend(table.map(Value::from));
+    params
+}
+
 fn get_column_type_and_enum(
     table: &str,
     column_name: &str,
@@ -534,12 +780,14 @@ fn get_column_type_and_enum(
         ("tinyint", "tinyint(1)") => ColumnTypeFamily::Boolean,
         ("tinyint", _) => ColumnTypeFamily::Int,
         ("mediumint", _) => ColumnTypeFamily::Int,
-        ("bigint", _) => ColumnTypeFamily::Int,
+        ("bigint", _) => ColumnTypeFamily::BigInt,
         ("decimal", _) => ColumnTypeFamily::Float,
         ("numeric", _) => ColumnTypeFamily::Float,
         ("float", _) => ColumnTypeFamily::Float,
         ("double", _) => ColumnTypeFamily::Float,
-        ("bit", _) => ColumnTypeFamily::Int,
+        // A single bit is a boolean, wider bit fields are read as bytes.
+        ("bit", "bit(1)") => ColumnTypeFamily::Boolean,
+        ("bit", _) => ColumnTypeFamily::Binary,
         ("date", _) => ColumnTypeFamily::DateTime,
         ("time", _) => ColumnTypeFamily::DateTime,
         ("datetime", _) => ColumnTypeFamily::DateTime,
@@ -552,7 +800,7 @@ fn get_column_type_and_enum(
         ("mediumtext", _) => ColumnTypeFamily::String,
         ("longtext", _) => ColumnTypeFamily::String,
         ("enum", _) => ColumnTypeFamily::Enum(format!("{}_{}", table, column_name)),
-        // XXX: Is this correct?
+        // Sets are represented as strings of comma-separated values, see `parse_value_list` for the allowed values.
         ("set", _) => ColumnTypeFamily::String,
         ("binary", _) => ColumnTypeFamily::Binary,
         ("varbinary", _) => ColumnTypeFamily::Binary,
@@ -576,6 +824,11 @@ fn get_column_type_and_enum(
         data_type: data_type.to_owned(),
         full_data_type: full_data_type.to_owned(),
         character_maximum_length,
+        datetime_precision: None,
+        timezone_aware: false,
+        srid: None,
+        array_elements_required: false,
+        domain: None,
         family: family.clone(),
         arity,
     };
@@ -585,17 +838,50 @@ fn get_column_type_and_enum(
             tpe,
             Some(Enum {
                 name: name.clone(),
-                values: extract_enum_values(&full_data_type),
+                values: parse_value_list(full_data_type).unwrap_or_default(),
             }),
         ),
         _ => (tpe, None),
     }
 }
 
-fn extract_enum_values(full_data_type: &&str) -> Vec<String> {
-    let len = &full_data_type.len() - 1;
-    let vals = &full_data_type[5..len];
-    vals.split(',').map(|v| unquote_string(v)).collect()
+/// Parse the values of an `enum(...)` or `set(...)` full data type, as found in
+/// `information_schema.columns.column_type`. The values are quoted string literals, so they can
+/// contain commas, as well as quotes escaped by doubling them or with a backslash.
+///
+/// Returns `None` if the data type is not a parenthesized list of string literals.
+pub fn parse_value_list(full_data_type: &str) -> Option<Vec<String>> {
+    let start = full_data_type.find('(')?;
+    let mut chars = full_data_type[(start + 1)..].chars().peekable();
+    let mut values = Vec::new();
+
+    loop {
+        if chars.next()? != '\'' {
+            return None;
+        }
+
+        let mut value = String::new();
+
+        loop {
+            match chars.next()? {
+                '\'' if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    value.push('\'');
+                }
+                '\'' => break,
+                '\\' => value.push(chars.next()?),
+                c => value.push(c),
+            }
+        }
+
+        values.push(value);
+
+        match chars.next()? {
+            ',' => continue,
+            ')' => return Some(values),
+            _ => return None,
+        }
+    }
 }
 
 // See https://dev.mysql.com/doc/refman/8.0/en/string-literals.html
@@ -616,3 +902,21 @@ fn unescape_and_unquote_default_string(default: String, flavour: &Flavour) -> St
 
     MYSQL_ESCAPING_RE.replace_all(maybe_unquoted.as_ref(), "$1$2").into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_lists_with_commas_and_quotes_are_parsed() {
+        assert_eq!(
+            parse_value_list("enum('a,b','c')").unwrap(),
+            vec!["a,b".to_owned(), "c".to_owned()]
+        );
+        assert_eq!(
+            parse_value_list(r#"set('it''s','back\\slash','')"#).unwrap(),
+            vec!["it's".to_owned(), r#"back\slash"#.to_owned(), "".to_owned()]
+        );
+        assert!(parse_value_list("varchar(191)").is_none());
+    }
+}
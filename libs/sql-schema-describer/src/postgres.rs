@@ -1,12 +1,38 @@
 //! Postgres description.
 use super::*;
-use quaint::prelude::Queryable;
+use futures::{
+    future::{BoxFuture, FutureExt},
+    stream::{self, StreamExt},
+};
+use quaint::{ast::Value, connector::ResultRow, prelude::Queryable};
 use regex::Regex;
 use std::{borrow::Cow, collections::HashMap, convert::TryInto, sync::Arc};
 use tracing::debug;
 
 pub struct SqlSchemaDescriber {
     conn: Arc<dyn Queryable + Send + Sync + 'static>,
+    include_temporary_tables: bool,
+    hstore_as_json: bool,
+    max_concurrent_table_queries: usize,
+}
+
+/// The per-table catalog information of a schema, keyed by table name.
+#[derive(Default)]
+struct TableCatalog {
+    columns: HashMap<String, Vec<Column>>,
+    foreign_keys: HashMap<String, Vec<ForeignKey>>,
+    indices: HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+    descriptions: HashMap<String, String>,
+    storage_parameters: HashMap<String, Vec<(String, String)>>,
+}
+
+/// The result of one of the independent catalog queries behind a `TableCatalog`.
+enum TableCatalogPart {
+    Columns(HashMap<String, Vec<Column>>),
+    ForeignKeys(HashMap<String, Vec<ForeignKey>>),
+    Indices(HashMap<String, (Vec<Index>, Option<PrimaryKey>)>),
+    Descriptions(HashMap<String, String>),
+    StorageParameters(HashMap<String, Vec<(String, String)>>),
 }
 
 #[async_trait::async_trait]
@@ -29,29 +55,122 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         debug!("describing schema '{}'", schema);
         let sequences = self.get_sequences(schema).await?;
         let enums = self.get_enums(schema).await?;
-        let mut columns = self.get_columns(schema, &enums).await;
-        let mut foreign_keys = self.get_foreign_keys(schema).await;
-        let mut indexes = self.get_indices(schema, &sequences).await;
+        let composite_types = self.get_composite_types(schema).await?;
+        let mut catalog = self.get_table_catalog(schema, None, &enums, &sequences).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
 
+        // Tables can be dropped while we describe the schema, we skip them instead of failing the whole describe.
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            if let Some(table) = get_table(&table_name, &mut catalog) {
+                tables.push(table);
+            }
         }
 
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            composite_types,
+            views: Vec::new(),
         })
     }
+
+    async fn describe_table(&self, schema: &str, name: &str) -> SqlSchemaDescriberResult<Option<Table>> {
+        debug!("describing table '{}' in schema '{}'", name, schema);
+        let table_names = self.get_table_names(schema).await;
+
+        if !table_names.iter().any(|table_name| table_name == name) {
+            return Ok(None);
+        }
+
+        let sequences = self.get_sequences(schema).await?;
+        let enums = self.get_enums(schema).await?;
+        let mut catalog = self.get_table_catalog(schema, Some(name), &enums, &sequences).await;
+
+        Ok(get_table(name, &mut catalog))
+    }
 }
 
 impl SqlSchemaDescriber {
     /// Constructor.
     pub fn new(conn: Arc<dyn Queryable + Send + Sync + 'static>) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+        SqlSchemaDescriber {
+            conn,
+            include_temporary_tables: false,
+            hstore_as_json: false,
+            max_concurrent_table_queries: DEFAULT_MAX_CONCURRENT_TABLE_QUERIES,
+        }
+    }
+
+    /// Limit the number of catalog queries in flight on the connection. With a pooled connection,
+    /// this should not exceed the pool size, or the describer will wait on the pool and starve
+    /// other users of the connection.
+    pub fn with_max_concurrent_table_queries(mut self, limit: usize) -> Self {
+        self.max_concurrent_table_queries = limit.max(1);
+        self
+    }
+
+    /// Run the catalog queries for the tables of the schema (or only `table`) concurrently. They
+    /// do not depend on each other, only on the enums and sequences described before.
+    async fn get_table_catalog(
+        &self,
+        schema: &str,
+        table: Option<&str>,
+        enums: &[Enum],
+        sequences: &[Sequence],
+    ) -> TableCatalog {
+        let queries: Vec<BoxFuture<'_, TableCatalogPart>> = vec![
+            self.get_columns(schema, table, enums)
+                .map(TableCatalogPart::Columns)
+                .boxed(),
+            self.get_foreign_keys(schema, table)
+                .map(TableCatalogPart::ForeignKeys)
+                .boxed(),
+            self.get_indices(schema, table, sequences)
+                .map(TableCatalogPart::Indices)
+                .boxed(),
+            self.get_table_descriptions(schema, table)
+                .map(TableCatalogPart::Descriptions)
+                .boxed(),
+            self.get_storage_parameters(schema, table)
+                .map(TableCatalogPart::StorageParameters)
+                .boxed(),
+        ];
+
+        let mut parts = stream::iter(queries).buffer_unordered(self.max_concurrent_table_queries);
+        let mut catalog = TableCatalog::default();
+
+        while let Some(part) = parts.next().await {
+            match part {
+                TableCatalogPart::Columns(columns) => catalog.columns = columns,
+                TableCatalogPart::ForeignKeys(foreign_keys) => catalog.foreign_keys = foreign_keys,
+                TableCatalogPart::Indices(indices) => catalog.indices = indices,
+                TableCatalogPart::Descriptions(descriptions) => catalog.descriptions = descriptions,
+                TableCatalogPart::StorageParameters(storage_parameters) => {
+                    catalog.storage_parameters = storage_parameters
+                }
+            }
+        }
+
+        catalog
+    }
+
+    /// Also describe temporary tables. They live in the session's own `pg_temp_N` schema, so this
+    /// only matters when describing that schema. MySQL never lists temporary tables in
+    /// `information_schema`, so its describer has no such option.
+    pub fn with_temporary_tables(mut self, include_temporary_tables: bool) -> Self {
+        self.include_temporary_tables = include_temporary_tables;
+        self
+    }
+
+    /// Describe columns of the `hstore` extension type as JSON instead of strings. The key-value
+    /// pairs map naturally to JSON objects, but the database only accepts them in the hstore text
+    /// format, so this is only suitable for reading.
+    pub fn with_hstore_as_json(mut self, hstore_as_json: bool) -> Self {
+        self.hstore_as_json = hstore_as_json;
+        self
     }
 
     async fn get_databases(&self) -> Vec<String> {
@@ -73,14 +192,15 @@ impl SqlSchemaDescriber {
 
     async fn get_table_names(&self, schema: &str) -> Vec<String> {
         debug!("Getting table names");
+        // Temporary tables (`pg_class.relpersistence = 't'`) are listed as `LOCAL TEMPORARY`.
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
             -- Views are not supported yet
-            AND table_type = 'BASE TABLE'
+            AND (table_type = 'BASE TABLE' OR (table_type = 'LOCAL TEMPORARY' AND $2))
             ORDER BY table_name";
         let rows = self
             .conn
-            .query_raw(sql, &[schema.into()])
+            .query_raw(sql, &[schema.into(), self.include_temporary_tables.into()])
             .await
             .expect("get table names ");
         let names = rows
@@ -112,48 +232,61 @@ impl SqlSchemaDescriber {
         size.try_into().unwrap()
     }
 
-    fn get_table(
-        &self,
-        name: &str,
-        columns: &mut HashMap<String, Vec<Column>>,
-        foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
-        indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
-    ) -> Table {
-        debug!("Getting table '{}'", name);
-        let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
-        let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
-        let columns = columns.remove(name).expect("could not get columns");
-        Table {
-            name: name.to_string(),
-            columns,
-            foreign_keys,
-            indices,
-            primary_key,
-        }
-    }
-
-    async fn get_columns(&self, schema: &str, enums: &[Enum]) -> HashMap<String, Vec<Column>> {
+    async fn get_columns(&self, schema: &str, table: Option<&str>, enums: &[Enum]) -> HashMap<String, Vec<Column>> {
         let mut columns: HashMap<String, Vec<Column>> = HashMap::new();
 
-        let sql = r#"
+        // `information_schema.columns` only lists user columns, system columns like `ctid` or `xmin`
+        // are never returned.
+        let sql = format!(
+            r#"
             SELECT
                 table_name,
                 column_name,
                 data_type,
                 udt_name as full_data_type,
                 character_maximum_length,
+                datetime_precision,
                 column_default,
                 is_nullable,
                 is_identity,
-                data_type
-            FROM information_schema.columns
+                identity_generation,
+                identity_start,
+                identity_increment,
+                data_type,
+                -- Postgres ignores NOT NULL on array elements, so it has to be enforced with a check constraint.
+                EXISTS (
+                    SELECT 1
+                    FROM pg_constraint con
+                    INNER JOIN pg_class rel ON rel.oid = con.conrelid
+                    INNER JOIN pg_namespace nsp ON nsp.oid = rel.relnamespace
+                    WHERE con.contype = 'c'
+                        AND nsp.nspname = info.table_schema
+                        AND rel.relname = info.table_name
+                        AND pg_get_constraintdef(con.oid)
+                            LIKE 'CHECK ((array_position(' || quote_ident(info.column_name) || ', NULL::%) IS NULL))'
+                ) AS array_elements_required,
+                -- The type columns describe the base type of a domain, its checks have to be queried separately.
+                domain_name,
+                (
+                    SELECT string_agg(pg_get_constraintdef(con.oid), E'\n' ORDER BY con.conname)
+                    FROM pg_constraint con
+                    INNER JOIN pg_type typ ON typ.oid = con.contypid
+                    INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+                    WHERE con.contype = 'c'
+                        AND nsp.nspname = info.domain_schema
+                        AND typ.typname = info.domain_name
+                ) AS domain_checks
+            FROM information_schema.columns info
             WHERE table_schema = $1
+            {table_filter}
             ORDER BY ordinal_position
-        "#;
+        "#,
+            table_filter = table_filter("table_name", table),
+        );
 
         let rows = self
             .conn
-            .query_raw(&sql, &[schema.into()])
+            .query_raw(&sql, &query_params(schema, table))
             .await
             .expect("querying for columns");
 
@@ -173,6 +306,10 @@ impl SqlSchemaDescriber {
                 .and_then(|x| x.to_string())
                 .expect("get full_data_type aka udt_name");
             let character_maximum_length = col.get("character_maximum_length").and_then(|x| x.as_i64());
+            let datetime_precision = col
+                .get("datetime_precision")
+                .and_then(|x| x.as_i64())
+                .map(|precision| precision as u32);
             let is_identity_str = col
                 .get("is_identity")
                 .and_then(|x| x.to_string())
@@ -202,32 +339,55 @@ impl SqlSchemaDescriber {
                 ColumnArity::Nullable
             };
 
-            let tpe = get_column_type(
+            let mut tpe = get_column_type(
                 data_type.as_ref(),
                 &full_data_type,
                 character_maximum_length,
+                datetime_precision,
                 arity,
                 enums,
+                self.hstore_as_json,
             );
 
+            tpe.array_elements_required = tpe.arity.is_list()
+                && col
+                    .get("array_elements_required")
+                    .and_then(|x| x.as_bool())
+                    .unwrap_or(false);
+
+            tpe.domain = col.get("domain_name").and_then(|x| x.to_string()).map(|name| Domain {
+                name,
+                checks: col
+                    .get("domain_checks")
+                    .and_then(|x| x.to_string())
+                    .map(|checks| checks.lines().map(String::from).collect())
+                    .unwrap_or_default(),
+            });
+
             let default = match col.get("column_default") {
                 None => None,
                 Some(param_value) => match param_value.to_string() {
                     None => None,
+                    Some(default_string) if tpe.arity.is_list() => {
+                        Some(match parse_array_default(&default_string, &tpe.family) {
+                            Some(values) => DefaultValue::LIST(values),
+                            None => DefaultValue::DBGENERATED(default_string),
+                        })
+                    }
                     Some(default_string) => {
                         Some(match &tpe.family {
-                            ColumnTypeFamily::Int => match parse_int(&default_string) {
+                            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                                 Some(int_value) => DefaultValue::VALUE(int_value),
-                                None => match is_autoincrement(&default_string, schema, &table_name, &col_name) {
+                                None => match is_autoincrement(&default_string, &table_name, &col_name) {
                                     true => DefaultValue::SEQUENCE(default_string),
                                     false => DefaultValue::DBGENERATED(default_string),
                                 },
                             },
-                            ColumnTypeFamily::Float => match parse_float(&default_string) {
+                            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => match parse_float(&default_string) {
                                 Some(float_value) => DefaultValue::VALUE(float_value),
                                 None => DefaultValue::DBGENERATED(default_string),
                             },
-                            ColumnTypeFamily::Boolean => match parse_bool(&default_string) {
+                            ColumnTypeFamily::Boolean => match parse_postgres_bool(&default_string) {
                                 Some(bool_value) => DefaultValue::VALUE(bool_value),
                                 None => DefaultValue::DBGENERATED(default_string),
                             },
@@ -256,27 +416,23 @@ impl SqlSchemaDescriber {
                             ColumnTypeFamily::LogSequenceNumber => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::TextSearch => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::TransactionId => DefaultValue::DBGENERATED(default_string),
-                            ColumnTypeFamily::Enum(enum_name) => {
-                                let enum_suffix_without_quotes = format!("::{}", enum_name);
-                                let enum_suffix_with_quotes = format!("::\"{}\"", enum_name);
-                                if default_string.ends_with(&enum_suffix_with_quotes) {
-                                    DefaultValue::VALUE(PrismaValue::Enum(unquote_string(
-                                        &default_string.replace(&enum_suffix_with_quotes, ""),
-                                    )))
-                                } else if default_string.ends_with(&enum_suffix_without_quotes) {
-                                    DefaultValue::VALUE(PrismaValue::Enum(unquote_string(
-                                        &default_string.replace(&enum_suffix_without_quotes, ""),
-                                    )))
-                                } else {
-                                    DefaultValue::DBGENERATED(default_string)
-                                }
-                            }
+                            ColumnTypeFamily::Enum(enum_name) => match parse_enum_default(&default_string, enum_name) {
+                                Some(enum_value) => DefaultValue::VALUE(enum_value),
+                                None => DefaultValue::DBGENERATED(default_string),
+                            },
                             ColumnTypeFamily::Unsupported(_) => DefaultValue::DBGENERATED(default_string),
                         })
                     }
                 },
             };
 
+            // Identity columns have no column default, their sequence options are kept instead.
+            let default = if is_identity {
+                Some(DefaultValue::IDENTITY(get_identity_options(&col)))
+            } else {
+                default
+            };
+
             let is_auto_increment = is_identity
                 || match default {
                     Some(DefaultValue::SEQUENCE(_)) => true,
@@ -298,14 +454,93 @@ impl SqlSchemaDescriber {
         columns
     }
 
+    /// Returns a map from table name to the table's comment, for the tables that have one.
+    async fn get_table_descriptions(&self, schema: &str, table: Option<&str>) -> HashMap<String, String> {
+        let sql = format!(
+            "
+            SELECT cl.relname AS table_name, obj_description(cl.oid, 'pg_class') AS description
+            FROM pg_class cl
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1
+            AND obj_description(cl.oid, 'pg_class') IS NOT NULL
+            {table_filter}
+            ",
+            table_filter = table_filter("cl.relname", table),
+        );
+        let rows = self
+            .conn
+            .query_raw(&sql, &query_params(schema, table))
+            .await
+            .expect("get table descriptions");
+        let descriptions = rows
+            .into_iter()
+            .filter_map(|row| {
+                let table_name = row.get("table_name").and_then(|x| x.to_string())?;
+                let description = row.get("description").and_then(|x| x.to_string())?;
+
+                Some((table_name, description))
+            })
+            .collect();
+
+        debug!("Found table descriptions: {:?}", descriptions);
+        descriptions
+    }
+
+    /// Returns a map from table or index name to its storage parameters (`reloptions`), for the
+    /// relations that have any. Tables and indexes share a namespace, so their names cannot clash.
+    async fn get_storage_parameters(
+        &self,
+        schema: &str,
+        table: Option<&str>,
+    ) -> HashMap<String, Vec<(String, String)>> {
+        let sql = format!(
+            "
+            SELECT cl.relname AS name, unnest(cl.reloptions) AS option
+            FROM pg_class cl
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            LEFT JOIN pg_index ix ON ix.indexrelid = cl.oid
+            JOIN pg_class tbl ON tbl.oid = COALESCE(ix.indrelid, cl.oid)
+            WHERE ns.nspname = $1
+            AND cl.relkind IN ('r', 'i')
+            AND cl.reloptions IS NOT NULL
+            {table_filter}
+            ",
+            table_filter = table_filter("tbl.relname", table),
+        );
+        let rows = self
+            .conn
+            .query_raw(&sql, &query_params(schema, table))
+            .await
+            .expect("get storage parameters");
+        let mut storage_parameters: HashMap<String, Vec<(String, String)>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("name");
+            let option = row.get("option").and_then(|x| x.to_string()).expect("option");
+            let mut parts = option.splitn(2, '=');
+            let key = parts.next().unwrap_or_default().to_owned();
+            let value = parts.next().unwrap_or_default().to_owned();
+
+            storage_parameters
+                .entry(name)
+                .or_insert_with(Vec::new)
+                .push((key, value));
+        }
+
+        debug!("Found storage parameters: {:?}", storage_parameters);
+        storage_parameters
+    }
+
     /// Returns a map from table name to foreign keys.
-    async fn get_foreign_keys(&self, schema: &str) -> HashMap<String, Vec<ForeignKey>> {
+    async fn get_foreign_keys(&self, schema: &str, table: Option<&str>) -> HashMap<String, Vec<ForeignKey>> {
         // The `generate_subscripts` in the inner select is needed because the optimizer is free to reorganize the unnested rows if not explicitly ordered.
-        let sql = r#"
+        let sql = format!(
+            r#"
             SELECT
                 con.oid as "con_id",
                 att2.attname as "child_column",
                 cl.relname as "parent_table",
+                parent_ns.nspname as "parent_schema",
                 att.attname as "parent_column",
                 con.confdeltype,
                 conname as constraint_name,
@@ -330,15 +565,20 @@ impl SqlSchemaDescriber {
                 WHERE
                     ns.nspname = $1
                     and con1.contype = 'f'
+                    {table_filter}
                     ORDER BY colidx
             ) con
             JOIN pg_attribute att on
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
-            ORDER BY con_id, con.colidx"#;
+            ORDER BY con_id, con.colidx"#,
+            table_filter = table_filter("cl.relname", table),
+        );
         debug!("describing table foreign keys, SQL: '{}'", sql);
 
         // One foreign key with multiple columns will be represented here as several
@@ -346,7 +586,7 @@ impl SqlSchemaDescriber {
         // objects.
         let result_set = self
             .conn
-            .query_raw(&sql, &[schema.into()])
+            .query_raw(&sql, &query_params(schema, table))
             .await
             .expect("querying for foreign keys");
         let mut intermediate_fks: HashMap<i64, (String, ForeignKey)> = HashMap::new();
@@ -361,6 +601,10 @@ impl SqlSchemaDescriber {
                 .get("parent_table")
                 .and_then(|x| x.to_string())
                 .expect("get parent_table");
+            let referenced_schema = row
+                .get("parent_schema")
+                .and_then(|x| x.to_string())
+                .expect("get parent_schema");
             let referenced_column = row
                 .get("parent_column")
                 .and_then(|x| x.to_string())
@@ -395,6 +639,8 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: Some(referenced_schema)
+                            .filter(|referenced_schema| referenced_schema != schema),
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                     };
@@ -427,11 +673,13 @@ impl SqlSchemaDescriber {
     async fn get_indices(
         &self,
         schema: &str,
+        table: Option<&str>,
         sequences: &[Sequence],
     ) -> HashMap<String, (Vec<Index>, Option<PrimaryKey>)> {
         let mut indexes_map = HashMap::new();
 
-        let sql = r#"
+        let sql = format!(
+            r#"
         SELECT
             indexInfos.relname as name,
             columnInfos.attname AS column_name,
@@ -439,6 +687,19 @@ impl SqlSchemaDescriber {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            EXISTS (
+                SELECT 1 FROM pg_constraint
+                WHERE pg_constraint.conindid = indexInfos.oid AND pg_constraint.contype = 'u'
+            ) AS is_constraint,
+            COALESCE((
+                SELECT condeferrable FROM pg_constraint
+                WHERE pg_constraint.conindid = indexInfos.oid AND pg_constraint.contype = 'u'
+            ), false) AS is_deferrable,
+            COALESCE((
+                SELECT condeferred FROM pg_constraint
+                WHERE pg_constraint.conindid = indexInfos.oid AND pg_constraint.contype = 'u'
+            ), false) AS is_initially_deferred,
+            rawIndex.predicate,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
@@ -452,9 +713,10 @@ impl SqlSchemaDescriber {
                     indisunique,
                     indisprimary,
                     pg_index.indkey AS indkey,
-                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx
+                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx,
+                    pg_get_expr(pg_index.indpred, pg_index.indrelid) AS predicate
                 FROM pg_index
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
+                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey, predicate
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
@@ -474,13 +736,16 @@ impl SqlSchemaDescriber {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+            {table_filter}
+        GROUP BY tableInfos.relname, indexInfos.oid, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, rawIndex.predicate, columnInfos.attname, rawIndex.indkeyidx
         ORDER BY rawIndex.indkeyidx
-        "#;
+        "#,
+            table_filter = table_filter("tableInfos.relname", table),
+        );
         debug!("Getting indices: {}", sql);
         let rows = self
             .conn
-            .query_raw(&sql, &[schema.into()])
+            .query_raw(&sql, &query_params(schema, table))
             .await
             .expect("querying for indices");
 
@@ -490,11 +755,21 @@ impl SqlSchemaDescriber {
                 column_name,
                 is_primary_key,
                 is_unique,
+                is_constraint,
+                is_deferrable,
+                is_initially_deferred,
                 name,
+                predicate,
                 sequence_name,
                 table_name,
             } = quaint::serde::from_row::<IndexRow>(index).unwrap();
 
+            let deferrable = match (is_deferrable, is_initially_deferred) {
+                (true, true) => Some(Deferrable::InitiallyDeferred),
+                (true, false) => Some(Deferrable::InitiallyImmediate),
+                (false, _) => None,
+            };
+
             if is_primary_key {
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) =
                     indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
@@ -517,14 +792,19 @@ impl SqlSchemaDescriber {
                             columns: vec![column_name],
                             sequence,
                             constraint_name: Some(name.clone()),
+                            autoincrement: false,
                         });
                     }
                 }
             } else {
                 let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
+                // A unique constraint and its backing index share a name, so they are collapsed
+                // into a single index tagged with `is_constraint`.
                 if let Some(existing_index) = entry.0.iter_mut().find(|idx| idx.name == name) {
                     existing_index.columns.push(column_name);
+                    existing_index.is_constraint |= is_constraint;
+                    existing_index.deferrable = existing_index.deferrable.or(deferrable);
                 } else {
                     entry.0.push(Index {
                         name,
@@ -533,6 +813,10 @@ impl SqlSchemaDescriber {
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
+                        is_constraint,
+                        predicate,
+                        storage_parameters: Vec::new(),
+                        deferrable,
                     })
                 }
             }
@@ -606,6 +890,51 @@ impl SqlSchemaDescriber {
         debug!("Found enums: {:?}", enums);
         Ok(enums)
     }
+
+    async fn get_composite_types(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<CompositeType>> {
+        debug!("Getting composite types");
+        // Every table has a composite row type too, relkind 'c' restricts the query to standalone composite types.
+        let sql = "SELECT t.typname AS type_name,
+                a.attname AS field_name,
+                format_type(a.atttypid, a.atttypmod) AS data_type
+            FROM pg_type t
+            JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+            JOIN pg_class c ON c.oid = t.typrelid
+            JOIN pg_attribute a ON a.attrelid = c.oid
+            WHERE n.nspname = $1
+            AND t.typtype = 'c'
+            AND c.relkind = 'c'
+            AND a.attnum > 0
+            AND NOT a.attisdropped
+            ORDER BY type_name, a.attnum";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .expect("querying for composite types");
+        let mut composite_types: Vec<CompositeType> = Vec::new();
+
+        for row in rows.into_iter() {
+            debug!("Got composite type row: {:?}", row);
+            let type_name = row.get("type_name").and_then(|x| x.to_string()).expect("type_name");
+            let field = CompositeTypeField {
+                name: row.get("field_name").and_then(|x| x.to_string()).expect("field_name"),
+                data_type: row.get("data_type").and_then(|x| x.to_string()).expect("data_type"),
+            };
+
+            // Rows are ordered by type name, so the fields of a type are always consecutive.
+            match composite_types.last_mut() {
+                Some(composite_type) if composite_type.name == type_name => composite_type.fields.push(field),
+                _ => composite_types.push(CompositeType {
+                    name: type_name,
+                    fields: vec![field],
+                }),
+            }
+        }
+
+        debug!("Found composite types: {:?}", composite_types);
+        Ok(composite_types)
+    }
 }
 
 #[derive(Deserialize)]
@@ -614,16 +943,59 @@ struct IndexRow {
     column_name: String,
     is_unique: bool,
     is_primary_key: bool,
+    is_constraint: bool,
+    is_deferrable: bool,
+    is_initially_deferred: bool,
+    predicate: Option<String>,
     table_name: String,
     sequence_name: Option<String>,
 }
 
+/// Restricts a query to a single table, when one is given. The table name is always the second parameter.
+fn table_filter(table_name_column: &str, table: Option<&str>) -> String {
+    match table {
+        Some(_) => format!("AND {} = $2", table_name_column),
+        None => String::new(),
+    }
+}
+
+fn query_params<'a>(schema: &'a str, table: Option<&'a str>) -> Vec<Value<'a>> {
+    let mut params: Vec<Value<'a>> = vec![schema.into()];
+    params.extend(table.map(Value::from));
+    params
+}
+
+/// Read the generation kind and sequence options of an identity column from its
+/// `information_schema.columns` row. The options are returned as strings by Postgres.
+fn get_identity_options(col: &ResultRow) -> IdentityOptions {
+    let identity_i64 = |name: &str| -> i64 {
+        col.get(name)
+            .and_then(|x| x.to_string())
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(1)
+    };
+
+    let always = col
+        .get("identity_generation")
+        .and_then(|x| x.to_string())
+        .map(|generation| generation.eq_ignore_ascii_case("always"))
+        .unwrap_or(false);
+
+    IdentityOptions {
+        always,
+        start: identity_i64("identity_start"),
+        increment: identity_i64("identity_increment"),
+    }
+}
+
 fn get_column_type<'a>(
     data_type: &str,
     full_data_type: &'a str,
     character_maximum_length: Option<i64>,
+    datetime_precision: Option<u32>,
     arity: ColumnArity,
     enums: &[Enum],
+    hstore_as_json: bool,
 ) -> ColumnType {
     use ColumnTypeFamily::*;
     let trim = |name: &'a str| name.trim_start_matches('_');
@@ -634,7 +1006,7 @@ fn get_column_type<'a>(
         x if data_type == "ARRAY" && x.starts_with('_') && enum_exists(trim(x)) => Enum(trim(x).to_owned()),
         "int2" | "_int2" => Int,
         "int4" | "_int4" => Int,
-        "int8" | "_int8" => Int,
+        "int8" | "_int8" => BigInt,
         "oid" | "_oid" => Int,
         "float4" | "_float4" => Float,
         "float8" | "_float8" => Float,
@@ -668,13 +1040,34 @@ fn get_column_type<'a>(
         "tsquery" | "_tsquery" => TextSearch,
         "tsvector" | "_tsvector" => TextSearch,
         "txid_snapshot" | "_txid_snapshot" => TransactionId,
+        // Network address types have no Prisma equivalent, they are read as strings.
         "inet" | "_inet" => String,
+        "cidr" | "_cidr" => String,
+        "macaddr" | "_macaddr" => String,
+        // xml documents are not validated by Prisma, they are read as strings as well.
+        "xml" | "_xml" => String,
+        // hstore is an extension type (reported as USER-DEFINED), its key-value pairs are read in
+        // their text representation unless requested otherwise.
+        "hstore" | "_hstore" if hstore_as_json => Json,
+        "hstore" | "_hstore" => String,
         data_type => Unsupported(data_type.into()),
     };
+    // Intervals also report a precision, but they are not read as datetimes.
+    let datetime_precision = match family {
+        DateTime => datetime_precision,
+        _ => None,
+    };
+    let timezone_aware = matches!(trim(full_data_type), "timestamptz" | "timetz");
+
     ColumnType {
         data_type: data_type.to_owned(),
         full_data_type: full_data_type.to_owned(),
         character_maximum_length,
+        datetime_precision,
+        timezone_aware,
+        srid: None,
+        array_elements_required: false,
+        domain: None,
         family,
         arity,
     }
@@ -684,7 +1077,7 @@ static RE_SEQ: Lazy<Regex> = Lazy::new(|| Regex::new("^(?:.+\\.)?\"?([^.\"]+)\"?
 
 static AUTOINCREMENT_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(
-        r#"nextval\('(?:"(?P<schema_name>.+)"\.)?(")?(?P<table_and_column_name>.+)_seq(?:[0-9]+)?(")?'::regclass\)"#,
+        r#"nextval\('(?:"?(?P<schema_name>[^"]+)"?\.)?(")?(?P<table_and_column_name>.+)_seq(?:[0-9]+)?(")?'::regclass\)"#,
     )
     .unwrap()
 });
@@ -692,34 +1085,61 @@ static AUTOINCREMENT_REGEX: Lazy<Regex> = Lazy::new(|| {
 /// Returns whether a particular sequence (`value`) matches the provided column info.
 /// todo this only seems to work on sequence names autogenerated by barrel???
 /// the names for manually created and named sequences wont match
-fn is_autoincrement(value: &str, schema_name: &str, table_name: &str, column_name: &str) -> bool {
+///
+/// The sequence may be schema-qualified, quoted or not, and does not have to live in the schema of the table: a
+/// serial whose owned sequence was moved to another schema is still an autoincrementing column.
+fn is_autoincrement(value: &str, table_name: &str, column_name: &str) -> bool {
     AUTOINCREMENT_REGEX
         .captures(value)
         .and_then(|captures| {
-            captures
-                .name("schema_name")
-                .map(|matched| matched.as_str())
-                .or(Some(schema_name))
-                .filter(|matched| *matched == schema_name)
-                .and_then(|_| {
-                    captures.name("table_and_column_name").filter(|matched| {
-                        let expected_len = table_name.len() + column_name.len() + 1;
-
-                        if matched.as_str().len() != expected_len {
-                            return false;
-                        }
-
-                        let table_name_segments = table_name.split('_');
-                        let column_name_segments = column_name.split('_');
-                        let matched_segments = matched.as_str().split('_');
-                        matched_segments
-                            .zip(table_name_segments.chain(column_name_segments))
-                            .all(|(found, expected)| found == expected)
-                    })
-                })
-                .map(|_| true)
+            captures.name("table_and_column_name").filter(|matched| {
+                let expected_len = table_name.len() + column_name.len() + 1;
+
+                if matched.as_str().len() != expected_len {
+                    return false;
+                }
+
+                let table_name_segments = table_name.split('_');
+                let column_name_segments = column_name.split('_');
+                let matched_segments = matched.as_str().split('_');
+                matched_segments
+                    .zip(table_name_segments.chain(column_name_segments))
+                    .all(|(found, expected)| found == expected)
+            })
         })
-        .unwrap_or(false)
+        .is_some()
+}
+
+/// Assemble a table from the bulk-queried columns, foreign keys and indices. Returns `None` when no columns were
+/// found for the table, which happens when it was dropped while describing.
+fn get_table(name: &str, catalog: &mut TableCatalog) -> Option<Table> {
+    debug!("Getting table '{}'", name);
+    let columns = match catalog.columns.remove(name) {
+        Some(columns) => columns,
+        None => {
+            debug!("Table '{}' has no columns, it was probably dropped. Skipping it.", name);
+            return None;
+        }
+    };
+    let (mut indices, primary_key) = catalog.indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
+    let foreign_keys = catalog.foreign_keys.remove(name).unwrap_or_else(Vec::new);
+
+    for index in &mut indices {
+        index.storage_parameters = catalog.storage_parameters.remove(&index.name).unwrap_or_else(Vec::new);
+    }
+
+    Some(Table {
+        name: name.to_string(),
+        columns,
+        foreign_keys,
+        engine: None,
+        default_character_set: None,
+        default_collation: None,
+        description: catalog.descriptions.remove(name),
+        storage_parameters: catalog.storage_parameters.remove(name).unwrap_or_else(Vec::new),
+        indices,
+        primary_key,
+    })
 }
 
 fn unsuffix_default_literal<'a>(literal: &'a str, data_type: &str, full_data_type: &str) -> Option<Cow<'a, str>> {
@@ -738,6 +1158,116 @@ fn unsuffix_default_literal<'a>(literal: &'a str, data_type: &str, full_data_typ
     Some(first_capture.into())
 }
 
+/// Postgres normalizes boolean defaults to `true` and `false`, but we also accept the quoted and
+/// cast spellings of boolean literals, e.g. `'t'::boolean`.
+///
+/// See https://www.postgresql.org/docs/current/datatype-boolean.html
+fn parse_postgres_bool(default_string: &str) -> Option<PrismaValue> {
+    let literal = unsuffix_default_literal(default_string, "boolean", "bool").unwrap_or_else(|| default_string.into());
+
+    match literal.trim_matches('\'').to_lowercase().as_str() {
+        "t" | "true" | "y" | "yes" | "on" | "1" => Some(PrismaValue::Boolean(true)),
+        "f" | "false" | "n" | "no" | "off" | "0" => Some(PrismaValue::Boolean(false)),
+        _ => None,
+    }
+}
+
+/// Enum defaults are cast to the enum type, e.g. `'black'::color` or `'Black'::"Color"`.
+fn parse_enum_default(default_string: &str, enum_name: &str) -> Option<PrismaValue> {
+    let enum_suffix_with_quotes = format!("::\"{}\"", enum_name);
+    let enum_suffix_without_quotes = format!("::{}", enum_name);
+
+    [enum_suffix_with_quotes, enum_suffix_without_quotes]
+        .iter()
+        .find(|suffix| default_string.ends_with(suffix.as_str()))
+        .map(|suffix| PrismaValue::Enum(unquote_string(&default_string[..default_string.len() - suffix.len()])))
+}
+
+/// Postgres renders array defaults as quoted array literals with a type cast, e.g. `'{1,2,3}'::integer[]`. We only
+/// handle one-dimensional arrays of non-null elements, anything else is left to be a db generated default. Enum arrays
+/// declared with the array constructor are rendered as `ARRAY['black'::color, 'white'::color]`.
+///
+/// See https://www.postgresql.org/docs/current/arrays.html#ARRAYS-INPUT
+fn parse_array_default(default_string: &str, family: &ColumnTypeFamily) -> Option<Vec<PrismaValue>> {
+    static POSTGRES_ARRAY_DEFAULT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?ms)^'\{(.*)\}'(::.*)?$"#).unwrap());
+    static POSTGRES_ARRAY_CONSTRUCTOR_DEFAULT_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?ms)^ARRAY\[(.*?)\](::.*)?$"#).unwrap());
+
+    if let ColumnTypeFamily::Enum(enum_name) = family {
+        if let Some(captures) = POSTGRES_ARRAY_CONSTRUCTOR_DEFAULT_RE.captures(default_string) {
+            return captures
+                .get(1)
+                .unwrap()
+                .as_str()
+                .split(',')
+                .map(|element| parse_enum_default(element.trim(), enum_name))
+                .collect();
+        }
+    }
+
+    let captures = POSTGRES_ARRAY_DEFAULT_RE.captures(default_string)?;
+    let elements = captures.get(1).unwrap().as_str().replace("''", "'");
+
+    split_array_literal(&elements)?
+        .into_iter()
+        .map(|(element, quoted)| match family {
+            _ if !quoted && element.eq_ignore_ascii_case("null") => None,
+            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => parse_int(&element),
+            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => parse_float(&element),
+            ColumnTypeFamily::Boolean => parse_postgres_bool(&element),
+            ColumnTypeFamily::String => Some(PrismaValue::String(element)),
+            ColumnTypeFamily::Enum(_) => Some(PrismaValue::Enum(element)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Split the elements of a one-dimensional array literal, e.g. `1,"a b","say \"hi\""`. Double quoted elements are
+/// unescaped and flagged with `true`, so that a quoted `"NULL"` can be told apart from a null element.
+fn split_array_literal(literal: &str) -> Option<Vec<(String, bool)>> {
+    let mut elements = Vec::new();
+    let mut chars = literal.chars().peekable();
+
+    if literal.is_empty() {
+        return Some(elements);
+    }
+
+    loop {
+        let mut element = String::new();
+        let quoted = chars.peek() == Some(&'"');
+
+        if quoted {
+            chars.next();
+
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => element.push(chars.next()?),
+                    c => element.push(c),
+                }
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                match c {
+                    ',' => break,
+                    '{' | '}' | '"' | '\\' => return None,
+                    c => element.push(c),
+                }
+
+                chars.next();
+            }
+        }
+
+        elements.push((element, quoted));
+
+        match chars.next() {
+            Some(',') => (),
+            None => return Some(elements),
+            Some(_) => return None,
+        }
+    }
+}
+
 // See https://www.postgresql.org/docs/9.3/sql-syntax-lexical.html
 fn process_string_literal(literal: &str) -> Cow<'_, str> {
     static POSTGRES_STRING_DEFAULT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?ms)^B?'(.*)'$"#).unwrap());
@@ -780,29 +1310,31 @@ mod tests {
         let col_name = "id";
 
         let non_autoincrement = "_seq";
-        assert!(!is_autoincrement(non_autoincrement, schema_name, table_name, col_name));
+        assert!(!is_autoincrement(non_autoincrement, table_name, col_name));
 
         let autoincrement = format!(
             r#"nextval('"{}"."{}_{}_seq"'::regclass)"#,
             schema_name, table_name, col_name
         );
-        assert!(is_autoincrement(&autoincrement, schema_name, table_name, col_name));
+        assert!(is_autoincrement(&autoincrement, table_name, col_name));
 
         let autoincrement_with_number = format!(
             r#"nextval('"{}"."{}_{}_seq1"'::regclass)"#,
             schema_name, table_name, col_name
         );
-        assert!(is_autoincrement(
-            &autoincrement_with_number,
-            schema_name,
-            table_name,
-            col_name
-        ));
+        assert!(is_autoincrement(&autoincrement_with_number, table_name, col_name));
 
         let autoincrement_without_schema = format!(r#"nextval('"{}_{}_seq1"'::regclass)"#, table_name, col_name);
+        assert!(is_autoincrement(&autoincrement_without_schema, table_name, col_name));
+
+        let autoincrement_in_other_schema =
+            format!(r#"nextval('"other"."{}_{}_seq"'::regclass)"#, table_name, col_name);
+        assert!(is_autoincrement(&autoincrement_in_other_schema, table_name, col_name));
+
+        let autoincrement_with_unquoted_schema =
+            format!(r#"nextval('other."{}_{}_seq"'::regclass)"#, table_name, col_name);
         assert!(is_autoincrement(
-            &autoincrement_without_schema,
-            schema_name,
+            &autoincrement_with_unquoted_schema,
             table_name,
             col_name
         ));
@@ -812,7 +1344,6 @@ mod tests {
             r#"nextval('"compound_table_compound_column_name_seq"'::regclass)"#;
         assert!(is_autoincrement(
             &autoincrement_with_ambiguous_table_and_column_names,
-            "<ignored>",
             "compound_table",
             "compound_column_name",
         ));
@@ -823,9 +1354,83 @@ mod tests {
             r#"nextval('"compound_table_something_compound_column_name_seq"'::regclass)"#;
         assert!(!is_autoincrement(
             &autoincrement_with_ambiguous_table_and_column_names,
-            "<ignored>",
             "compound_table",
             "compound_column_name",
         ));
     }
+
+    #[test]
+    fn enum_array_defaults_are_parsed() {
+        let family = ColumnTypeFamily::Enum("color".to_owned());
+        let expected = Some(vec![
+            PrismaValue::Enum("black".to_owned()),
+            PrismaValue::Enum("white".to_owned()),
+        ]);
+
+        assert_eq!(
+            parse_array_default("ARRAY['black'::color, 'white'::color]", &family),
+            expected
+        );
+        assert_eq!(parse_array_default("'{black,white}'::color[]", &family), expected);
+        assert_eq!(
+            parse_array_default("ARRAY['black'::other, 'white'::other]", &family),
+            None
+        );
+    }
+
+    #[test]
+    fn quoted_string_array_defaults_are_parsed() {
+        let family = ColumnTypeFamily::String;
+        let expected = Some(vec![
+            PrismaValue::String("a b".to_owned()),
+            PrismaValue::String("it's".to_owned()),
+            PrismaValue::String(r#"say "hi" \o/"#.to_owned()),
+            PrismaValue::String("NULL".to_owned()),
+            PrismaValue::String(String::new()),
+        ]);
+
+        assert_eq!(
+            parse_array_default(r#"'{"a b",it''s,"say \"hi\" \\o/","NULL",""}'::text[]"#, &family),
+            expected
+        );
+        assert_eq!(parse_array_default("'{a,NULL}'::text[]", &family), None);
+        assert_eq!(parse_array_default("'{{a},{b}}'::text[]", &family), None);
+    }
+
+    #[test]
+    fn tables_dropped_while_describing_are_skipped() {
+        let mut columns: HashMap<String, Vec<Column>> = HashMap::new();
+        columns.insert(
+            "Cat".to_owned(),
+            vec![Column {
+                name: "id".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                default: None,
+                auto_increment: false,
+            }],
+        );
+
+        let mut foreign_keys = HashMap::new();
+        let mut indices = HashMap::new();
+        let mut descriptions = HashMap::new();
+        let mut storage_parameters = HashMap::new();
+
+        // "Dog" was listed by `get_table_names`, but dropped before its columns were queried.
+        let tables: Vec<Table> = ["Cat", "Dog"]
+            .iter()
+            .filter_map(|name| {
+                get_table(
+                    name,
+                    &mut columns,
+                    &mut foreign_keys,
+                    &mut indices,
+                    &mut descriptions,
+                    &mut storage_parameters,
+                )
+            })
+            .collect();
+
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].name, "Cat");
+    }
 }
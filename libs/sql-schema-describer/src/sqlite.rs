@@ -1,11 +1,16 @@
 //! SQLite description.
 use super::*;
+use futures::{
+    future,
+    stream::{self, StreamExt},
+};
 use quaint::{ast::Value, prelude::Queryable};
 use std::{borrow::Cow, collections::HashMap, convert::TryInto, sync::Arc};
 use tracing::debug;
 
 pub struct SqlSchemaDescriber {
     conn: Arc<dyn Queryable + Send + Sync + 'static>,
+    max_concurrent_table_queries: usize,
 }
 
 #[async_trait::async_trait]
@@ -28,20 +33,35 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         debug!("describing schema '{}'", schema);
         let table_names: Vec<String> = self.get_table_names(schema).await;
 
-        let mut tables = Vec::with_capacity(table_names.len());
-
-        for table_name in table_names.iter().filter(|table| !is_system_table(&table)) {
-            tables.push(self.get_table(schema, table_name).await)
-        }
+        // Tables can be dropped while we describe the schema, we skip them instead of failing the whole describe.
+        let mut tables: Vec<Table> = stream::iter(table_names.iter().filter(|table| !is_system_table(&table)))
+            .map(|table_name| self.get_table(schema, table_name))
+            .buffered(self.max_concurrent_table_queries)
+            .filter_map(future::ready)
+            .collect()
+            .await;
 
         //sqlite allows foreign key definitions without specifying the referenced columns, it then assumes the pk is used
         let mut foreign_keys_without_referenced_columns = vec![];
         for (table_index, table) in tables.iter().enumerate() {
             for (fk_index, foreign_key) in table.foreign_keys.iter().enumerate() {
                 if foreign_key.referenced_columns.is_empty() {
-                    let referenced_table = tables.iter().find(|t| t.name == foreign_key.referenced_table).unwrap();
-                    let referenced_pk = referenced_table.primary_key.as_ref().unwrap();
-                    foreign_keys_without_referenced_columns.push((table_index, fk_index, referenced_pk.columns.clone()))
+                    let referenced_pk = tables
+                        .iter()
+                        .find(|t| t.name == foreign_key.referenced_table)
+                        .and_then(|referenced_table| referenced_table.primary_key.as_ref());
+
+                    match referenced_pk {
+                        Some(referenced_pk) => foreign_keys_without_referenced_columns.push((
+                            table_index,
+                            fk_index,
+                            referenced_pk.columns.clone(),
+                        )),
+                        None => debug!(
+                            "Could not resolve the primary key of '{}', referenced from '{}'.",
+                            foreign_key.referenced_table, table.name
+                        ),
+                    }
                 }
             }
         }
@@ -50,20 +70,61 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views(schema).await;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
+            // There are no composite types in SQLite.
+            composite_types: vec![],
             tables,
+            views,
         })
     }
+
+    async fn describe_table(&self, schema: &str, name: &str) -> SqlSchemaDescriberResult<Option<Table>> {
+        debug!("describing table '{}' in schema '{}'", name, schema);
+        let table_names: Vec<String> = self.get_table_names(schema).await;
+
+        if is_system_table(name) || !table_names.iter().any(|table_name| table_name == name) {
+            return Ok(None);
+        }
+
+        let mut table = match self.get_table(schema, name).await {
+            Some(table) => table,
+            None => return Ok(None),
+        };
+
+        //sqlite allows foreign key definitions without specifying the referenced columns, it then assumes the pk is used
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_columns.is_empty() {
+                if let (_, Some(referenced_pk)) = self.get_columns(schema, &foreign_key.referenced_table).await {
+                    foreign_key.referenced_columns = referenced_pk.columns;
+                }
+            }
+        }
+
+        Ok(Some(table))
+    }
 }
 
 impl SqlSchemaDescriber {
     /// Constructor.
     pub fn new(conn: Arc<dyn Queryable + Send + Sync + 'static>) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
+        SqlSchemaDescriber {
+            conn,
+            max_concurrent_table_queries: DEFAULT_MAX_CONCURRENT_TABLE_QUERIES,
+        }
+    }
+
+    /// Limit the number of tables described at the same time, and thus the number of queries in
+    /// flight on the connection. With a pooled connection, this should not exceed the pool size, or
+    /// the describer will wait on the pool and starve other users of the connection.
+    pub fn with_max_concurrent_table_queries(mut self, limit: usize) -> Self {
+        self.max_concurrent_table_queries = limit.max(1);
+        self
     }
 
     async fn get_databases(&self) -> Vec<String> {
@@ -97,6 +158,29 @@ impl SqlSchemaDescriber {
         names
     }
 
+    /// Views have no keys or indexes, only their columns and their defining statement are described.
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = format!(r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+        let mut views = Vec::new();
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("name");
+            let definition = row.get("sql").and_then(|x| x.to_string()).expect("sql");
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#;
@@ -109,18 +193,31 @@ impl SqlSchemaDescriber {
         size.try_into().unwrap()
     }
 
-    async fn get_table(&self, schema: &str, name: &str) -> Table {
+    /// Returns `None` when the table has no columns, which happens when it was dropped while describing, since SQLite
+    /// tables always have at least one column.
+    async fn get_table(&self, schema: &str, name: &str) -> Option<Table> {
         debug!("describing table '{}' in schema '{}", name, schema);
         let (columns, primary_key) = self.get_columns(schema, name).await;
+
+        if columns.is_empty() {
+            debug!("Table '{}' has no columns, it was probably dropped. Skipping it.", name);
+            return None;
+        }
+
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let indices = self.get_indices(schema, name).await;
-        Table {
+        Some(Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
-        }
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
+        })
     }
 
     async fn get_columns(&self, schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
@@ -147,18 +244,22 @@ impl SqlSchemaDescriber {
                     Some(Value::Text(Some(cow_string))) => {
                         let default_string = cow_string.to_string();
 
-                        if default_string.to_lowercase() == "null" {
+                        // Only the unquoted keyword means there is no default. The string literal
+                        // `'NULL'` keeps its quotes here, and is a regular string default.
+                        if default_string.eq_ignore_ascii_case("null") {
                             None
                         } else {
                             Some(match &tpe.family {
-                                ColumnTypeFamily::Int => match parse_int(&default_string) {
+                                ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                                     Some(int_value) => DefaultValue::VALUE(int_value),
                                     None => DefaultValue::DBGENERATED(default_string),
                                 },
-                                ColumnTypeFamily::Float => match parse_float(&default_string) {
-                                    Some(float_value) => DefaultValue::VALUE(float_value),
-                                    None => DefaultValue::DBGENERATED(default_string),
-                                },
+                                ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => {
+                                    match parse_float(&default_string) {
+                                        Some(float_value) => DefaultValue::VALUE(float_value),
+                                        None => DefaultValue::DBGENERATED(default_string),
+                                    }
+                                }
                                 ColumnTypeFamily::Boolean => match parse_int(&default_string) {
                                     Some(PrismaValue::Int(1)) => DefaultValue::VALUE(PrismaValue::Boolean(true)),
                                     Some(PrismaValue::Int(0)) => DefaultValue::VALUE(PrismaValue::Boolean(false)),
@@ -226,6 +327,7 @@ impl SqlSchemaDescriber {
             }
 
             //Integer Id columns are always implemented with either row id or autoincrement
+            let mut is_rowid_alias = false;
             if pk_cols.len() == 1 {
                 let pk_col = &columns[0];
                 for col in cols.iter_mut() {
@@ -235,21 +337,43 @@ impl SqlSchemaDescriber {
                                  is auto incrementing"
                         );
                         col.auto_increment = true;
+                        is_rowid_alias = true;
                     }
                 }
             }
 
+            // Only the `AUTOINCREMENT` keyword guarantees that rowids are never reused.
+            let autoincrement = is_rowid_alias && self.uses_autoincrement_keyword(schema, table).await;
+
             debug!("Determined that table has primary key with columns {:?}", columns);
             Some(PrimaryKey {
                 columns,
                 sequence: None,
                 constraint_name: None,
+                autoincrement,
             })
         };
 
         (cols, primary_key)
     }
 
+    async fn uses_autoincrement_keyword(&self, schema: &str, table: &str) -> bool {
+        static AUTOINCREMENT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\bAUTOINCREMENT\b").unwrap());
+
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='table' AND name = ?"#,
+            schema
+        );
+        let result_set = self.conn.query_raw(&sql, &[table.into()]).await.unwrap();
+
+        result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|sql| sql.to_string()))
+            .map(|sql| AUTOINCREMENT_RE.is_match(&sql))
+            .unwrap_or(false)
+    }
+
     async fn get_foreign_keys(&self, schema: &str, table: &str) -> Vec<ForeignKey> {
         struct IntermediateForeignKey {
             pub columns: HashMap<i64, String>,
@@ -306,6 +430,7 @@ impl SqlSchemaDescriber {
                     let fk = IntermediateForeignKey {
                         columns,
                         referenced_table,
+                        referenced_schema: None,
                         referenced_columns,
                         on_delete_action,
                     };
@@ -336,6 +461,7 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
 
@@ -375,6 +501,10 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                is_constraint: false,
+                predicate: None,
+                storage_parameters: Vec::new(),
+                deferrable: None,
             };
 
             let sql = format!(r#"PRAGMA "{}".index_info("{}");"#, schema, name);
@@ -408,6 +538,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "real" => ColumnTypeFamily::Float,
         "float" => ColumnTypeFamily::Float,
         "serial" => ColumnTypeFamily::Int,
+        "bigint" => ColumnTypeFamily::BigInt,
         "boolean" => ColumnTypeFamily::Boolean,
         "text" => ColumnTypeFamily::String,
         s if s.contains("char") => ColumnTypeFamily::String,
@@ -417,6 +548,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "datetime" => ColumnTypeFamily::DateTime,
         "timestamp" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -427,6 +559,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "float[]" => ColumnTypeFamily::Float,
         "int[]" => ColumnTypeFamily::Int,
         "integer[]" => ColumnTypeFamily::Int,
+        "bigint[]" => ColumnTypeFamily::BigInt,
         "text[]" => ColumnTypeFamily::String,
         data_type => ColumnTypeFamily::Unsupported(data_type.into()),
     };
@@ -434,6 +567,11 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         data_type: tpe.to_string(),
         full_data_type: tpe.to_string(),
         character_maximum_length: None,
+        datetime_precision: None,
+        timezone_aware: false,
+        srid: None,
+        array_elements_required: false,
+        domain: None,
         family,
         arity,
     }
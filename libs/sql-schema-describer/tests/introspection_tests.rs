@@ -34,6 +34,13 @@ fn int_data_type(api: &TestApi) -> String {
     }
 }
 
+fn expected_engine(api: &TestApi) -> Option<String> {
+    match api.sql_family() {
+        SqlFamily::Mysql => Some("InnoDB".to_string()),
+        _ => None,
+    }
+}
+
 fn varchar_data_type(api: &TestApi, length: u64) -> String {
     match (api.sql_family(), api.connector_name()) {
         (SqlFamily::Postgres, _) => "character varying".to_string(),
@@ -74,6 +81,11 @@ async fn is_required_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -86,6 +98,11 @@ async fn is_required_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Nullable,
@@ -128,6 +145,11 @@ async fn foreign_keys_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            datetime_precision: None,
+            timezone_aware: false,
+            srid: None,
+            array_elements_required: false,
+            domain: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Required,
@@ -145,6 +167,10 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
         }]
     } else {
         vec![]
@@ -167,8 +193,15 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city".to_string()],
                 referenced_columns: vec!["id".to_string()],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
             }],
+            engine: expected_engine(&api),
+            // The default character set depends on the server version.
+            default_character_set: user_table.default_character_set.clone(),
+            default_collation: user_table.default_collation.clone(),
+            description: user_table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -214,6 +247,11 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -231,6 +269,11 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 } else {
                     Some(255)
                 },
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -244,6 +287,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
         }]
     } else {
         vec![]
@@ -272,8 +319,15 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city_name".to_string(), "city".to_string()],
                 referenced_columns: vec!["name".to_string(), "id".to_string(),],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
             },],
+            engine: expected_engine(&api),
+            // The default character set depends on the server version.
+            default_character_set: user_table.default_character_set.clone(),
+            default_collation: user_table.default_collation.clone(),
+            description: user_table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -295,6 +349,11 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            datetime_precision: None,
+            timezone_aware: false,
+            srid: None,
+            array_elements_required: false,
+            domain: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Required,
@@ -337,6 +396,11 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -354,6 +418,11 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 } else {
                     Some(255)
                 },
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -377,8 +446,15 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 } else {
                     None
                 },
+                autoincrement: false,
             }),
             foreign_keys: vec![],
+            engine: expected_engine(&api),
+            // The default character set depends on the server version.
+            default_character_set: table.default_character_set.clone(),
+            default_collation: table.default_collation.clone(),
+            description: table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -407,6 +483,11 @@ async fn indices_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -421,6 +502,11 @@ async fn indices_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -446,6 +532,10 @@ async fn indices_must_work(api: &TestApi) {
                 name: "count".to_string(),
                 columns: vec!["count".to_string()],
                 tpe: IndexType::Normal,
+                is_constraint: false,
+                predicate: None,
+                storage_parameters: Vec::new(),
+                deferrable: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
@@ -455,8 +545,15 @@ async fn indices_must_work(api: &TestApi) {
                 } else {
                     None
                 },
+                autoincrement: false,
             }),
             foreign_keys: vec![],
+            engine: expected_engine(&api),
+            // The default character set depends on the server version.
+            default_character_set: user_table.default_character_set.clone(),
+            default_collation: user_table.default_collation.clone(),
+            description: user_table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -482,6 +579,11 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -495,6 +597,11 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -508,12 +615,20 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        is_constraint: false,
+        predicate: None,
+        storage_parameters: Vec::new(),
+        deferrable: None,
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -521,12 +636,20 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                is_constraint: true,
+                predicate: None,
+                storage_parameters: Vec::new(),
+                deferrable: None,
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
         }),
         SqlFamily::Mssql => todo!("Greetings from Redmond"),
     };
@@ -538,6 +661,12 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             indices: expected_indices,
             primary_key: None,
             foreign_keys: vec![],
+            engine: expected_engine(&api),
+            // The default character set depends on the server version.
+            default_character_set: user_table.default_character_set.clone(),
+            default_collation: user_table.default_collation.clone(),
+            description: user_table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
     assert!(
@@ -569,6 +698,11 @@ async fn defaults_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            datetime_precision: None,
+            timezone_aware: false,
+            srid: None,
+            array_elements_required: false,
+            domain: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Nullable,
@@ -585,6 +719,12 @@ async fn defaults_must_work(api: &TestApi) {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            engine: expected_engine(&api),
+            // The default character set depends on the server version.
+            default_character_set: user_table.default_character_set.clone(),
+            default_collation: user_table.default_collation.clone(),
+            description: user_table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -68,6 +68,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int(11)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -81,6 +86,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int(11)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -94,6 +104,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "smallint".to_string(),
                 full_data_type: "smallint(6)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -107,6 +122,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyint".to_string(),
                 full_data_type: "tinyint(4)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -119,6 +139,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyint".to_string(),
                 full_data_type: "tinyint(1)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::Required,
             },
@@ -132,6 +157,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumint".to_string(),
                 full_data_type: "mediumint(9)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -145,7 +175,12 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "bigint".to_string(),
                 full_data_type: "bigint(20)".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -158,6 +193,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "decimal".to_string(),
                 full_data_type: "decimal(10,0)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -171,6 +211,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "decimal".to_string(),
                 full_data_type: "decimal(10,0)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -184,6 +229,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "float".to_string(),
                 full_data_type: "float".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -197,6 +247,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "double".to_string(),
                 full_data_type: "double".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -210,6 +265,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "date".to_string(),
                 full_data_type: "date".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -223,6 +283,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "time".to_string(),
                 full_data_type: "time".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -236,6 +301,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "datetime".to_string(),
                 full_data_type: "datetime".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -249,6 +319,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "timestamp".to_string(),
                 full_data_type: "timestamp".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -262,6 +337,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "year".to_string(),
                 full_data_type: "year(4)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -275,6 +355,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "char".to_string(),
                 full_data_type: "char(1)".to_string(),
                 character_maximum_length: Some(1),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -288,6 +373,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "varchar".to_string(),
                 full_data_type: "varchar(255)".to_string(),
                 character_maximum_length: Some(255),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -301,6 +391,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "text".to_string(),
                 full_data_type: "text".to_string(),
                 character_maximum_length: Some(65535),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -314,6 +409,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinytext".to_string(),
                 full_data_type: "tinytext".to_string(),
                 character_maximum_length: Some(255),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -327,6 +427,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumtext".to_string(),
                 full_data_type: "mediumtext".to_string(),
                 character_maximum_length: Some(16777215),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -340,6 +445,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "longtext".to_string(),
                 full_data_type: "longtext".to_string(),
                 character_maximum_length: Some(4294967295),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -353,6 +463,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "enum".to_string(),
                 full_data_type: "enum(\'a\',\'b\')".to_string(),
                 character_maximum_length: Some(1),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Enum("User_enum_col".into()),
                 arity: ColumnArity::Required,
             },
@@ -366,6 +481,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "set".to_string(),
                 full_data_type: "set(\'a\',\'b\')".to_string(),
                 character_maximum_length: Some(3),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -379,6 +499,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "binary".to_string(),
                 full_data_type: "binary(1)".to_string(),
                 character_maximum_length: Some(1),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -392,6 +517,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "varbinary".to_string(),
                 full_data_type: "varbinary(255)".to_string(),
                 character_maximum_length: Some(255),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -405,6 +535,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "blob".to_string(),
                 full_data_type: "blob".to_string(),
                 character_maximum_length: Some(65535),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -418,6 +553,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyblob".to_string(),
                 full_data_type: "tinyblob".to_string(),
                 character_maximum_length: Some(255),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
@@ -432,6 +572,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumblob".to_string(),
                 full_data_type: "mediumblob".to_string(),
                 character_maximum_length: Some(16777215),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -445,6 +590,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "longblob".to_string(),
                 full_data_type: "longblob".to_string(),
                 character_maximum_length: Some(4294967295),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -458,6 +608,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "geometry".to_string(),
                 full_data_type: "geometry".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -471,6 +626,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "point".to_string(),
                 full_data_type: "point".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -484,6 +644,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "linestring".to_string(),
                 full_data_type: "linestring".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -497,6 +662,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "polygon".to_string(),
                 full_data_type: "polygon".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -510,6 +680,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multipoint".to_string(),
                 full_data_type: "multipoint".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -523,6 +698,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multilinestring".to_string(),
                 full_data_type: "multilinestring".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -536,6 +716,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multipolygon".to_string(),
                 full_data_type: "multipolygon".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -549,6 +734,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "geometrycollection".to_string(),
                 full_data_type: "geometrycollection".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -562,6 +752,11 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "json".to_string(),
                 full_data_type: "json".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
             },
@@ -582,8 +777,15 @@ async fn all_mysql_column_types_must_work() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
                 constraint_name: None,
+                autoincrement: false,
             }),
             foreign_keys: vec![],
+            engine: Some("InnoDB".to_owned()),
+            // The default character set depends on the server version.
+            default_character_set: table.default_character_set.clone(),
+            default_collation: table.default_collation.clone(),
+            description: table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -622,6 +824,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -635,6 +842,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -647,6 +859,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -659,6 +876,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -671,6 +893,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -682,28 +909,45 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                 Index {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
                 }
             ],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                autoincrement: false,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -711,6 +955,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -718,6 +963,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -725,6 +971,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -732,9 +979,16 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            engine: Some("InnoDB".to_owned()),
+            // The default character set depends on the server version.
+            default_character_set: table.default_character_set.clone(),
+            default_collation: table.default_collation.clone(),
+            description: table.description.clone(),
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -761,7 +1015,11 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
         }]
     );
 }
@@ -799,7 +1057,11 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
         &[Index {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
         }]
     );
 }
@@ -837,6 +1099,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -868,6 +1131,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
@@ -983,3 +1247,150 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn table_engines_must_be_introspected(api: &TestApi) -> TestResult {
+    api.database()
+        .query_raw("CREATE TABLE cats (id INTEGER PRIMARY KEY) ENGINE=MyISAM", &[])
+        .await?;
+    api.database()
+        .query_raw("CREATE TABLE dogs (id INTEGER PRIMARY KEY) ENGINE=InnoDB", &[])
+        .await?;
+
+    let schema = api.describe().await?;
+
+    assert_eq!(schema.table_bang("cats").engine.as_deref(), Some("MyISAM"));
+    assert_eq!(schema.table_bang("dogs").engine.as_deref(), Some("InnoDB"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn fulltext_indexes_must_be_introspected(api: &TestApi) -> TestResult {
+    api.database()
+        .query_raw(
+            "CREATE TABLE articles (id INTEGER PRIMARY KEY, body TEXT, FULLTEXT INDEX body_fulltext (body)) ENGINE=InnoDB",
+            &[],
+        )
+        .await?;
+
+    let schema = api.describe().await?;
+
+    assert_eq!(
+        schema.table_bang("articles").indices,
+        &[Index {
+            name: "body_fulltext".into(),
+            columns: vec!["body".to_owned()],
+            tpe: IndexType::Fulltext,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
+        }]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn spatial_indexes_and_srids_must_be_introspected(api: &TestApi) -> TestResult {
+    api.database()
+        .query_raw(
+            "CREATE TABLE places (id INTEGER PRIMARY KEY, location POINT NOT NULL SRID 4326, area POLYGON, SPATIAL INDEX location_spatial (location)) ENGINE=InnoDB",
+            &[],
+        )
+        .await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("places");
+
+    assert_eq!(
+        table.indices,
+        &[Index {
+            name: "location_spatial".into(),
+            columns: vec!["location".to_owned()],
+            tpe: IndexType::Spatial,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
+        }]
+    );
+    assert_eq!(table.column_bang("location").tpe.family, ColumnTypeFamily::Geometric);
+    assert_eq!(table.column_bang("location").tpe.srid, Some(4326));
+    assert_eq!(table.column_bang("area").tpe.srid, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn bit_columns_must_be_introspected_as_booleans_or_bytes(api: &TestApi) -> TestResult {
+    api.database()
+        .query_raw(
+            "CREATE TABLE flags (id INTEGER PRIMARY KEY, is_active BIT(1) NOT NULL, permissions BIT(8) NOT NULL)",
+            &[],
+        )
+        .await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("flags");
+
+    assert_eq!(table.column_bang("is_active").tpe.family, ColumnTypeFamily::Boolean);
+    assert_eq!(table.column_bang("permissions").tpe.family, ColumnTypeFamily::Binary);
+    assert_eq!(table.column_bang("permissions").tpe.full_data_type, "bit(8)");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn enum_values_with_commas_and_set_values_with_quotes_must_be_introspected(api: &TestApi) -> TestResult {
+    api.database()
+        .query_raw(
+            "CREATE TABLE pets (id INTEGER PRIMARY KEY, mood ENUM('hungry, angry', 'calm') NOT NULL, traits SET('can''t fly', 'naps a lot'))",
+            &[],
+        )
+        .await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("pets");
+
+    assert_eq!(
+        schema.get_enum("pets_mood").unwrap().values,
+        vec!["hungry, angry".to_owned(), "calm".to_owned()]
+    );
+
+    let traits = table.column_bang("traits");
+
+    assert_eq!(traits.tpe.family, ColumnTypeFamily::String);
+    assert_eq!(
+        sql_schema_describer::mysql::parse_value_list(&traits.tpe.full_data_type).unwrap(),
+        vec!["can't fly".to_owned(), "naps a lot".to_owned()]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn describing_catalogs_concurrently_respects_the_limit(api: &TestApi) -> TestResult {
+    for i in 0..3 {
+        api.database()
+            .raw_cmd(&format!(
+                "CREATE TABLE `{}`.`table_{}` (id INTEGER PRIMARY KEY, name VARCHAR(100) NOT NULL UNIQUE)",
+                api.schema_name(),
+                i
+            ))
+            .await?;
+    }
+
+    let instrumented = std::sync::Arc::new(InstrumentedQueryable::new(api.database().clone()));
+    let describer =
+        sql_schema_describer::mysql::SqlSchemaDescriber::new(instrumented.clone()).with_max_concurrent_table_queries(2);
+
+    let schema = describer.describe(api.schema_name()).await?;
+
+    assert_eq!(schema.tables.len(), 3);
+    assert_eq!(schema.table_bang("table_1").indices.len(), 1);
+    assert_eq!(instrumented.max_in_flight(), 2);
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ mod test_api;
 use crate::{common::*, postgres::*};
 use barrel::{types, Migration};
 use pretty_assertions::assert_eq;
+use prisma_value::PrismaValue;
 use sql_schema_describer::*;
 use test_api::*;
 use test_macros::test_each_connector;
@@ -73,6 +74,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_bytea".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::List,
@@ -86,6 +92,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_bool".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::List,
@@ -99,6 +110,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_date".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::List,
@@ -112,6 +128,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_float8".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::List,
@@ -125,6 +146,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_float8".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::List,
@@ -138,6 +164,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_int4".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::List,
@@ -151,6 +182,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_text".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::List,
@@ -164,6 +200,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_varchar".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::List,
@@ -177,6 +218,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bytea".into(),
                 full_data_type: "bytea".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
@@ -190,6 +236,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "boolean".into(),
                 full_data_type: "bool".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::Required,
@@ -203,6 +254,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "date".into(),
                 full_data_type: "date".into(),
                 character_maximum_length: None,
+                datetime_precision: Some(0),
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -217,6 +273,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "double precision".into(),
                 full_data_type: "float8".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
@@ -231,6 +292,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "double precision".into(),
                 full_data_type: "float8".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
@@ -245,6 +311,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -259,6 +330,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -276,6 +352,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "text".into(),
                 full_data_type: "text".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
@@ -290,6 +371,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "character varying".into(),
                 full_data_type: "varchar".into(),
                 character_maximum_length: Some(1),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -303,8 +389,13 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -317,7 +408,12 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -333,6 +429,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bit".into(),
                 full_data_type: "bit".into(),
                 character_maximum_length: Some(1),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -346,6 +447,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bit varying".into(),
                 full_data_type: "varbit".into(),
                 character_maximum_length: Some(1),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -359,6 +465,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "box".into(),
                 full_data_type: "box".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -372,6 +483,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "character".into(),
                 full_data_type: "bpchar".into(),
                 character_maximum_length: Some(1),
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -385,6 +501,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "circle".into(),
                 full_data_type: "circle".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -398,6 +519,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "interval".into(),
                 full_data_type: "interval".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -411,6 +537,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "line".into(),
                 full_data_type: "line".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -424,6 +555,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "lseg".into(),
                 full_data_type: "lseg".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -437,6 +573,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "numeric".into(),
                 full_data_type: "numeric".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -450,6 +591,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "path".into(),
                 full_data_type: "path".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -463,6 +609,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "pg_lsn".into(),
                 full_data_type: "pg_lsn".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::LogSequenceNumber,
                 arity: ColumnArity::Required,
             },
@@ -476,6 +627,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "polygon".into(),
                 full_data_type: "polygon".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -489,6 +645,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "smallint".into(),
                 full_data_type: "int2".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -502,6 +663,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "smallint".into(),
                 full_data_type: "int2".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -518,6 +684,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -534,6 +705,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "time without time zone".into(),
                 full_data_type: "time".into(),
                 character_maximum_length: None,
+                datetime_precision: Some(6),
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -547,6 +723,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "time with time zone".into(),
                 full_data_type: "timetz".into(),
                 character_maximum_length: None,
+                datetime_precision: Some(6),
+                timezone_aware: true,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -561,6 +742,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "timestamp without time zone".into(),
                 full_data_type: "timestamp".into(),
                 character_maximum_length: None,
+                datetime_precision: Some(6),
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -575,6 +761,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "timestamp with time zone".into(),
                 full_data_type: "timestamptz".into(),
                 character_maximum_length: None,
+                datetime_precision: Some(6),
+                timezone_aware: true,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -589,6 +780,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "tsquery".into(),
                 full_data_type: "tsquery".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::TextSearch,
                 arity: ColumnArity::Required,
@@ -603,6 +799,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "tsvector".into(),
                 full_data_type: "tsvector".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::TextSearch,
                 arity: ColumnArity::Required,
@@ -617,6 +818,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "txid_snapshot".into(),
                 full_data_type: "txid_snapshot".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::TransactionId,
                 arity: ColumnArity::Required,
@@ -631,6 +837,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "json".into(),
                 full_data_type: "json".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
@@ -645,6 +856,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "jsonb".into(),
                 full_data_type: "jsonb".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
@@ -659,6 +875,11 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "uuid".into(),
                 full_data_type: "uuid".into(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Uuid,
                 arity: ColumnArity::Required,
@@ -679,6 +900,10 @@ async fn all_postgres_column_types_must_work() {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                is_constraint: true,
+                predicate: None,
+                storage_parameters: Vec::new(),
+                deferrable: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -688,8 +913,14 @@ async fn all_postgres_column_types_must_work() {
                     allocation_size: 1,
                 },),
                 constraint_name: Some("User_pkey".into()),
+                autoincrement: false,
             }),
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -726,6 +957,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -740,6 +976,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -753,6 +994,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -766,6 +1012,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -779,6 +1030,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -792,6 +1048,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -805,6 +1066,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".into()],
                 sequence: None,
                 constraint_name: Some("User_pkey".into()),
+                autoincrement: false,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -812,6 +1074,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -819,6 +1082,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -826,6 +1090,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -833,6 +1098,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -840,9 +1106,15 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -997,3 +1269,304 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn datetime_precision_must_be_introspected(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."precision_test" (
+                id INTEGER PRIMARY KEY,
+                created_at TIMESTAMP(3) NOT NULL,
+                updated_at TIMESTAMP NOT NULL,
+                starts_at TIME(0) NOT NULL
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("precision_test");
+
+    assert_eq!(table.column_bang("created_at").tpe.datetime_precision, Some(3));
+    assert_eq!(table.column_bang("updated_at").tpe.datetime_precision, Some(6));
+    assert_eq!(table.column_bang("starts_at").tpe.datetime_precision, Some(0));
+    assert_eq!(table.column_bang("id").tpe.datetime_precision, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn array_defaults_must_be_introspected_as_lists(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."array_defaults_test" (
+                id INTEGER PRIMARY KEY,
+                numbers INTEGER[] NOT NULL DEFAULT '{{1,2,3}}'
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("array_defaults_test");
+
+    assert_eq!(
+        table.column_bang("numbers").default,
+        Some(DefaultValue::LIST(vec![
+            PrismaValue::Int(1),
+            PrismaValue::Int(2),
+            PrismaValue::Int(3)
+        ]))
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn array_element_nullability_must_be_introspected(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."array_elements_test" (
+                id INTEGER PRIMARY KEY,
+                "requiredNumbers" INTEGER[] CHECK (array_position("requiredNumbers", NULL) IS NULL),
+                numbers INTEGER[]
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("array_elements_test");
+
+    assert!(table.column_bang("requiredNumbers").tpe.array_elements_required);
+    assert!(!table.column_bang("numbers").tpe.array_elements_required);
+    assert!(!table.column_bang("id").tpe.array_elements_required);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn temporary_tables_are_only_described_when_requested(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd("CREATE TEMPORARY TABLE temp_cats (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let temp_schema = api
+        .database()
+        .query_raw("SELECT nspname FROM pg_namespace WHERE oid = pg_my_temp_schema()", &[])
+        .await?
+        .into_single()?
+        .get("nspname")
+        .and_then(|name| name.to_string())
+        .unwrap();
+
+    let describer = sql_schema_describer::postgres::SqlSchemaDescriber::new(api.database().clone());
+    let schema = describer.describe(&temp_schema).await?;
+
+    assert!(schema.table("temp_cats").is_err());
+
+    let describer =
+        sql_schema_describer::postgres::SqlSchemaDescriber::new(api.database().clone()).with_temporary_tables(true);
+    let schema = describer.describe(&temp_schema).await?;
+
+    schema.table_bang("temp_cats").column_bang("id");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn foreign_keys_into_other_schemas_record_the_referenced_schema(api: &TestApi) -> TestResult {
+    let other_schema = format!("{}_referenced", api.schema_name());
+
+    api.database()
+        .raw_cmd(&format!(
+            "DROP SCHEMA IF EXISTS \"{other_schema}\" CASCADE;
+             CREATE SCHEMA \"{other_schema}\";
+             CREATE TABLE \"{other_schema}\".\"User\" (id SERIAL PRIMARY KEY);
+             CREATE TABLE \"{schema}\".\"User\" (id SERIAL PRIMARY KEY);
+             CREATE TABLE \"{schema}\".\"Post\" (
+                 id SERIAL PRIMARY KEY,
+                 author_id INTEGER REFERENCES \"{other_schema}\".\"User\"(id),
+                 editor_id INTEGER REFERENCES \"{schema}\".\"User\"(id)
+             );",
+            schema = api.schema_name(),
+            other_schema = other_schema,
+        ))
+        .await?;
+
+    let schema = api.describe().await?;
+    let post = schema.table_bang("Post");
+
+    let author_fk = post.foreign_key_for_column("author_id").unwrap();
+    assert_eq!(author_fk.referenced_table, "User");
+    assert_eq!(author_fk.referenced_schema.as_deref(), Some(other_schema.as_str()));
+
+    let editor_fk = post.foreign_key_for_column("editor_id").unwrap();
+    assert_eq!(editor_fk.referenced_table, "User");
+    assert_eq!(editor_fk.referenced_schema, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn identity_columns_sequence_options_must_be_introspected(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."identity_test" (
+                id INTEGER GENERATED BY DEFAULT AS IDENTITY (START WITH 100 INCREMENT BY 5) PRIMARY KEY,
+                other_id BIGINT GENERATED ALWAYS AS IDENTITY
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("identity_test");
+
+    let id = table.column_bang("id");
+
+    assert!(id.auto_increment);
+    assert_eq!(
+        id.default,
+        Some(DefaultValue::IDENTITY(IdentityOptions {
+            always: false,
+            start: 100,
+            increment: 5,
+        }))
+    );
+
+    assert_eq!(
+        table.column_bang("other_id").default,
+        Some(DefaultValue::IDENTITY(IdentityOptions {
+            always: true,
+            start: 1,
+            increment: 1,
+        }))
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn hstore_columns_must_be_introspected_as_strings_or_json(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd("CREATE EXTENSION IF NOT EXISTS hstore SCHEMA public")
+        .await?;
+
+    let create_table = format!(
+        r#"CREATE TABLE "{0}"."hstore_test" (id INTEGER PRIMARY KEY, attributes public.hstore)"#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let attributes = schema.table_bang("hstore_test").column_bang("attributes");
+
+    assert_eq!(attributes.tpe.full_data_type, "hstore");
+    assert_eq!(attributes.tpe.family, ColumnTypeFamily::String);
+
+    let describer =
+        sql_schema_describer::postgres::SqlSchemaDescriber::new(api.database().clone()).with_hstore_as_json(true);
+    let schema = describer.describe(api.schema_name()).await?;
+
+    assert_eq!(
+        schema.table_bang("hstore_test").column_bang("attributes").tpe.family,
+        ColumnTypeFamily::Json
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn storage_parameters_of_tables_and_indexes_must_be_introspected(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{0}"."Cat" (id INTEGER PRIMARY KEY, name TEXT) WITH (fillfactor = 70)"#,
+        api.schema_name()
+    );
+    let create_index = format!(
+        r#"CREATE INDEX "Cat_name_idx" ON "{0}"."Cat" (name) WITH (fillfactor = 80)"#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+    api.database().raw_cmd(&create_index).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(table.storage_parameters, &[("fillfactor".to_owned(), "70".to_owned())]);
+    assert_eq!(
+        table.indices[0].storage_parameters,
+        &[("fillfactor".to_owned(), "80".to_owned())]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn deferrable_unique_constraints_must_be_introspected(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."Cat" (
+                id INTEGER PRIMARY KEY,
+                name TEXT CONSTRAINT "Cat_name_key" UNIQUE DEFERRABLE INITIALLY DEFERRED,
+                nickname TEXT CONSTRAINT "Cat_nickname_key" UNIQUE DEFERRABLE,
+                tag TEXT CONSTRAINT "Cat_tag_key" UNIQUE
+            )
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Cat");
+    let deferrable = |name: &str| {
+        table
+            .indices
+            .iter()
+            .find(|index| index.name == name)
+            .unwrap()
+            .deferrable
+    };
+
+    assert_eq!(deferrable("Cat_name_key"), Some(Deferrable::InitiallyDeferred));
+    assert_eq!(deferrable("Cat_nickname_key"), Some(Deferrable::InitiallyImmediate));
+    assert_eq!(deferrable("Cat_tag_key"), None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn describing_catalogs_concurrently_respects_the_limit(api: &TestApi) -> TestResult {
+    for i in 0..3 {
+        api.database()
+            .raw_cmd(&format!(
+                r#"CREATE TABLE "{}"."table_{}" (id SERIAL PRIMARY KEY, name TEXT NOT NULL UNIQUE)"#,
+                api.schema_name(),
+                i
+            ))
+            .await?;
+    }
+
+    let instrumented = std::sync::Arc::new(InstrumentedQueryable::new(api.database().clone()));
+    let describer = sql_schema_describer::postgres::SqlSchemaDescriber::new(instrumented.clone())
+        .with_max_concurrent_table_queries(2);
+
+    let schema = describer.describe(api.schema_name()).await?;
+
+    assert_eq!(schema.tables.len(), 3);
+    assert_eq!(schema.table_bang("table_1").indices.len(), 1);
+    assert_eq!(instrumented.max_in_flight(), 2);
+
+    Ok(())
+}
@@ -30,6 +30,11 @@ fn database_schema_is_serializable() {
                             data_type: "integer".to_string(),
                             full_data_type: "int".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -43,6 +48,11 @@ fn database_schema_is_serializable() {
                             data_type: "varchar(255)".to_string(),
                             full_data_type: "varchar(255)".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Nullable,
@@ -56,6 +66,11 @@ fn database_schema_is_serializable() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            datetime_precision: None,
+                            timezone_aware: false,
+                            srid: None,
+                            array_elements_required: false,
+                            domain: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -68,19 +83,30 @@ fn database_schema_is_serializable() {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
             Table {
                 name: "table2".to_string(),
@@ -90,6 +116,11 @@ fn database_schema_is_serializable() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -102,8 +133,14 @@ fn database_schema_is_serializable() {
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    autoincrement: false,
                 }),
                 foreign_keys: vec![],
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
             },
         ],
         enums: vec![Enum {
@@ -115,6 +152,8 @@ fn database_schema_is_serializable() {
             initial_value: 1,
             allocation_size: 32,
         }],
+        composite_types: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -139,6 +178,11 @@ fn database_schema_without_primary_key_is_serializable() {
                     data_type: "integer".to_string(),
                     full_data_type: "int".to_string(),
                     character_maximum_length: None,
+                    datetime_precision: None,
+                    timezone_aware: false,
+                    srid: None,
+                    array_elements_required: false,
+                    domain: None,
 
                     family: ColumnTypeFamily::Int,
                     arity: ColumnArity::Nullable,
@@ -149,9 +193,16 @@ fn database_schema_without_primary_key_is_serializable() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -190,6 +241,11 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             data_type: "raw type".to_string(),
             full_data_type: "full raw type".to_string(),
             character_maximum_length: None,
+            datetime_precision: None,
+            timezone_aware: false,
+            srid: None,
+            array_elements_required: false,
+            domain: None,
 
             family: family.to_owned(),
             arity: ColumnArity::Nullable,
@@ -205,9 +261,16 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -233,6 +296,11 @@ fn database_schema_is_serializable_for_every_column_arity() {
                 data_type: "integer".to_string(),
                 full_data_type: "int".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: arity.to_owned(),
@@ -248,9 +316,16 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -277,6 +352,11 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -290,6 +370,11 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -303,6 +388,11 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -316,6 +406,11 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -329,6 +424,11 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -344,6 +444,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column1".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -351,6 +452,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column2".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -358,6 +460,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -365,6 +468,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column4".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
@@ -372,13 +476,21 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column5".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }],
         enums: vec![],
         sequences: vec![],
+        composite_types: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -7,7 +7,7 @@ use common::*;
 use pretty_assertions::assert_eq;
 use sql_schema_describer::*;
 use sqlite::*;
-use test_api::{sqlite_test_api, TestApi, TestResult};
+use test_api::{sqlite_test_api, InstrumentedQueryable, TestApi, TestResult};
 use test_macros::test_each_connector;
 
 #[tokio::test]
@@ -33,6 +33,11 @@ async fn sqlite_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -45,6 +50,11 @@ async fn sqlite_column_types_must_work() {
                 data_type: "INTEGER".to_string(),
                 full_data_type: "INTEGER".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -57,6 +67,11 @@ async fn sqlite_column_types_must_work() {
                 data_type: "TEXT".to_string(),
                 full_data_type: "TEXT".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -69,6 +84,11 @@ async fn sqlite_column_types_must_work() {
                 data_type: "REAL".to_string(),
                 full_data_type: "REAL".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -81,6 +101,11 @@ async fn sqlite_column_types_must_work() {
                 data_type: "INTEGER".to_string(),
                 full_data_type: "INTEGER".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -93,6 +118,11 @@ async fn sqlite_column_types_must_work() {
                 data_type: "decimal (5, 3)".to_string(),
                 full_data_type: "decimal (5, 3)".to_string(),
                 character_maximum_length: None,
+                datetime_precision: None,
+                timezone_aware: false,
+                srid: None,
+                array_elements_required: false,
+                domain: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -111,8 +141,14 @@ async fn sqlite_column_types_must_work() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
                 constraint_name: None,
+                autoincrement: false,
             }),
             foreign_keys: vec![],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -148,6 +184,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -160,6 +201,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -172,6 +218,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -184,6 +235,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -196,6 +252,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -208,6 +269,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        datetime_precision: None,
+                        timezone_aware: false,
+                        srid: None,
+                        array_elements_required: false,
+                        domain: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -221,6 +287,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                autoincrement: false,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -228,6 +295,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -235,6 +303,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -242,6 +311,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -249,6 +319,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -256,9 +327,15 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
         }
     );
 }
@@ -294,6 +371,7 @@ async fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_
             columns: vec!["primary_col".to_owned()],
             sequence: None,
             constraint_name: None,
+            autoincrement: false,
         }
     );
 }
@@ -347,6 +425,42 @@ async fn escaped_quotes_in_string_defaults_must_be_unescaped(api: &TestApi) -> T
     Ok(())
 }
 
+#[test_each_connector(tags("sqlite"))]
+async fn the_string_null_is_a_default_unlike_the_null_keyword(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."null_defaults_test" (
+                id INTEGER PRIMARY KEY,
+                string_null VARCHAR NOT NULL DEFAULT 'NULL',
+                keyword_null VARCHAR DEFAULT NULL
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    let table = schema.table_bang("null_defaults_test");
+
+    let string_null_default = table
+        .column_bang("string_null")
+        .default
+        .as_ref()
+        .unwrap()
+        .as_value()
+        .unwrap()
+        .clone()
+        .into_string()
+        .unwrap();
+
+    assert_eq!(string_null_default, "NULL");
+    assert_eq!(table.column_bang("keyword_null").default, None);
+
+    Ok(())
+}
+
 #[test_each_connector(tags("sqlite"))]
 async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi) -> TestResult {
     let create_table = format!(
@@ -379,3 +493,54 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn describing_tables_concurrently_respects_the_limit(api: &TestApi) -> TestResult {
+    for i in 0..10 {
+        api.database()
+            .raw_cmd(&format!(
+                r#"CREATE TABLE "{}"."table_{}" (id INTEGER PRIMARY KEY)"#,
+                api.schema_name(),
+                i
+            ))
+            .await?;
+    }
+
+    let instrumented = std::sync::Arc::new(InstrumentedQueryable::new(api.database().clone()));
+    let describer = sql_schema_describer::sqlite::SqlSchemaDescriber::new(instrumented.clone())
+        .with_max_concurrent_table_queries(3);
+
+    let schema = describer.describe(api.schema_name()).await?;
+
+    assert_eq!(schema.tables.len(), 10);
+    assert_eq!(instrumented.max_in_flight(), 3);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn views_must_be_described_with_their_definition(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}"."User" (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"#,
+        api.schema_name()
+    );
+    let create_view = format!(
+        r#"CREATE VIEW "{}"."UserName" AS SELECT name FROM "User""#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+    api.database().raw_cmd(&create_view).await?;
+
+    let schema = api.describe().await?;
+
+    assert_eq!(schema.tables.len(), 1);
+
+    let view = schema.get_view("UserName").expect("UserName view");
+
+    assert!(view.definition.ends_with(r#"AS SELECT name FROM "User""#));
+    assert_eq!(view.columns.len(), 1);
+    assert_eq!(view.columns[0].name, "name");
+
+    Ok(())
+}
@@ -6,7 +6,10 @@ use quaint::{
     single::Quaint,
 };
 use sql_schema_describer::*;
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use test_setup::*;
 
 pub type TestResult = anyhow::Result<()>;
@@ -180,6 +183,77 @@ pub async fn sqlite_test_api(db_name: &'static str) -> TestApi {
     }
 }
 
+/// Counts the queries in flight, yielding once before each query so concurrent queries overlap.
+pub struct InstrumentedQueryable {
+    inner: Arc<dyn Queryable + Send + Sync + 'static>,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+impl InstrumentedQueryable {
+    pub fn new(inner: Arc<dyn Queryable + Send + Sync + 'static>) -> Self {
+        InstrumentedQueryable {
+            inner,
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// The highest number of queries that were in flight at the same time.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    async fn instrument<T>(&self, query: impl std::future::Future<Output = T>) -> T {
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+
+        let mut yielded = false;
+        futures::future::poll_fn(|cx| {
+            if yielded {
+                std::task::Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        })
+        .await;
+
+        let result = query.await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl Queryable for InstrumentedQueryable {
+    async fn query(&self, q: quaint::ast::Query<'_>) -> Result<quaint::connector::ResultSet, quaint::error::Error> {
+        self.instrument(self.inner.query(q)).await
+    }
+
+    async fn query_raw(
+        &self,
+        sql: &str,
+        params: &[quaint::ast::Value<'_>],
+    ) -> Result<quaint::connector::ResultSet, quaint::error::Error> {
+        self.instrument(self.inner.query_raw(sql, params)).await
+    }
+
+    async fn execute_raw(&self, sql: &str, params: &[quaint::ast::Value<'_>]) -> Result<u64, quaint::error::Error> {
+        self.instrument(self.inner.execute_raw(sql, params)).await
+    }
+
+    async fn raw_cmd(&self, cmd: &str) -> Result<(), quaint::error::Error> {
+        self.instrument(self.inner.raw_cmd(cmd)).await
+    }
+
+    async fn version(&self) -> Result<Option<String>, quaint::error::Error> {
+        self.inner.version().await
+    }
+}
+
 pub struct BarrelMigrationExecutor {
     pub(super) database: Arc<dyn Queryable + Send + Sync>,
     pub(super) sql_variant: barrel::backend::SqlVariant,
@@ -21,7 +21,7 @@ fn connector_names() -> Vec<(&'static str, Tags)> {
 }
 
 fn postgres_capabilities() -> Capabilities {
-    Capabilities::SCALAR_LISTS | Capabilities::ENUMS | Capabilities::JSON
+    Capabilities::SCALAR_LISTS | Capabilities::ENUMS | Capabilities::JSON | Capabilities::TRANSACTIONAL_DDL
 }
 
 fn mysql_capabilities() -> Capabilities {
@@ -32,6 +32,10 @@ fn mysql_5_6_capabilities() -> Capabilities {
     Capabilities::ENUMS
 }
 
+fn sqlite_capabilities() -> Capabilities {
+    Capabilities::TRANSACTIONAL_DDL
+}
+
 fn infer_capabilities(tags: Tags) -> Capabilities {
     if tags.intersects(Tags::POSTGRES) {
         return postgres_capabilities();
@@ -45,6 +49,10 @@ fn infer_capabilities(tags: Tags) -> Capabilities {
         return mysql_capabilities();
     }
 
+    if tags.intersects(Tags::SQLITE) {
+        return sqlite_capabilities();
+    }
+
     Capabilities::empty()
 }
 
@@ -3,9 +3,10 @@ use std::{error::Error, fmt::Display, str::FromStr};
 
 bitflags! {
     pub struct Capabilities: u8 {
-        const SCALAR_LISTS = 0b00000001;
-        const ENUMS        = 0b00000010;
-        const JSON         = 0b00000100;
+        const SCALAR_LISTS      = 0b00000001;
+        const ENUMS             = 0b00000010;
+        const JSON              = 0b00000100;
+        const TRANSACTIONAL_DDL = 0b00001000;
     }
 }
 
@@ -44,4 +45,28 @@ const CAPABILITY_NAMES: &[(&str, Capabilities)] = &[
     ("enums", Capabilities::ENUMS),
     ("json", Capabilities::JSON),
     ("scalar_lists", Capabilities::SCALAR_LISTS),
+    ("transactional_ddl", Capabilities::TRANSACTIONAL_DDL),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capability_names_are_sorted() {
+        let names: Vec<&str> = CAPABILITY_NAMES.iter().map(|(name, _)| *name).collect();
+        let mut sorted_names = names.clone();
+        sorted_names.sort();
+
+        assert_eq!(names, sorted_names);
+    }
+
+    #[test]
+    fn all_capability_names_parse_to_their_capability() {
+        for (name, capability) in CAPABILITY_NAMES {
+            assert_eq!(name.parse::<Capabilities>().unwrap(), *capability);
+        }
+
+        assert!("transactional_dml".parse::<Capabilities>().is_err());
+    }
+}
@@ -13,6 +13,13 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Returns true to signal to the caller that there are more steps to unapply.
     async fn unapply_step(&self, database_migration: &T, step: usize) -> ConnectorResult<bool>;
 
+    /// Applies all the steps to the database at once, in a transaction, so an interrupted migration leaves the
+    /// database unchanged. Returns the number of applied steps, or `None` if the connector can't apply this
+    /// migration transactionally, in which case the steps have to be applied one by one with `apply_step`.
+    async fn apply_steps_transactionally(&self, _database_migration: &T) -> ConnectorResult<Option<usize>> {
+        Ok(None)
+    }
+
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
 }
@@ -19,6 +19,7 @@ where
 }
 
 /// The errors and warnings emitted by the [DestructiveChangesChecker](trait.DestructiveChangesChecker.html).
+/// Unexecutable migrations are only reported in `unexecutable_migrations`, never as warnings.
 #[derive(Debug, Default)]
 pub struct DestructiveChangeDiagnostics {
     pub errors: Vec<MigrationError>,
@@ -41,12 +42,8 @@ impl DestructiveChangeDiagnostics {
         !self.warnings.is_empty()
     }
 
-    pub fn warn_about_unexecutable_migrations(&mut self) {
-        for unexecutable in &self.unexecutable_migrations {
-            self.warnings.push(MigrationWarning {
-                description: unexecutable.description.clone(),
-            });
-        }
+    pub fn has_unexecutable_migrations(&self) -> bool {
+        !self.unexecutable_migrations.is_empty()
     }
 }
 
@@ -80,6 +80,17 @@ where
         migration_updates: &mut MigrationUpdateParams,
         database_migration: &T,
     ) -> ConnectorResult<()> {
+        if let Some(applied_steps) = self
+            .step_applier
+            .apply_steps_transactionally(&database_migration)
+            .await?
+        {
+            migration_updates.applied = applied_steps;
+            self.migration_persistence.update(&migration_updates).await?;
+            return Ok(());
+        }
+
+        // The steps are not applied in a transaction, so we record the progress after each of them.
         let mut step = 0;
         while self.step_applier.apply_step(&database_migration, step).await? {
             step += 1;
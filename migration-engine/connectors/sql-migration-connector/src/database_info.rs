@@ -1,25 +1,109 @@
 use super::SqlResult;
-use datamodel::{walkers::walk_scalar_fields, Datamodel};
+use datamodel::{
+    walkers::{walk_models, walk_scalar_fields},
+    Datamodel,
+};
 use migration_connector::MigrationError;
 use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
+use regex::RegexSet;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
 pub struct DatabaseInfo {
     connection_info: ConnectionInfo,
     database_version: Option<String>,
+    /// User-provided patterns for tables that should never be migrated.
+    additional_ignored_tables: RegexSet,
+    /// Names of the tables managed outside of Prisma, which are never created, altered or dropped.
+    unmanaged_tables: HashSet<String>,
+    /// Whether created tables, indexes and columns are guarded with `IF NOT EXISTS`.
+    idempotent_ddl: bool,
+    /// Whether indexes are created with `CREATE INDEX CONCURRENTLY` on Postgres.
+    concurrent_index_creation: bool,
+    /// Whether foreign key checks are turned off while migrations are applied.
+    foreign_key_checks_disabled: bool,
+    /// Whether the differ pairs dropped and created tables with the same structure as renames.
+    table_rename_detection: bool,
 }
 
 impl DatabaseInfo {
     pub(crate) async fn new(connection: &Quaint, connection_info: ConnectionInfo) -> SqlResult<Self> {
         let database_version = get_database_version(connection, &connection_info).await?;
 
-        Ok(DatabaseInfo {
+        Ok(Self::from_version(connection_info, database_version))
+    }
+
+    /// The info of a database server running `database_version`, with the default options.
+    pub(crate) fn from_version(connection_info: ConnectionInfo, database_version: Option<String>) -> Self {
+        DatabaseInfo {
             connection_info,
             database_version,
-        })
+            additional_ignored_tables: RegexSet::empty(),
+            unmanaged_tables: HashSet::new(),
+            idempotent_ddl: false,
+            concurrent_index_creation: false,
+            foreign_key_checks_disabled: false,
+            table_rename_detection: false,
+        }
+    }
+
+    pub(crate) fn with_additional_ignored_tables(mut self, ignored_tables: RegexSet) -> Self {
+        self.additional_ignored_tables = ignored_tables;
+        self
+    }
+
+    pub(crate) fn additional_ignored_tables(&self) -> &RegexSet {
+        &self.additional_ignored_tables
+    }
+
+    pub(crate) fn with_unmanaged_tables(mut self, unmanaged_tables: HashSet<String>) -> Self {
+        self.unmanaged_tables = unmanaged_tables;
+        self
+    }
+
+    pub(crate) fn unmanaged_tables(&self) -> &HashSet<String> {
+        &self.unmanaged_tables
+    }
+
+    pub(crate) fn with_idempotent_ddl(mut self, idempotent_ddl: bool) -> Self {
+        self.idempotent_ddl = idempotent_ddl;
+        self
+    }
+
+    pub(crate) fn idempotent_ddl(&self) -> bool {
+        self.idempotent_ddl
+    }
+
+    pub(crate) fn with_concurrent_index_creation(mut self, concurrent_index_creation: bool) -> Self {
+        self.concurrent_index_creation = concurrent_index_creation;
+        self
+    }
+
+    /// Whether `CREATE INDEX` statements are rendered with `CONCURRENTLY`. This is only ever true on
+    /// Postgres.
+    pub(crate) fn concurrent_index_creation(&self) -> bool {
+        self.concurrent_index_creation && self.sql_family() == SqlFamily::Postgres
+    }
+
+    pub(crate) fn with_foreign_key_checks_disabled(mut self, foreign_key_checks_disabled: bool) -> Self {
+        self.foreign_key_checks_disabled = foreign_key_checks_disabled;
+        self
+    }
+
+    pub(crate) fn foreign_key_checks_disabled(&self) -> bool {
+        self.foreign_key_checks_disabled
+    }
+
+    pub(crate) fn with_table_rename_detection(mut self, table_rename_detection: bool) -> Self {
+        self.table_rename_detection = table_rename_detection;
+        self
+    }
+
+    pub(crate) fn table_rename_detection(&self) -> bool {
+        self.table_rename_detection
     }
 
     pub(crate) fn is_mysql_5_6(&self) -> bool {
@@ -40,6 +124,19 @@ impl DatabaseInfo {
                 .unwrap_or(false)
     }
 
+    /// Whether the database understands `ALTER TABLE ... RENAME COLUMN`: Postgres, MySQL 8 and
+    /// SQLite 3.25 onwards.
+    pub(crate) fn supports_rename_column(&self) -> bool {
+        let version = self.database_version.as_deref().and_then(major_minor_version);
+
+        match self.sql_family() {
+            SqlFamily::Postgres => true,
+            SqlFamily::Mysql => !self.is_mariadb() && version.map(|version| version >= (8, 0)).unwrap_or(false),
+            SqlFamily::Sqlite => version.map(|version| version >= (3, 25)).unwrap_or(false),
+            SqlFamily::Mssql => false,
+        }
+    }
+
     pub(crate) fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
@@ -48,13 +145,31 @@ impl DatabaseInfo {
         &self.connection_info
     }
 
+    /// The maximum length of identifiers in bytes. Longer identifiers are silently truncated
+    /// (Postgres) or rejected (MySQL) by the database.
+    pub(crate) fn max_identifier_length(&self) -> Option<usize> {
+        match self.sql_family() {
+            SqlFamily::Postgres => Some(63),
+            SqlFamily::Mysql => Some(64),
+            _ => None,
+        }
+    }
+
     pub(crate) fn check_database_version_compatibility(&self, datamodel: &Datamodel) -> Vec<MigrationError> {
         let mut errors = Vec::new();
 
+        if self.sql_family() == SqlFamily::Mysql {
+            check_datamodel_for_mysql(datamodel, &mut errors)
+        }
+
         if self.is_mysql_5_6() {
             check_datamodel_for_mysql_5_6(datamodel, &mut errors)
         }
 
+        if let Some(max_length) = self.max_identifier_length() {
+            check_datamodel_identifier_lengths(datamodel, max_length, &mut errors)
+        }
+
         errors
     }
 }
@@ -72,10 +187,45 @@ async fn get_database_version(connection: &Quaint, connection_info: &ConnectionI
 
             Ok(version_string)
         }
+        SqlFamily::Sqlite => {
+            let rows = connection.query_raw("SELECT sqlite_version() version", &[]).await?;
+
+            let version_string = rows
+                .get(0)
+                .and_then(|row| row.get("version").and_then(|version| version.to_string()));
+
+            Ok(version_string)
+        }
         _ => Ok(None),
     }
 }
 
+/// Parse the leading `major.minor` of a version string like `8.0.21` or `3.31.1`.
+fn major_minor_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+
+    Some((major, minor))
+}
+
+/// MySQL always names primary keys `PRIMARY`.
+fn check_datamodel_for_mysql(datamodel: &Datamodel, errors: &mut Vec<MigrationError>) {
+    for model in walk_models(datamodel) {
+        if let Some(id_database_name) = model.id_database_name() {
+            errors.push(MigrationError {
+                description: format!(
+                    "The primary key of the model {} is named `{}`, but primary keys cannot be named on MySQL.",
+                    model.name(),
+                    id_database_name
+                ),
+                field: None,
+                tpe: "".into(),
+            })
+        }
+    }
+}
+
 fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<MigrationError>) {
     walk_scalar_fields(datamodel).for_each(|field| {
         if field.field_type().is_json() {
@@ -91,3 +241,28 @@ fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<Migrati
         }
     });
 }
+
+fn check_datamodel_identifier_lengths(datamodel: &Datamodel, max_length: usize, errors: &mut Vec<MigrationError>) {
+    let identifier_too_long = |identifier: &str, location: String| MigrationError {
+        description: format!(
+            "The database name `{}` of {} is longer than the maximum identifier length of {} bytes.",
+            identifier, location, max_length
+        ),
+        field: None,
+        tpe: "".into(),
+    };
+
+    for model in walk_models(datamodel).filter(|model| model.database_name().len() > max_length) {
+        errors.push(identifier_too_long(
+            model.database_name(),
+            format!("the model {}", model.name()),
+        ));
+    }
+
+    for field in walk_scalar_fields(datamodel).filter(|field| field.db_name().len() > max_length) {
+        errors.push(identifier_too_long(
+            field.db_name(),
+            format!("the field {}.{}", field.model().name(), field.name()),
+        ));
+    }
+}
@@ -3,11 +3,14 @@
 //! detail of the SQL connector.
 
 use crate::{
-    catch, connect, database_info::DatabaseInfo, sql_destructive_changes_checker::DestructiveChangeCheckerFlavour,
-    sql_renderer::SqlRenderer, CheckDatabaseInfoResult, SqlError, SqlResult, SystemDatabase,
+    catch, connect,
+    database_info::DatabaseInfo,
+    sql_destructive_changes_checker::DestructiveChangeCheckerFlavour,
+    sql_renderer::{quote_ident, IdentifierQuoting, SqlRenderer},
+    CheckDatabaseInfoResult, SqlError, SqlResult, SystemDatabase,
 };
 use futures::future::TryFutureExt;
-use migration_connector::{ConnectorError, ConnectorResult};
+use migration_connector::{ConnectorError, ConnectorResult, MigrationError};
 use once_cell::sync::Lazy;
 use quaint::{
     connector::{ConnectionInfo, MysqlUrl, PostgresUrl, Queryable},
@@ -15,7 +18,7 @@ use quaint::{
     single::Quaint,
 };
 use regex::RegexSet;
-use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
+use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, Table};
 use std::{
     collections::HashMap,
     fs,
@@ -24,12 +27,22 @@ use std::{
 };
 use url::Url;
 
-pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
+pub(crate) fn from_connection_info(
+    connection_info: &ConnectionInfo,
+    identifier_quoting: IdentifierQuoting,
+) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
     match connection_info {
-        ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour(url.clone())),
-        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour(url.clone())),
+        ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour {
+            url: url.clone(),
+            identifier_quoting,
+        }),
+        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour {
+            url: url.clone(),
+            identifier_quoting,
+        }),
         ConnectionInfo::Sqlite { file_path, .. } => Box::new(SqliteFlavour {
             file_path: file_path.clone(),
+            identifier_quoting,
         }),
         ConnectionInfo::Mssql(_) => todo!("Greetings from Redmond!"),
     }
@@ -47,6 +60,11 @@ pub(crate) trait SqlFlavour: DestructiveChangeCheckerFlavour + SqlRenderer {
         Ok(())
     }
 
+    /// Check that the indexes of the schema can be created on this database.
+    fn check_indexes(&self, _schema: &SqlSchema) -> Vec<MigrationError> {
+        Vec::new()
+    }
+
     /// Create a database called `dbname` on the server, if applicable.
     async fn create_database(&self, database_url: &str) -> ConnectorResult<String>;
 
@@ -57,11 +75,25 @@ pub(crate) trait SqlFlavour: DestructiveChangeCheckerFlavour + SqlRenderer {
         conn: Arc<dyn Queryable + Send + Sync>,
     ) -> SqlResult<SqlSchema>;
 
+    /// Introspect a single table of the SQL schema.
+    async fn describe_table<'a>(
+        &'a self,
+        schema_name: &'a str,
+        table_name: &'a str,
+        conn: Arc<dyn Queryable + Send + Sync>,
+    ) -> SqlResult<Option<Table>>;
+
     /// Create the database schema.
     async fn initialize(&self, conn: &dyn Queryable, database_info: &DatabaseInfo) -> SqlResult<()>;
+
+    /// The statements turning foreign key checks off and back on, in that order.
+    fn foreign_key_checks_toggle(&self) -> (&'static str, &'static str);
 }
 
-pub(crate) struct MysqlFlavour(MysqlUrl);
+pub(crate) struct MysqlFlavour {
+    url: MysqlUrl,
+    pub(crate) identifier_quoting: IdentifierQuoting,
+}
 
 #[async_trait::async_trait]
 impl SqlFlavour for MysqlFlavour {
@@ -85,16 +117,33 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    fn check_indexes(&self, schema: &SqlSchema) -> Vec<MigrationError> {
+        schema
+            .tables
+            .iter()
+            .flat_map(|table| table.indices.iter().map(move |index| (table, index)))
+            .filter(|(_, index)| index.predicate.is_some())
+            .map(|(table, index)| MigrationError {
+                description: format!(
+                    "The index `{}` on the `{}` table is a partial index. Partial indexes are not supported on MySQL.",
+                    index.name, table.name
+                ),
+                field: None,
+                tpe: "".into(),
+            })
+            .collect()
+    }
+
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         let mut url = Url::parse(database_str).unwrap();
         url.set_path("/mysql");
         let (conn, _) = connect(&url.to_string()).await?;
 
-        let db_name = self.0.dbname();
+        let db_name = self.url.dbname();
 
         let query = format!(
-            "CREATE DATABASE `{}` CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;",
-            db_name
+            "CREATE DATABASE {} CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;",
+            quote_ident(SqlFamily::Mysql, db_name)
         );
         catch(conn.connection_info(), conn.raw_cmd(&query).map_err(SqlError::from)).await?;
 
@@ -111,10 +160,21 @@ impl SqlFlavour for MysqlFlavour {
             .await?)
     }
 
+    async fn describe_table<'a>(
+        &'a self,
+        schema_name: &'a str,
+        table_name: &'a str,
+        conn: Arc<dyn Queryable + Send + Sync>,
+    ) -> SqlResult<Option<Table>> {
+        Ok(sql_schema_describer::mysql::SqlSchemaDescriber::new(conn)
+            .describe_table(schema_name, table_name)
+            .await?)
+    }
+
     async fn initialize(&self, conn: &dyn Queryable, database_info: &DatabaseInfo) -> SqlResult<()> {
         let schema_sql = format!(
-            "CREATE SCHEMA IF NOT EXISTS `{}` DEFAULT CHARACTER SET latin1;",
-            database_info.connection_info().schema_name()
+            "CREATE SCHEMA IF NOT EXISTS {} DEFAULT CHARACTER SET latin1;",
+            quote_ident(SqlFamily::Mysql, database_info.connection_info().schema_name())
         );
 
         conn.raw_cmd(&schema_sql).await?;
@@ -125,10 +185,15 @@ impl SqlFlavour for MysqlFlavour {
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Mysql
     }
+
+    fn foreign_key_checks_toggle(&self) -> (&'static str, &'static str) {
+        ("SET FOREIGN_KEY_CHECKS=0", "SET FOREIGN_KEY_CHECKS=1")
+    }
 }
 
 pub(crate) struct SqliteFlavour {
     file_path: String,
+    pub(crate) identifier_quoting: IdentifierQuoting,
 }
 
 #[async_trait::async_trait]
@@ -164,6 +229,17 @@ impl SqlFlavour for SqliteFlavour {
             .await?)
     }
 
+    async fn describe_table<'a>(
+        &'a self,
+        schema_name: &'a str,
+        table_name: &'a str,
+        conn: Arc<dyn Queryable + Send + Sync>,
+    ) -> SqlResult<Option<Table>> {
+        Ok(sql_schema_describer::sqlite::SqlSchemaDescriber::new(conn)
+            .describe_table(schema_name, table_name)
+            .await?)
+    }
+
     async fn initialize(&self, _conn: &dyn Queryable, _database_info: &DatabaseInfo) -> SqlResult<()> {
         let path_buf = PathBuf::from(&self.file_path);
 
@@ -177,19 +253,26 @@ impl SqlFlavour for SqliteFlavour {
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Sqlite
     }
+
+    fn foreign_key_checks_toggle(&self) -> (&'static str, &'static str) {
+        ("PRAGMA foreign_keys=OFF", "PRAGMA foreign_keys=ON")
+    }
 }
 
-pub(crate) struct PostgresFlavour(PostgresUrl);
+pub(crate) struct PostgresFlavour {
+    url: PostgresUrl,
+    pub(crate) identifier_quoting: IdentifierQuoting,
+}
 
 #[async_trait::async_trait]
 impl SqlFlavour for PostgresFlavour {
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         let url = Url::parse(database_str).unwrap();
-        let db_name = self.0.dbname();
+        let db_name = self.url.dbname();
 
         let (conn, _) = create_postgres_admin_conn(url).await?;
 
-        let query = format!("CREATE DATABASE \"{}\"", db_name);
+        let query = format!("CREATE DATABASE {}", quote_ident(SqlFamily::Postgres, db_name));
         catch(conn.connection_info(), conn.raw_cmd(&query).map_err(SqlError::from)).await?;
 
         Ok(db_name.to_owned())
@@ -205,10 +288,21 @@ impl SqlFlavour for PostgresFlavour {
             .await?)
     }
 
+    async fn describe_table<'a>(
+        &'a self,
+        schema_name: &'a str,
+        table_name: &'a str,
+        conn: Arc<dyn Queryable + Send + Sync>,
+    ) -> SqlResult<Option<Table>> {
+        Ok(sql_schema_describer::postgres::SqlSchemaDescriber::new(conn)
+            .describe_table(schema_name, table_name)
+            .await?)
+    }
+
     async fn initialize(&self, conn: &dyn Queryable, database_info: &DatabaseInfo) -> SqlResult<()> {
         let schema_sql = format!(
-            "CREATE SCHEMA IF NOT EXISTS \"{}\";",
-            &database_info.connection_info().schema_name()
+            "CREATE SCHEMA IF NOT EXISTS {};",
+            quote_ident(SqlFamily::Postgres, database_info.connection_info().schema_name())
         );
 
         conn.raw_cmd(&schema_sql).await?;
@@ -219,11 +313,17 @@ impl SqlFlavour for PostgresFlavour {
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Postgres
     }
+
+    /// Postgres can't turn foreign keys off. Deferring them only has an effect on constraints
+    /// declared `DEFERRABLE`, until the end of the transaction.
+    fn foreign_key_checks_toggle(&self) -> (&'static str, &'static str) {
+        ("SET CONSTRAINTS ALL DEFERRED", "SET CONSTRAINTS ALL IMMEDIATE")
+    }
 }
 
 /// Try to connect as an admin to a postgres database. We try to pick a default database from which
 /// we can create another database.
-async fn create_postgres_admin_conn(mut url: Url) -> ConnectorResult<(Quaint, DatabaseInfo)> {
+pub(crate) async fn create_postgres_admin_conn(mut url: Url) -> ConnectorResult<(Quaint, DatabaseInfo)> {
     use migration_connector::ErrorKind;
 
     let candidate_default_databases = &["postgres", "template1"];
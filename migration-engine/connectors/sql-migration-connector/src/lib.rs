@@ -3,9 +3,11 @@
 #![allow(clippy::trivial_regex)] // these will grow
 
 mod component;
+mod connection_string;
 mod database_info;
 mod error;
 mod flavour;
+mod shadow_database;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
@@ -17,6 +19,7 @@ mod sql_schema_differ;
 mod sql_schema_helpers;
 
 pub use error::*;
+pub use sql_database_step_applier::StatementInterceptor;
 pub use sql_migration::*;
 pub use sql_migration_persistence::MIGRATION_TABLE_NAME;
 
@@ -29,11 +32,15 @@ use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
+use regex::RegexSet;
+use shadow_database::ShadowDatabase;
 use sql_database_migration_inferrer::*;
 use sql_database_step_applier::*;
 use sql_destructive_changes_checker::*;
 use sql_migration_persistence::*;
-use sql_schema_describer::SqlSchema;
+use sql_renderer::{quote_ident, IdentifierQuoting};
+use sql_schema_describer::{SqlSchema, Table};
+use sql_schema_differ::DiffingOptions;
 use std::{sync::Arc, time::Duration};
 use tracing::debug;
 
@@ -43,26 +50,118 @@ pub struct SqlMigrationConnector {
     pub database: Arc<dyn Queryable + Send + Sync + 'static>,
     pub database_info: DatabaseInfo,
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+    statement_interceptor: Option<Arc<dyn StatementInterceptor>>,
+    /// The connection string, with environment variables resolved. Used to derive shadow database URLs.
+    database_str: String,
+    /// Set on the connectors returned by `create_shadow_database`.
+    shadow_database: Option<ShadowDatabase>,
+}
+
+/// The outcome of `SqlMigrationConnector::apply_migration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationApplyResult {
+    /// The number of applied steps. Zero means the database was already up to date.
+    pub steps_applied: usize,
+}
+
+impl MigrationApplyResult {
+    /// Whether the migration had no changes to apply.
+    pub fn is_noop(&self) -> bool {
+        self.steps_applied == 0
+    }
 }
 
 impl SqlMigrationConnector {
     pub async fn new(database_str: &str) -> ConnectorResult<Self> {
+        Self::new_with_ignored_tables(database_str, RegexSet::empty()).await
+    }
+
+    /// Construct a connector that leaves the tables matching `ignored_tables` alone, on top of
+    /// the tables that are always ignored (e.g. the migrations table).
+    pub async fn new_with_ignored_tables(database_str: &str, ignored_tables: RegexSet) -> ConnectorResult<Self> {
         let (connection, database_info) = connect(database_str).await?;
-        let flavour = flavour::from_connection_info(database_info.connection_info());
+        let (database_str, _) = connection_string::resolve(database_str)?;
+        let database_info = database_info.with_additional_ignored_tables(ignored_tables);
+        let flavour = flavour::from_connection_info(database_info.connection_info(), IdentifierQuoting::Always);
         flavour.check_database_info(&database_info)?;
 
         Ok(Self {
             flavour,
             database_info,
             database: Arc::new(connection),
+            statement_interceptor: None,
+            database_str: database_str.into_owned(),
+            shadow_database: None,
         })
     }
 
+    /// Leave the tables with the given names untouched when diffing, even when they exist in both
+    /// the database and the datamodel. This is meant for tables maintained by another service.
+    pub fn with_unmanaged_tables<I, S>(mut self, tables: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.database_info = self
+            .database_info
+            .with_unmanaged_tables(tables.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Render `CREATE TABLE`, `CREATE INDEX` and `ADD COLUMN` statements with `IF NOT EXISTS` where the
+    /// database supports it, so a partially applied migration can be applied again. MySQL supports it
+    /// for tables only, and columns are only guarded on Postgres.
+    pub fn with_idempotent_ddl(mut self) -> Self {
+        self.database_info = self.database_info.with_idempotent_ddl(true);
+        self
+    }
+
+    /// Create indexes with `CREATE INDEX CONCURRENTLY` on Postgres, so that writes to the table are not
+    /// blocked while the index is built. Such statements can't run in a transaction block, so only the
+    /// steps before the first index creation are applied in a transaction, and the rest step by step.
+    pub fn with_concurrent_index_creation(mut self) -> Self {
+        self.database_info = self.database_info.with_concurrent_index_creation(true);
+        self
+    }
+
+    /// Turn foreign key checks off while migrations are applied, and back on afterwards, so that
+    /// steps can run in an order that temporarily violates foreign keys. The checks are turned back
+    /// on when a step fails too. This is an escape hatch: the data is not checked again when the
+    /// checks are turned back on. On Postgres, where the checks can't be turned off, only constraints
+    /// declared `DEFERRABLE` are deferred, and only for migrations applied in a single transaction.
+    pub fn with_foreign_key_checks_disabled(mut self) -> Self {
+        self.database_info = self.database_info.with_foreign_key_checks_disabled(true);
+        self
+    }
+
+    /// Diff a dropped table and a created table with the same columns and indexes as a rename of the
+    /// dropped table, keeping its data. The heuristic can pair unrelated tables that happen to have
+    /// the same structure, so it is off by default.
+    pub fn with_table_rename_detection(mut self) -> Self {
+        self.database_info = self.database_info.with_table_rename_detection(true);
+        self
+    }
+
+    /// Only quote table and column names in the generated SQL when they are reserved words or would
+    /// not be valid identifiers otherwise. Some drivers and tools downstream of the migration SQL do
+    /// not cope well with quoted identifiers everywhere.
+    pub fn with_minimal_identifier_quoting(mut self) -> Self {
+        self.flavour =
+            flavour::from_connection_info(self.database_info.connection_info(), IdentifierQuoting::WhenNecessary);
+        self
+    }
+
+    /// Pass every statement through `interceptor` before executing it when applying migrations. See
+    /// `StatementInterceptor`.
+    pub fn with_statement_interceptor(mut self, interceptor: Arc<dyn StatementInterceptor>) -> Self {
+        self.statement_interceptor = Some(interceptor);
+        self
+    }
+
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
-        let connection_info =
-            ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let flavour = flavour::from_connection_info(&connection_info);
-        flavour.create_database(database_str).await
+        let (database_str, connection_info) = connection_string::resolve(database_str)?;
+        let flavour = flavour::from_connection_info(&connection_info, IdentifierQuoting::Always);
+        flavour.create_database(&database_str).await
     }
 
     async fn drop_database(&self) -> ConnectorResult<()> {
@@ -71,7 +170,10 @@ impl SqlMigrationConnector {
         catch(self.database_info.connection_info(), async {
             match &self.database_info.connection_info() {
                 ConnectionInfo::Postgres(_) => {
-                    let sql_str = format!(r#"DROP SCHEMA "{}" CASCADE;"#, self.schema_name());
+                    let sql_str = format!(
+                        "DROP SCHEMA {} CASCADE;",
+                        quote_ident(SqlFamily::Postgres, self.schema_name())
+                    );
                     debug!("{}", sql_str);
 
                     self.conn().raw_cmd(&sql_str).await.ok();
@@ -90,7 +192,7 @@ impl SqlMigrationConnector {
                         .await?;
                 }
                 ConnectionInfo::Mysql(_) => {
-                    let sql_str = format!(r#"DROP SCHEMA `{}`;"#, self.schema_name());
+                    let sql_str = format!("DROP SCHEMA {};", quote_ident(SqlFamily::Mysql, self.schema_name()));
                     debug!("{}", sql_str);
                     self.conn().raw_cmd(&sql_str).await?;
                 }
@@ -104,6 +206,244 @@ impl SqlMigrationConnector {
         Ok(())
     }
 
+    /// Render the migration as a SQL script, ready to be written to a `.sql` file. When
+    /// `migration_name` is set, the script starts with a `-- Migration: <name>` comment.
+    pub fn render_steps_to_sql(
+        &self,
+        database_migration: &SqlMigration,
+        migration_name: Option<&str>,
+    ) -> ConnectorResult<String> {
+        sql_database_step_applier::render_steps_to_sql(
+            database_migration,
+            self.flavour.as_ref(),
+            &self.database_info,
+            migration_name,
+        )
+    }
+
+    /// Render each step of the migration on its own, keeping track of which statements each step
+    /// produced. Steps that render to no statements are kept, with an empty list.
+    pub fn render_steps_detailed(
+        &self,
+        database_migration: &SqlMigration,
+    ) -> ConnectorResult<Vec<(SqlMigrationStep, Vec<String>)>> {
+        sql_database_step_applier::render_steps_detailed(database_migration, self.flavour.as_ref(), &self.database_info)
+    }
+
+    /// Render the SQL statements undoing `steps`, to be run in order. Returns `None` when any of the
+    /// steps cannot be automatically reversed, e.g. because it drops data.
+    pub fn render_rollback_script(&self, steps: &[SqlMigrationStep]) -> ConnectorResult<Option<Vec<String>>> {
+        sql_database_step_applier::render_rollback_script(steps, self.flavour.as_ref(), &self.database_info)
+    }
+
+    /// Apply all the steps of the migration, in a single transaction when the database allows it. An
+    /// empty migration is not an error, the result reports zero applied steps.
+    pub async fn apply_migration(&self, database_migration: &SqlMigration) -> ConnectorResult<MigrationApplyResult> {
+        let applier = SqlDatabaseStepApplier::new(self);
+
+        if let Some(steps_applied) = applier.apply_steps_transactionally(database_migration).await? {
+            return Ok(MigrationApplyResult { steps_applied });
+        }
+
+        let steps_applied = database_migration.corrected_steps.len();
+
+        for index in 0..steps_applied {
+            applier.apply_step(database_migration, index).await?;
+        }
+
+        Ok(MigrationApplyResult { steps_applied })
+    }
+
+    /// Apply a single step to the database. See `SqlDatabaseStepApplier::apply_single_step`.
+    pub async fn apply_single_step(&self, step: &SqlMigrationStep) -> ConnectorResult<()> {
+        SqlDatabaseStepApplier::new(self).apply_single_step(step).await
+    }
+
+    /// Describe a single table of the connector's schema, or return `None` if it does not exist. This
+    /// is much cheaper than describing the whole schema when only one table is needed.
+    pub async fn describe_table(&self, name: &str) -> ConnectorResult<Option<Table>> {
+        let conn = self.database.clone();
+        let fut = self.flavour.describe_table(self.schema_name(), name, conn);
+
+        catch(self.database_info.connection_info(), fut).await
+    }
+
+    /// Diff the datamodel against the live database schema, returning the steps needed to bring the
+    /// database in line with the datamodel. This ignores the migrations history, so it also surfaces
+    /// changes made to the database by hand.
+    pub async fn diff_against_datamodel(&self, datamodel: &datamodel::dml::Datamodel) -> ConnectorResult<SqlMigration> {
+        let fut = async {
+            let current_database_schema = self.describe_schema().await?;
+            let expected_database_schema =
+                sql_schema_calculator::SqlSchemaCalculator::calculate(datamodel, &self.database_info)?;
+
+            sql_database_migration_inferrer::infer(
+                &current_database_schema,
+                &expected_database_schema,
+                self.schema_name(),
+                self.sql_family(),
+                &self.database_info,
+                self.flavour.as_ref(),
+            )
+        };
+
+        catch(self.database_info.connection_info(), fut).await
+    }
+
+    /// Create a temporary database on the same server, holding a copy of the schema of this
+    /// connector, and return a connector pointed at it. Migrations can be applied there to check
+    /// that they succeed without touching the real database. The shadow database is dropped together
+    /// with the returned connector, or with `drop_shadow_database`, which also reports errors. Only
+    /// PostgreSQL and MySQL are supported.
+    pub async fn create_shadow_database(&self) -> ConnectorResult<SqlMigrationConnector> {
+        let shadow_database = ShadowDatabase::new(&self.database_str, self.sql_family())?;
+
+        Self::create_database(shadow_database.url()).await?;
+
+        let mut shadow = match Self::new_with_ignored_tables(
+            shadow_database.url(),
+            self.database_info.additional_ignored_tables().clone(),
+        )
+        .await
+        {
+            Ok(shadow) => shadow,
+            Err(err) => {
+                shadow_database.drop_database().await.ok();
+                return Err(err);
+            }
+        };
+        shadow.shadow_database = Some(shadow_database);
+
+        match self.copy_schema_to(&shadow).await {
+            Ok(()) => Ok(shadow),
+            Err(err) => {
+                shadow.drop_shadow_database().await.ok();
+                Err(err)
+            }
+        }
+    }
+
+    /// The name of the shadow database, on connectors returned by `create_shadow_database`.
+    pub fn shadow_database_name(&self) -> Option<&str> {
+        self.shadow_database
+            .as_ref()
+            .map(|shadow_database| shadow_database.name())
+    }
+
+    /// Close the connection of a connector returned by `create_shadow_database` and drop its
+    /// database. Other connectors are rejected.
+    pub async fn drop_shadow_database(mut self) -> ConnectorResult<()> {
+        let shadow_database = self.shadow_database.take().ok_or_else(|| {
+            ConnectorError::from_kind(migration_connector::ErrorKind::Generic(anyhow::anyhow!(
+                "Only connectors created with `create_shadow_database` can be dropped."
+            )))
+        })?;
+
+        drop(self);
+
+        shadow_database.drop_database().await
+    }
+
+    /// Create the schema of this connector in the (empty) database of `shadow`.
+    async fn copy_schema_to(&self, shadow: &SqlMigrationConnector) -> ConnectorResult<()> {
+        let fut = async {
+            shadow
+                .flavour
+                .initialize(shadow.database.as_ref(), &shadow.database_info)
+                .await?;
+
+            let current_database_schema = self.describe_schema().await?;
+
+            sql_database_migration_inferrer::infer(
+                &SqlSchema::empty(),
+                &current_database_schema,
+                shadow.schema_name(),
+                shadow.sql_family(),
+                &shadow.database_info,
+                shadow.flavour.as_ref(),
+            )
+        };
+
+        let migration = catch(self.database_info.connection_info(), fut).await?;
+        shadow.apply_migration(&migration).await?;
+
+        Ok(())
+    }
+
+    /// Rename a column of an existing table in a transaction, keeping its data. Indexes, the primary
+    /// key and foreign keys on the column or referencing it follow it.
+    pub async fn rename_column(&self, table: &str, from: &str, to: &str) -> ConnectorResult<()> {
+        let schema = catch(self.database_info.connection_info(), self.describe_schema()).await?;
+        let statements = sql_database_step_applier::render_rename_column(
+            table,
+            from,
+            to,
+            &schema,
+            self.flavour.as_ref(),
+            &self.database_info,
+        )
+        .map_err(|err| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err)))?;
+
+        catch(self.database_info.connection_info(), async {
+            // SQLite ignores the `foreign_keys` pragma inside of a transaction. The rebuilt tables must
+            // not trip over the foreign keys referencing them.
+            let toggle_foreign_keys = self.flavour.sql_family() == SqlFamily::Sqlite;
+            let (disable_foreign_keys, enable_foreign_keys) = self.flavour.foreign_key_checks_toggle();
+
+            if toggle_foreign_keys {
+                self.conn().raw_cmd(disable_foreign_keys).await?;
+            }
+
+            let renamed = async {
+                self.conn().raw_cmd("BEGIN").await?;
+
+                for statement in &statements {
+                    debug!("{}", statement);
+
+                    if let Err(err) = self.conn().raw_cmd(statement).await {
+                        self.conn().raw_cmd("ROLLBACK").await?;
+                        return Err(err);
+                    }
+                }
+
+                self.conn().raw_cmd("COMMIT").await
+            }
+            .await;
+
+            if toggle_foreign_keys {
+                self.conn().raw_cmd(enable_foreign_keys).await?;
+            }
+
+            Ok(renamed?)
+        })
+        .await
+    }
+
+    /// The names of the migrations applied to the database, in the order they were applied.
+    pub async fn applied_migrations(&self) -> ConnectorResult<Vec<String>> {
+        SqlMigrationPersistence { connector: self }
+            .applied_migration_names()
+            .await
+    }
+
+    /// Check that the indexes of the schema can be created on the database, e.g. that it does not
+    /// contain partial indexes on MySQL.
+    pub fn check_index_compatibility(&self, schema: &SqlSchema) -> Vec<MigrationError> {
+        self.flavour.check_indexes(schema)
+    }
+
+    /// Whether the schema has no user tables. The migration table and ignored tables are not counted.
+    /// Tooling can check this before initializing a database, to avoid clobbering an existing one.
+    pub async fn is_empty(&self) -> ConnectorResult<bool> {
+        let schema = catch(self.database_info.connection_info(), self.describe_schema()).await?;
+        let diffing_options = DiffingOptions::from_database_info(&self.database_info);
+
+        Ok(schema
+            .tables
+            .iter()
+            .all(|table| diffing_options.table_is_ignored(&table.name)))
+    }
+
     async fn describe_schema(&self) -> SqlResult<SqlSchema> {
         let conn = self.connector().database.clone();
         let schema_name = self.schema_name();
@@ -146,7 +486,14 @@ impl MigrationConnector for SqlMigrationConnector {
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(&self, datamodel: &datamodel::dml::Datamodel) -> Vec<MigrationError> {
-        self.database_info.check_database_version_compatibility(datamodel)
+        let mut errors = self.database_info.check_database_version_compatibility(datamodel);
+
+        // Calculation errors are reported when inferring the migration.
+        if let Ok(schema) = sql_schema_calculator::SqlSchemaCalculator::calculate(datamodel, &self.database_info) {
+            errors.extend(self.check_index_compatibility(&schema));
+        }
+
+        errors
     }
 
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a> {
@@ -158,7 +505,7 @@ impl MigrationConnector for SqlMigrationConnector {
     }
 
     fn database_migration_step_applier<'a>(&'a self) -> Box<dyn DatabaseMigrationStepApplier<SqlMigration> + 'a> {
-        Box::new(SqlDatabaseStepApplier { connector: self })
+        Box::new(SqlDatabaseStepApplier::new(self))
     }
 
     fn destructive_changes_checker<'a>(&'a self) -> Box<dyn DestructiveChangesChecker<SqlMigration> + 'a> {
@@ -181,11 +528,10 @@ pub(crate) async fn catch<O>(
 }
 
 async fn connect(database_str: &str) -> ConnectorResult<(Quaint, DatabaseInfo)> {
-    let connection_info =
-        ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+    let (database_str, connection_info) = connection_string::resolve(database_str)?;
 
     let connection_fut = async {
-        let connection = Quaint::new(database_str)
+        let connection = Quaint::new(&database_str)
             .await
             .map_err(SqlError::from)
             .map_err(|err: SqlError| err.into_connector_error(&connection_info))?;
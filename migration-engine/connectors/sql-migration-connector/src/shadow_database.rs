@@ -0,0 +1,124 @@
+//! Shadow databases are temporary databases holding a copy of the schema of the connector, so
+//! migrations can be tried out without touching the real database.
+
+use crate::{catch, connect, flavour::create_postgres_admin_conn, sql_renderer::quote_ident, SqlError};
+use futures::future::TryFutureExt;
+use migration_connector::{ConnectorError, ConnectorResult, ErrorKind};
+use quaint::prelude::{Queryable, SqlFamily};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use url::Url;
+
+static SHADOW_DATABASE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A shadow database created by `SqlMigrationConnector::create_shadow_database`. It is dropped with
+/// `ShadowDatabase::drop_database`, or when the `ShadowDatabase` is dropped otherwise.
+pub(crate) struct ShadowDatabase {
+    name: String,
+    url: Url,
+    sql_family: SqlFamily,
+    /// Whether `drop_database` was called, so the drop guard has nothing left to do.
+    dropped: bool,
+}
+
+impl ShadowDatabase {
+    /// Pick a name for a shadow database of the database at `database_str`, on the same server.
+    pub(crate) fn new(database_str: &str, sql_family: SqlFamily) -> ConnectorResult<Self> {
+        if !matches!(sql_family, SqlFamily::Postgres | SqlFamily::Mysql) {
+            return Err(ConnectorError::from_kind(ErrorKind::Generic(anyhow::anyhow!(
+                "Shadow databases are only supported on PostgreSQL and MySQL."
+            ))));
+        }
+
+        let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let name = format!(
+            "prisma_shadow_{}_{}",
+            std::process::id(),
+            SHADOW_DATABASE_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+
+        url.set_path(&format!("/{}", name));
+
+        Ok(ShadowDatabase {
+            name,
+            url,
+            sql_family,
+            dropped: false,
+        })
+    }
+
+    pub(crate) fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Drop the database, reporting errors. See `drop_database`.
+    pub(crate) async fn drop_database(mut self) -> ConnectorResult<()> {
+        self.dropped = true;
+
+        drop_database(&self.name, self.url.clone(), self.sql_family).await
+    }
+}
+
+impl Drop for ShadowDatabase {
+    /// Drop the database if `drop_database` was not called. Destructors can't be async, so the
+    /// database is dropped on a runtime of its own, in another thread so that it does not run
+    /// inside the runtime of the caller. Errors are ignored.
+    fn drop(&mut self) {
+        if self.dropped {
+            return;
+        }
+
+        let name = self.name.clone();
+        let url = self.url.clone();
+        let sql_family = self.sql_family;
+
+        let cleanup = std::thread::spawn(move || {
+            let mut runtime = tokio::runtime::Builder::new()
+                .basic_scheduler()
+                .enable_all()
+                .build()
+                .ok()?;
+
+            runtime.block_on(drop_database(&name, url, sql_family)).ok()
+        });
+
+        cleanup.join().ok();
+    }
+}
+
+/// Drop the database from an administration connection. Postgres refuses to drop a database with
+/// open connections, so the ones left, e.g. by clones of the shadow connector's `database`, are
+/// terminated first.
+async fn drop_database(name: &str, mut url: Url, sql_family: SqlFamily) -> ConnectorResult<()> {
+    match sql_family {
+        SqlFamily::Postgres => {
+            let (conn, _) = create_postgres_admin_conn(url).await?;
+            let fut = async {
+                conn.query_raw(
+                    "SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE datname = $1 AND pid <> pg_backend_pid()",
+                    &[name.into()],
+                )
+                .await?;
+                conn.raw_cmd(&format!(
+                    "DROP DATABASE IF EXISTS {}",
+                    quote_ident(SqlFamily::Postgres, name)
+                ))
+                .await?;
+
+                Ok::<_, quaint::error::Error>(())
+            };
+
+            catch(conn.connection_info(), fut.map_err(SqlError::from)).await
+        }
+        _ => {
+            url.set_path("/mysql");
+            let (conn, _) = connect(url.as_str()).await?;
+            let query = format!("DROP DATABASE IF EXISTS {}", quote_ident(SqlFamily::Mysql, name));
+
+            catch(conn.connection_info(), conn.raw_cmd(&query).map_err(SqlError::from)).await
+        }
+    }
+}
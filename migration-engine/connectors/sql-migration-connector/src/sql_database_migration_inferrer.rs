@@ -1,4 +1,4 @@
-mod sqlite;
+pub(crate) mod sqlite;
 
 use crate::sql_schema_calculator::SqlSchemaCalculator;
 use crate::sql_schema_differ::{SqlSchemaDiff, SqlSchemaDiffer};
@@ -66,7 +66,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
     }
 }
 
-fn infer(
+pub(crate) fn infer(
     current_database_schema: &SqlSchema,
     expected_database_schema: &SqlSchema,
     schema_name: &str,
@@ -107,6 +107,17 @@ fn infer_database_migration_steps_and_fix(
     database_info: &DatabaseInfo,
     flavour: &dyn SqlFlavour,
 ) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
+    // Match the tables and columns Postgres folded to lower case when they were created unquoted.
+    let folded_to;
+    let to = if sql_family.is_postgres() {
+        let mut normalized = to.clone();
+        sql_schema_differ::normalize_folded_identifiers(from, &mut normalized);
+        folded_to = normalized;
+        &folded_to
+    } else {
+        to
+    };
+
     let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(
         &from,
         &to,
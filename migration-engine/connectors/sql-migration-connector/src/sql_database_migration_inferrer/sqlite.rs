@@ -2,7 +2,6 @@ use crate::{
     database_info::DatabaseInfo,
     flavour::SqlFlavour,
     sql_migration::*,
-    sql_renderer::Quoted,
     sql_schema_differ::{ColumnDiffer, DiffingOptions, SqlSchemaDiff, TableDiffer},
     sql_schema_helpers::{SqlSchemaExt, TableRef},
     SqlResult,
@@ -21,10 +20,16 @@ pub(super) fn fix(
 
     let mut result = Vec::new();
     let mut fixed_tables = Vec::new();
+    // The step applier already turns the foreign keys off around the whole migration.
+    let toggle_foreign_keys = !database_info.foreign_key_checks_disabled();
 
-    result.push(SqlMigrationStep::RawSql {
-        raw: "PRAGMA foreign_keys=OFF;".to_string(),
-    });
+    if toggle_foreign_keys {
+        result.push(SqlMigrationStep::RawSql {
+            raw: "PRAGMA foreign_keys=OFF;".to_string(),
+        });
+    }
+
+    let steps_start = result.len();
 
     for step in steps {
         match step {
@@ -66,17 +71,19 @@ pub(super) fn fix(
     }
 
     // No steps
-    if result.len() == 1 {
+    if result.len() == steps_start {
         return Ok(Vec::new());
     }
 
     result.push(SqlMigrationStep::RawSql {
-        raw: format!("PRAGMA {}.foreign_key_check;", Quoted::sqlite_ident(schema_name)),
+        raw: format!("PRAGMA {}.foreign_key_check;", flavour.quote(schema_name)),
     });
 
-    result.push(SqlMigrationStep::RawSql {
-        raw: "PRAGMA foreign_keys=ON;".to_string(),
-    });
+    if toggle_foreign_keys {
+        result.push(SqlMigrationStep::RawSql {
+            raw: "PRAGMA foreign_keys=ON;".to_string(),
+        });
+    }
 
     Ok(result)
 }
@@ -93,6 +100,12 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
         | TableChange::AlterColumn(_)
         | TableChange::DropPrimaryKey { .. }
         | TableChange::AddPrimaryKey { .. } => true,
+        // SQLite tables have no character set.
+        TableChange::ConvertCharacterSet(_) => false,
+        // SQLite tables have no comment.
+        TableChange::SetComment(_) => false,
+        // SQLite tables have no storage parameters.
+        TableChange::SetStorageParameters(_) => false,
     });
 
     change_that_does_not_work_on_sqlite.is_some()
@@ -112,12 +125,15 @@ fn sqlite_fix_table(
     let next_table = next_database_schema
         .table_ref(table_name)
         .expect("SQLite table referenced in migration not found.");
-    Ok(fix_table(current_table, next_table, &schema_name, database_info, flavour).into_iter())
+    Ok(fix_table(current_table, next_table, &[], &schema_name, database_info, flavour).into_iter())
 }
 
-fn fix_table(
+/// Rebuild the `current` table as `next`, copying the data over. The columns in `renamed_columns`
+/// are copied from their previous name to their next name.
+pub(crate) fn fix_table(
     current: TableRef<'_>,
     next: TableRef<'_>,
+    renamed_columns: &[(&str, &str)],
     schema_name: &str,
     database_info: &DatabaseInfo,
     flavour: &dyn SqlFlavour,
@@ -143,6 +159,7 @@ fn fix_table(
             previous: current,
             next: TableRef::new(next.schema, &temporary_table),
         },
+        renamed_columns,
         schema_name,
         flavour,
     )
@@ -152,10 +169,10 @@ fn fix_table(
         name: current.name().to_owned(),
     }));
 
-    result.push(SqlMigrationStep::RenameTable {
+    result.push(SqlMigrationStep::RenameTable(RenameTable {
         name: name_of_temporary_table,
         new_name: next.name().to_owned(),
-    });
+    }));
 
     // Recreate the indices
     result.extend(next.table.indices.iter().map(|index| {
@@ -176,6 +193,7 @@ fn fix_table(
 fn copy_current_table_into_new_table(
     steps: &mut Vec<SqlMigrationStep>,
     differ: TableDiffer<'_>,
+    renamed_columns: &[(&str, &str)],
     schema_name: &str,
     flavour: &dyn SqlFlavour,
 ) -> std::fmt::Result {
@@ -202,9 +220,8 @@ fn copy_current_table_into_new_table(
 
     write!(
         query,
-        "INSERT INTO {}.{} (",
-        Quoted::sqlite_ident(schema_name),
-        Quoted::sqlite_ident(&differ.next.name())
+        "INSERT INTO {} (",
+        flavour.quote_with_schema(schema_name, &differ.next.name())
     )?;
 
     let mut destination_columns = intersection_columns
@@ -215,10 +232,11 @@ fn copy_current_table_into_new_table(
                 .iter()
                 .map(|columns| columns.name()),
         )
+        .chain(renamed_columns.iter().map(|(_, next_name)| *next_name))
         .peekable();
 
     while let Some(destination_column) = destination_columns.next() {
-        write!(query, "{}", Quoted::sqlite_ident(destination_column))?;
+        write!(query, "{}", flavour.quote(destination_column))?;
 
         if destination_columns.peek().is_some() {
             write!(query, ", ")?;
@@ -229,11 +247,11 @@ fn copy_current_table_into_new_table(
 
     let mut source_columns = intersection_columns
         .iter()
-        .map(|s| format!("{}", Quoted::sqlite_ident(s)))
+        .map(|s| format!("{}", flavour.quote(s)))
         .chain(columns_that_became_required_with_a_default.iter().map(|columns| {
             format!(
                 "coalesce({column_name}, {default_value}) AS {column_name}",
-                column_name = Quoted::sqlite_ident(columns.name()),
+                column_name = flavour.quote(columns.name()),
                 default_value = flavour.render_default(
                     columns
                         .next
@@ -245,6 +263,11 @@ fn copy_current_table_into_new_table(
                 )
             )
         }))
+        .chain(
+            renamed_columns
+                .iter()
+                .map(|(previous_name, _)| format!("{}", flavour.quote(previous_name))),
+        )
         .peekable();
 
     while let Some(source_column) = source_columns.next() {
@@ -257,9 +280,8 @@ fn copy_current_table_into_new_table(
 
     write!(
         query,
-        " FROM {}.{}",
-        Quoted::sqlite_ident(schema_name),
-        Quoted::sqlite_ident(&differ.previous.name())
+        " FROM {}",
+        flavour.quote_with_schema(schema_name, &differ.previous.name())
     )?;
 
     steps.push(SqlMigrationStep::RawSql { raw: query });
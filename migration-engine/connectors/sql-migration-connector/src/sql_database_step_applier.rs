@@ -2,13 +2,28 @@ use crate::*;
 use sql_renderer::{postgres_render_column_type, rendered_step::RenderedStep, IteratorJoin, Quoted};
 use sql_schema_describer::*;
 use sql_schema_differ::{ColumnDiffer, DiffingOptions};
-use sql_schema_helpers::{find_column, walk_columns, ColumnRef, SqlSchemaExt};
-use std::fmt::Write as _;
+use sql_schema_helpers::{find_column, walk_columns, ColumnRef, SqlSchemaExt, TableRef};
+use std::{
+    fmt::Write as _,
+    sync::atomic::{AtomicBool, Ordering},
+};
 use tracing_futures::Instrument;
 use SqlFlavour;
 
+/// A hook to rewrite the SQL of a migration right before it is executed, e.g. to set session parameters like
+/// `lock_timeout` before each DDL statement.
+pub trait StatementInterceptor: Send + Sync {
+    /// Receives a rendered statement and returns the statements to execute in its place.
+    fn intercept(&self, statement: String) -> Vec<String>;
+}
+
 pub struct SqlDatabaseStepApplier<'a> {
     pub connector: &'a crate::SqlMigrationConnector,
+    /// Rewrites each rendered statement before it is executed, when set.
+    pub statement_interceptor: Option<&'a dyn StatementInterceptor>,
+    /// Whether the foreign key checks were turned off by a step of the migration being applied one
+    /// step at a time, and not turned back on yet.
+    foreign_key_checks_turned_off: AtomicBool,
 }
 
 impl crate::component::Component for SqlDatabaseStepApplier<'_> {
@@ -47,6 +62,18 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
         crate::catch(self.connection_info(), fut).await
     }
 
+    async fn apply_steps_transactionally(&self, database_migration: &SqlMigration) -> ConnectorResult<Option<usize>> {
+        let fut = self
+            .apply_steps_in_transaction(
+                &database_migration.corrected_steps,
+                &database_migration.before,
+                &database_migration.after,
+            )
+            .instrument(tracing::debug_span!("ApplySqlStepsInTransaction"));
+
+        crate::catch(self.connection_info(), fut).await
+    }
+
     fn render_steps_pretty(
         &self,
         database_migration: &SqlMigration,
@@ -61,7 +88,271 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
     }
 }
 
-impl SqlDatabaseStepApplier<'_> {
+impl<'a> SqlDatabaseStepApplier<'a> {
+    pub(crate) fn new(connector: &'a crate::SqlMigrationConnector) -> Self {
+        SqlDatabaseStepApplier {
+            connector,
+            statement_interceptor: connector.statement_interceptor.as_deref(),
+            foreign_key_checks_turned_off: AtomicBool::new(false),
+        }
+    }
+
+    /// Surround the statements of a migration applied in a single transaction with the flavour's
+    /// statements turning foreign key checks off and on, when configured.
+    fn toggle_foreign_key_checks(&self, statements: Vec<String>) -> Vec<String> {
+        if !self.database_info().foreign_key_checks_disabled() || statements.is_empty() {
+            return statements;
+        }
+
+        let (disable, enable) = self.flavour().foreign_key_checks_toggle();
+
+        std::iter::once(disable.to_owned())
+            .chain(statements)
+            .chain(std::iter::once(enable.to_owned()))
+            .collect()
+    }
+
+    /// Whether foreign key checks are turned off before the first step this applier applies one by one,
+    /// and back on after the last one or after a failing step. Deferring constraints on Postgres has no effect
+    /// outside of a transaction.
+    fn toggles_foreign_key_checks_between_steps(&self) -> bool {
+        self.database_info().foreign_key_checks_disabled() && self.sql_family() != SqlFamily::Postgres
+    }
+
+    async fn execute_statements(&self, index: usize, statements: Vec<String>) -> SqlResult<()> {
+        for sql_string in self.intercept(statements) {
+            tracing::debug!(index, %sql_string);
+
+            self.conn().raw_cmd(&sql_string).await?;
+        }
+
+        Ok(())
+    }
+
+    fn intercept(&self, statements: Vec<String>) -> Vec<String> {
+        match self.statement_interceptor {
+            Some(interceptor) => statements
+                .into_iter()
+                .flat_map(|statement| interceptor.intercept(statement))
+                .collect(),
+            None => statements,
+        }
+    }
+
+    /// Render and execute a single step, e.g. to find out which step of a large migration fails.
+    /// The step is rendered against the current database schema, updated with the table the step
+    /// creates or alters, if any.
+    pub async fn apply_single_step(&self, step: &SqlMigrationStep) -> ConnectorResult<()> {
+        let fut = async {
+            let current_schema = self.describe().await?;
+            let mut next_schema = current_schema.clone();
+
+            match step {
+                SqlMigrationStep::CreateTable(CreateTable { table })
+                | SqlMigrationStep::AlterTable(AlterTable { table, .. }) => {
+                    next_schema.tables.retain(|next_table| next_table.name != table.name);
+                    next_schema.tables.push(table.clone());
+                }
+                _ => (),
+            }
+
+            self.apply_next_step(
+                std::slice::from_ref(step),
+                0,
+                self.flavour(),
+                &current_schema,
+                &next_schema,
+            )
+            .await?;
+
+            Ok::<(), SqlError>(())
+        }
+        .instrument(tracing::debug_span!("ApplySingleSqlStep"));
+
+        crate::catch(self.connection_info(), fut).await
+    }
+
+    async fn apply_steps_in_transaction(
+        &self,
+        steps: &[SqlMigrationStep],
+        current_schema: &SqlSchema,
+        next_schema: &SqlSchema,
+    ) -> SqlResult<Option<usize>> {
+        // `ALTER TYPE ... ADD VALUE` can't be executed in a transaction block.
+        let alters_enum = |step: &SqlMigrationStep| matches!(step, SqlMigrationStep::AlterEnum(_));
+
+        match self.sql_family() {
+            SqlFamily::Postgres if !steps.iter().any(alters_enum) => (),
+            SqlFamily::Sqlite => (),
+            // MySQL commits implicitly after each DDL statement.
+            _ => return Ok(None),
+        }
+
+        // Neither can `CREATE INDEX CONCURRENTLY`. The steps before the first of these are applied in
+        // a transaction, and the rest one by one once it is committed. Later steps can depend on the
+        // index, e.g. a foreign key on a new unique column, so the order of the steps is kept.
+        let concurrent_index_position = steps
+            .iter()
+            .position(|step| self.creates_index_concurrently(step))
+            .unwrap_or_else(|| steps.len());
+        let (steps_in_transaction, steps_after_transaction) = steps.split_at(concurrent_index_position);
+
+        let mut statements = Vec::with_capacity(steps_in_transaction.len());
+
+        for step in steps_in_transaction {
+            statements.extend(
+                render_raw_sql(&step, self.flavour(), self.database_info(), current_schema, next_schema)
+                    .map_err(SqlError::Generic)?,
+            );
+        }
+
+        let statements = self.intercept(self.toggle_foreign_key_checks(statements));
+
+        if self.sql_family() == SqlFamily::Sqlite {
+            if statements.is_empty() {
+                return Ok(Some(steps.len()));
+            }
+
+            return self
+                .apply_sqlite_statements_in_transaction(statements, steps.len())
+                .await;
+        }
+
+        // A multi-statement query runs in a single implicit transaction on Postgres. It is rolled
+        // back as a whole if any statement fails, or if the connection goes away before it completes.
+        if !statements.is_empty() {
+            let script = join_statements(&statements);
+            tracing::debug!(%script);
+
+            self.conn().raw_cmd(&script).await?;
+        }
+
+        for (index, step) in steps_after_transaction.iter().enumerate() {
+            let statements = render_raw_sql(step, self.flavour(), self.database_info(), current_schema, next_schema)
+                .map_err(SqlError::Generic)?;
+
+            self.execute_create_index_statements(concurrent_index_position + index, step, statements)
+                .await?;
+        }
+
+        Ok(Some(steps.len()))
+    }
+
+    /// Whether the step is a `CREATE INDEX CONCURRENTLY`. Unique indexes backing a deferrable
+    /// constraint are created with the constraint, in the transaction.
+    fn creates_index_concurrently(&self, step: &SqlMigrationStep) -> bool {
+        match step {
+            SqlMigrationStep::CreateIndex(CreateIndex { index, .. }) => {
+                self.database_info().concurrent_index_creation() && index.deferrable.is_none()
+            }
+            _ => false,
+        }
+    }
+
+    /// Execute the statements of a step, and when the step is a `CREATE INDEX CONCURRENTLY` that
+    /// fails, drop the invalid index Postgres leaves behind, so that applying the migration again
+    /// does not trip over it.
+    async fn execute_create_index_statements(
+        &self,
+        index: usize,
+        step: &SqlMigrationStep,
+        statements: Vec<String>,
+    ) -> SqlResult<()> {
+        let result = self.execute_statements(index, statements).await;
+
+        match (result, step) {
+            (Err(err), SqlMigrationStep::CreateIndex(CreateIndex { index: created, .. }))
+                if self.creates_index_concurrently(step) =>
+            {
+                if let Err(drop_err) = self.drop_invalid_index(&created.name).await {
+                    tracing::warn!("Failed to drop the invalid index `{}`: {}", created.name, drop_err);
+                }
+
+                Err(err)
+            }
+            (result, _) => result,
+        }
+    }
+
+    /// Drop the index if it exists and is marked invalid. A valid index with the same name, e.g.
+    /// the one that made the creation fail, is left alone.
+    async fn drop_invalid_index(&self, index_name: &str) -> SqlResult<()> {
+        let schema_name = self.schema_name();
+        let rows = self
+            .conn()
+            .query_raw(
+                "SELECT 1 FROM pg_index JOIN pg_class ON pg_class.oid = pg_index.indexrelid \
+                 JOIN pg_namespace ON pg_namespace.oid = pg_class.relnamespace \
+                 WHERE pg_namespace.nspname = $1 AND pg_class.relname = $2 AND NOT pg_index.indisvalid",
+                &[schema_name.into(), index_name.into()],
+            )
+            .await?;
+
+        if rows.into_iter().next().is_none() {
+            return Ok(());
+        }
+
+        let drop_index = format!(
+            "DROP INDEX IF EXISTS {}",
+            self.flavour().quote_with_schema(schema_name, index_name)
+        );
+        tracing::debug!(%drop_index);
+
+        self.conn().raw_cmd(&drop_index).await?;
+
+        Ok(())
+    }
+
+    /// SQLite migrations turn the `foreign_keys` pragma off before redefining tables, but the pragma
+    /// is a no-op inside a transaction. The leading and trailing pragma statements are executed
+    /// around an explicit transaction instead, and migrations toggling it anywhere else are applied
+    /// step by step.
+    async fn apply_sqlite_statements_in_transaction(
+        &self,
+        mut statements: Vec<String>,
+        steps_count: usize,
+    ) -> SqlResult<Option<usize>> {
+        let leading_pragmas = statements
+            .iter()
+            .take_while(|statement| is_foreign_keys_pragma(statement))
+            .count();
+        let before: Vec<String> = statements.drain(..leading_pragmas).collect();
+
+        let trailing_pragmas = statements
+            .iter()
+            .rev()
+            .take_while(|statement| is_foreign_keys_pragma(statement))
+            .count();
+        let after = statements.split_off(statements.len() - trailing_pragmas);
+
+        if statements.is_empty() || statements.iter().any(|statement| is_foreign_keys_pragma(statement)) {
+            return Ok(None);
+        }
+
+        // The statements were already intercepted, they are executed as they are.
+        for pragma in &before {
+            self.conn().raw_cmd(pragma).await?;
+        }
+
+        let script = format!("BEGIN;\n{};\nCOMMIT;", join_statements(&statements));
+        tracing::debug!(%script);
+
+        // The script stops at the first failing statement, with the transaction still open.
+        let result = match self.conn().raw_cmd(&script).await {
+            Ok(()) => Ok(Some(steps_count)),
+            Err(err) => {
+                self.conn().raw_cmd("ROLLBACK").await.ok();
+                Err(err.into())
+            }
+        };
+
+        for pragma in &after {
+            self.conn().raw_cmd(pragma).await?;
+        }
+
+        result
+    }
+
     async fn apply_next_step(
         &self,
         steps: &[SqlMigrationStep],
@@ -78,19 +369,51 @@ impl SqlDatabaseStepApplier<'_> {
         let step = &steps[index];
         tracing::debug!(?step);
 
-        for sql_string in render_raw_sql(&step, renderer, self.database_info(), current_schema, next_schema)
-            .map_err(SqlError::Generic)?
-        {
-            tracing::debug!(index, %sql_string);
+        let statements = render_raw_sql(&step, renderer, self.database_info(), current_schema, next_schema)
+            .map_err(SqlError::Generic)?;
+        let has_more = steps.get(index + 1).is_some();
+        let (disable, enable) = self.flavour().foreign_key_checks_toggle();
+        let toggle_foreign_key_checks = self.toggles_foreign_key_checks_between_steps();
 
-            self.conn().raw_cmd(&sql_string).await?;
+        if toggle_foreign_key_checks && !self.foreign_key_checks_turned_off.load(Ordering::SeqCst) {
+            self.execute_statements(index, vec![disable.to_owned()]).await?;
+            self.foreign_key_checks_turned_off.store(true, Ordering::SeqCst);
+        }
+
+        let result = self.execute_create_index_statements(index, step, statements).await;
+
+        if toggle_foreign_key_checks && (result.is_err() || !has_more) {
+            // The checks are turned back on before the error of a failing step is returned, so that
+            // they don't stay off for the rest of the session.
+            let enabled = self.execute_statements(index, vec![enable.to_owned()]).await;
+            self.foreign_key_checks_turned_off.store(false, Ordering::SeqCst);
+
+            result?;
+            enabled?;
+        } else {
+            result?;
         }
 
-        let has_more = steps.get(index + 1).is_some();
         Ok(has_more)
     }
 }
 
+/// Join statements into a single script. Some renderers already end their statements with a
+/// semicolon, which would otherwise be doubled.
+fn join_statements(statements: &[String]) -> String {
+    statements
+        .iter()
+        .map(|statement| statement.trim_end().trim_end_matches(';'))
+        .join(";\n")
+}
+
+fn is_foreign_keys_pragma(statement: &str) -> bool {
+    let statement = statement.trim().trim_end_matches(';');
+
+    statement.eq_ignore_ascii_case("PRAGMA foreign_keys=OFF")
+        || statement.eq_ignore_ascii_case("PRAGMA foreign_keys=ON")
+}
+
 fn render_steps_pretty(
     database_migration: &SqlMigration,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -116,6 +439,216 @@ fn render_steps_pretty(
     Ok(steps)
 }
 
+/// Render the whole migration as a single SQL script. When a migration name is given, the
+/// script starts with a `-- Migration: <name>` comment.
+pub(crate) fn render_steps_to_sql(
+    database_migration: &SqlMigration,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
+    migration_name: Option<&str>,
+) -> ConnectorResult<String> {
+    let mut script = String::new();
+
+    if let Some(migration_name) = migration_name {
+        script.push_str(&renderer.render_comment(&format!("Migration: {}", migration_name)));
+        script.push('\n');
+    }
+
+    let steps = render_steps_pretty(
+        database_migration,
+        renderer,
+        database_info,
+        &database_migration.before,
+        &database_migration.after,
+    )?;
+
+    for step in steps {
+        script.push_str(&step.raw);
+        script.push_str(";\n");
+    }
+
+    Ok(script)
+}
+
+/// Render the steps of the migration one by one, pairing each step with its statements.
+pub(crate) fn render_steps_detailed(
+    database_migration: &SqlMigration,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
+) -> ConnectorResult<Vec<(SqlMigrationStep, Vec<String>)>> {
+    database_migration
+        .corrected_steps
+        .iter()
+        .map(|step| {
+            let statements = render_raw_sql(
+                step,
+                renderer,
+                database_info,
+                &database_migration.before,
+                &database_migration.after,
+            )
+            .map_err(|err: anyhow::Error| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err)))?;
+
+            Ok((step.clone(), statements))
+        })
+        .collect()
+}
+
+/// Render the statements undoing `steps`, or `None` if any of the steps is not reversible. See
+/// `SqlMigrationStep::reverse()`.
+pub(crate) fn render_rollback_script(
+    steps: &[SqlMigrationStep],
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
+) -> ConnectorResult<Option<Vec<String>>> {
+    let rollback_steps = match SqlMigrationStep::reverse_all(steps) {
+        Some(rollback_steps) => rollback_steps,
+        None => return Ok(None),
+    };
+
+    // The reversed steps only drop tables, columns and indexes, so they do not need the schemas.
+    let schema = SqlSchema::empty();
+    let mut statements = Vec::with_capacity(rollback_steps.len());
+
+    for step in &rollback_steps {
+        let rendered = render_raw_sql(step, renderer, database_info, &schema, &schema)
+            .map_err(|err: anyhow::Error| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err)))?;
+
+        statements.extend(rendered);
+    }
+
+    Ok(Some(statements))
+}
+
+/// Render the statements renaming the column `from` of `table_name` to `to`, keeping its data.
+/// Servers without `RENAME COLUMN` get the full column definition (MySQL 5) or a rebuilt table
+/// (SQLite before 3.25). On SQLite, the tables with foreign keys referencing the column are rebuilt
+/// too. The statements are meant to run in a transaction, with foreign keys turned off on SQLite.
+pub(crate) fn render_rename_column(
+    table_name: &str,
+    from: &str,
+    to: &str,
+    schema: &SqlSchema,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
+) -> anyhow::Result<Vec<String>> {
+    let schema_name = database_info.connection_info().schema_name();
+    let table = schema
+        .get_table(table_name)
+        .ok_or_else(|| anyhow::anyhow!("The table `{}` does not exist.", table_name))?;
+
+    if table.column(from).is_none() {
+        anyhow::bail!("The table `{}` has no column named `{}`.", table_name, from);
+    }
+
+    let table_reference = renderer.quote_with_schema(schema_name, table_name).to_string();
+
+    if database_info.supports_rename_column() {
+        return Ok(vec![format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            table_reference,
+            renderer.quote(from),
+            renderer.quote(to)
+        )]);
+    }
+
+    match renderer.sql_family() {
+        SqlFamily::Mysql => {
+            let mut next_table = table.clone();
+            rename_column_in_table(&mut next_table, from, to);
+
+            let column = ColumnRef {
+                schema,
+                column: next_table.column_bang(to),
+                table: &next_table,
+            };
+
+            Ok(vec![format!(
+                "ALTER TABLE {} CHANGE {} {}",
+                table_reference,
+                renderer.quote(from),
+                renderer.render_column(schema_name, column, false)
+            )])
+        }
+        SqlFamily::Sqlite => {
+            let references_column = |foreign_key: &ForeignKey| {
+                foreign_key.referenced_table == table_name && foreign_key.referenced_columns.iter().any(|c| c == from)
+            };
+            let mut next_schema = schema.clone();
+
+            for next_table in next_schema.tables.iter_mut() {
+                if next_table.name == table_name {
+                    rename_column_in_table(next_table, from, to);
+                }
+
+                for foreign_key in next_table.foreign_keys.iter_mut().filter(|fk| references_column(fk)) {
+                    rename_column_in_list(&mut foreign_key.referenced_columns, from, to);
+                }
+            }
+
+            // Foreign keys are part of the table definition on SQLite, so the tables referencing the
+            // column have to be rebuilt as well.
+            let referencing_tables = schema
+                .tables
+                .iter()
+                .filter(|other| other.name != table_name && other.foreign_keys.iter().any(references_column));
+            let mut steps = sql_database_migration_inferrer::sqlite::fix_table(
+                TableRef::new(schema, table),
+                next_schema.table_ref(table_name).unwrap(),
+                &[(from, to)],
+                schema_name,
+                database_info,
+                renderer,
+            );
+
+            for referencing_table in referencing_tables {
+                steps.extend(sql_database_migration_inferrer::sqlite::fix_table(
+                    TableRef::new(schema, referencing_table),
+                    next_schema.table_ref(&referencing_table.name).unwrap(),
+                    &[],
+                    schema_name,
+                    database_info,
+                    renderer,
+                ));
+            }
+
+            let mut statements = Vec::with_capacity(steps.len());
+
+            for step in &steps {
+                statements.extend(render_raw_sql(step, renderer, database_info, schema, &next_schema)?);
+            }
+
+            Ok(statements)
+        }
+        sql_family => anyhow::bail!("Renaming columns is not supported on {}.", sql_family.as_str()),
+    }
+}
+
+/// Rename the column everywhere it is referenced in the table.
+fn rename_column_in_table(table: &mut Table, from: &str, to: &str) {
+    for column in table.columns.iter_mut().filter(|column| column.name == from) {
+        column.name = to.to_owned();
+    }
+
+    for index in table.indices.iter_mut() {
+        rename_column_in_list(&mut index.columns, from, to);
+    }
+
+    if let Some(primary_key) = &mut table.primary_key {
+        rename_column_in_list(&mut primary_key.columns, from, to);
+    }
+
+    for foreign_key in table.foreign_keys.iter_mut() {
+        rename_column_in_list(&mut foreign_key.columns, from, to);
+    }
+}
+
+fn rename_column_in_list(columns: &mut [String], from: &str, to: &str) {
+    for column in columns.iter_mut().filter(|column| column.as_str() == from) {
+        *column = to.to_owned();
+    }
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -127,11 +660,11 @@ fn render_raw_sql(
     let schema_name = database_info.connection_info().schema_name().to_string();
 
     match step {
-        SqlMigrationStep::CreateEnum(create_enum) => render_create_enum(renderer, create_enum),
+        SqlMigrationStep::CreateEnum(create_enum) => render_create_enum(renderer, database_info, create_enum),
         SqlMigrationStep::DropEnum(drop_enum) => render_drop_enum(renderer, drop_enum),
         SqlMigrationStep::AlterEnum(alter_enum) => match renderer.sql_family() {
-            SqlFamily::Postgres => postgres_alter_enum(alter_enum, next_schema, &schema_name)?.into(),
-            SqlFamily::Mysql => mysql_alter_enum(alter_enum, next_schema, &schema_name),
+            SqlFamily::Postgres => postgres_alter_enum(renderer, alter_enum, next_schema, &schema_name)?.into(),
+            SqlFamily::Mysql => mysql_alter_enum(renderer, alter_enum, next_schema, &schema_name),
             _ => Ok(Vec::new()),
         },
         SqlMigrationStep::CreateTable(CreateTable { table }) => {
@@ -148,8 +681,14 @@ fn render_raw_sql(
                 })
                 .join(",\n");
 
+            let if_not_exists = if database_info.idempotent_ddl() {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            };
             let mut create_table = format!(
-                "CREATE TABLE {} (\n{}",
+                "CREATE TABLE {}{} (\n{}",
+                if_not_exists,
                 renderer.quote_with_schema(&schema_name, &table.name),
                 columns,
             );
@@ -159,7 +698,17 @@ fn render_raw_sql(
 
             if !primary_columns.is_empty() && !primary_key_is_already_set {
                 let column_names = primary_columns.iter().map(|col| renderer.quote(&col)).join(",");
-                write!(create_table, ",\n    PRIMARY KEY ({})", column_names)?;
+                let constraint = match (sql_family, table.primary_key.as_ref()) {
+                    (
+                        SqlFamily::Postgres,
+                        Some(PrimaryKey {
+                            constraint_name: Some(constraint_name),
+                            ..
+                        }),
+                    ) => format!("CONSTRAINT {} ", renderer.quote(constraint_name)),
+                    _ => String::new(),
+                };
+                write!(create_table, ",\n    {}PRIMARY KEY ({})", constraint, column_names)?;
             }
 
             if sql_family == SqlFamily::Sqlite && !table.foreign_keys.is_empty() {
@@ -171,7 +720,7 @@ fn render_raw_sql(
                     write!(
                         create_table,
                         "FOREIGN KEY ({constrained_columns}) {references}{comma}",
-                        constrained_columns = fk.columns.iter().map(|col| format!(r#""{}""#, col)).join(","),
+                        constrained_columns = fk.columns.iter().map(|col| renderer.quote(col)).join(","),
                         references = renderer.render_references(&schema_name, fk),
                         comma = if fks.peek().is_some() { ",\n" } else { "" },
                     )?;
@@ -180,7 +729,33 @@ fn render_raw_sql(
 
             create_table.push_str(create_table_suffix(sql_family));
 
-            Ok(vec![create_table])
+            if sql_family == SqlFamily::Postgres && !table.storage_parameters.is_empty() {
+                write!(
+                    create_table,
+                    " WITH ({})",
+                    render_storage_parameters(&table.storage_parameters)
+                )?;
+            }
+
+            if let (SqlFamily::Mysql, Some(engine)) = (sql_family, &table.engine) {
+                write!(create_table, " ENGINE={}", engine)?;
+            }
+
+            match (sql_family, &table.description) {
+                (SqlFamily::Mysql, Some(comment)) => {
+                    write!(create_table, " COMMENT={}", render_comment(comment, sql_family))?;
+                    Ok(vec![create_table])
+                }
+                (SqlFamily::Postgres, Some(comment)) => Ok(vec![
+                    create_table,
+                    format!(
+                        "COMMENT ON TABLE {} IS {};",
+                        renderer.quote_with_schema(&schema_name, &table.name),
+                        render_comment(comment, sql_family)
+                    ),
+                ]),
+                _ => Ok(vec![create_table]),
+            }
         }
         SqlMigrationStep::DropTable(DropTable { name }) => match sql_family {
             SqlFamily::Mysql | SqlFamily::Postgres => Ok(vec![format!(
@@ -198,9 +773,10 @@ fn render_raw_sql(
             ]),
             SqlFamily::Mssql => todo!("Greetings from Redmond"),
         },
-        SqlMigrationStep::RenameTable { name, new_name } => {
+        SqlMigrationStep::RenameTable(RenameTable { name, new_name }) => {
+            // Postgres and SQLite keep renamed tables in their schema and reject a qualified new name.
             let new_name = match sql_family {
-                SqlFamily::Sqlite => renderer.quote(new_name).to_string(),
+                SqlFamily::Sqlite | SqlFamily::Postgres => renderer.quote(new_name).to_string(),
                 _ => renderer.quote_with_schema(&schema_name, &new_name).to_string(),
             };
             Ok(vec![format!(
@@ -220,8 +796,16 @@ fn render_raw_sql(
                     table = renderer.quote_with_schema(&schema_name, table)
                 )?;
 
-                if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
-                    write!(add_constraint, "CONSTRAINT {} ", renderer.quote(constraint_name))?;
+                let idempotent = database_info.idempotent_ddl() && sql_family == SqlFamily::Postgres;
+                // Postgres picks a fresh name for an unnamed constraint instead of failing when it
+                // already exists, so the name it would pick the first time is rendered explicitly.
+                let constraint_name = match foreign_key.constraint_name.as_ref() {
+                    None if idempotent => Some(format!("{}_{}_fkey", table, foreign_key.columns.join("_"))),
+                    constraint_name => constraint_name.cloned(),
+                };
+
+                if let Some(constraint_name) = constraint_name {
+                    write!(add_constraint, "CONSTRAINT {} ", renderer.quote(&constraint_name))?;
                 }
 
                 write!(
@@ -232,19 +816,23 @@ fn render_raw_sql(
 
                 add_constraint.push_str(&renderer.render_references(&schema_name, &foreign_key));
 
-                Ok(vec![add_constraint])
+                if idempotent {
+                    Ok(vec![ignore_duplicate_object(&add_constraint)])
+                } else {
+                    Ok(vec![add_constraint])
+                }
             }
         },
         SqlMigrationStep::DropForeignKey(DropForeignKey { table, constraint_name }) => match sql_family {
             SqlFamily::Mysql => Ok(vec![format!(
                 "ALTER TABLE {table} DROP FOREIGN KEY {constraint_name}",
                 table = renderer.quote_with_schema(&schema_name, table),
-                constraint_name = Quoted::mysql_ident(constraint_name),
+                constraint_name = renderer.quote(constraint_name),
             )]),
             SqlFamily::Postgres => Ok(vec![format!(
                 "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
                 table = renderer.quote_with_schema(&schema_name, table),
-                constraint_name = Quoted::postgres_ident(constraint_name),
+                constraint_name = renderer.quote(constraint_name),
             )]),
             SqlFamily::Sqlite => Ok(Vec::new()),
             SqlFamily::Mssql => todo!("Greetings from Redmond"),
@@ -252,13 +840,15 @@ fn render_raw_sql(
 
         SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
             let mut lines = Vec::new();
+            // Postgres comments are set with a separate statement.
+            let mut comment_statements = Vec::new();
             for change in changes {
                 match change {
                     TableChange::DropPrimaryKey { constraint_name } => match renderer.sql_family() {
                         SqlFamily::Mysql => lines.push("DROP PRIMARY KEY".to_owned()),
                         SqlFamily::Postgres => lines.push(format!(
                             "DROP CONSTRAINT {}",
-                            Quoted::postgres_ident(
+                            renderer.quote(
                                 constraint_name
                                     .as_ref()
                                     .expect("Missing constraint name for DROP CONSTRAINT on Postgres.")
@@ -266,10 +856,23 @@ fn render_raw_sql(
                         )),
                         _ => (),
                     },
-                    TableChange::AddPrimaryKey { columns } => lines.push(format!(
-                        "ADD PRIMARY KEY ({})",
-                        columns.iter().map(|colname| renderer.quote(colname)).join(", ")
-                    )),
+                    TableChange::AddPrimaryKey {
+                        columns,
+                        constraint_name,
+                    } => {
+                        let constraint = match (renderer.sql_family(), constraint_name) {
+                            (SqlFamily::Postgres, Some(constraint_name)) => {
+                                format!("CONSTRAINT {} ", renderer.quote(constraint_name))
+                            }
+                            _ => String::new(),
+                        };
+
+                        lines.push(format!(
+                            "ADD {}PRIMARY KEY ({})",
+                            constraint,
+                            columns.iter().map(|colname| renderer.quote(colname)).join(", ")
+                        ))
+                    }
                     TableChange::AddColumn(AddColumn { column }) => {
                         let column = ColumnRef {
                             table,
@@ -277,12 +880,45 @@ fn render_raw_sql(
                             column,
                         };
                         let col_sql = renderer.render_column(&schema_name, column, true);
-                        lines.push(format!("ADD COLUMN {}", col_sql));
+
+                        if database_info.idempotent_ddl() && sql_family == SqlFamily::Postgres {
+                            lines.push(format!("ADD COLUMN IF NOT EXISTS {}", col_sql));
+                        } else {
+                            lines.push(format!("ADD COLUMN {}", col_sql));
+                        }
                     }
                     TableChange::DropColumn(DropColumn { name }) => {
                         let name = renderer.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
                     }
+                    TableChange::ConvertCharacterSet(ConvertCharacterSet {
+                        character_set,
+                        collation,
+                    }) => {
+                        if sql_family == SqlFamily::Mysql {
+                            let mut line = format!("CONVERT TO CHARACTER SET {}", character_set);
+
+                            if let Some(collation) = collation {
+                                write!(line, " COLLATE {}", collation)?;
+                            }
+
+                            lines.push(line);
+                        }
+                    }
+                    TableChange::SetComment(SetComment { comment }) => match sql_family {
+                        SqlFamily::Mysql => lines.push(format!("COMMENT={}", render_comment(comment, sql_family))),
+                        SqlFamily::Postgres => comment_statements.push(format!(
+                            "COMMENT ON TABLE {} IS {};",
+                            renderer.quote_with_schema(&schema_name, &table.name),
+                            render_comment(comment, sql_family)
+                        )),
+                        _ => (),
+                    },
+                    TableChange::SetStorageParameters(SetStorageParameters { parameters }) => {
+                        if sql_family == SqlFamily::Postgres {
+                            lines.push(format!("SET ({})", render_storage_parameters(parameters)));
+                        }
+                    }
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
                         match safe_alter_column(
                             renderer,
@@ -313,14 +949,16 @@ fn render_raw_sql(
             }
 
             if lines.is_empty() {
-                return Ok(Vec::new());
+                return Ok(comment_statements);
             }
 
-            Ok(vec![format!(
+            let alter_table = format!(
                 "ALTER TABLE {} {};",
                 renderer.quote_with_schema(&schema_name, &table.name),
                 lines.join(",\n")
-            )])
+            );
+
+            Ok(std::iter::once(alter_table).chain(comment_statements).collect())
         }
         SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
             Ok(vec![render_create_index(renderer, database_info, table, index)])
@@ -331,7 +969,29 @@ fn render_raw_sql(
                 renderer.quote(&name),
                 renderer.quote_with_schema(&schema_name, &table),
             )]),
-            SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
+            SqlFamily::Postgres => {
+                let backs_constraint = current_schema
+                    .table(table)
+                    .ok()
+                    .and_then(|table| table.indices.iter().find(|index| &index.name == name))
+                    .map(|index| index.is_constraint)
+                    .unwrap_or(false);
+
+                // Postgres refuses to drop an index that a constraint depends on.
+                if backs_constraint {
+                    Ok(vec![format!(
+                        "ALTER TABLE {} DROP CONSTRAINT {}",
+                        renderer.quote_with_schema(&schema_name, &table),
+                        renderer.quote(&name),
+                    )])
+                } else {
+                    Ok(vec![format!(
+                        "DROP INDEX {}",
+                        renderer.quote_with_schema(&schema_name, &name)
+                    )])
+                }
+            }
+            SqlFamily::Sqlite => Ok(vec![format!(
                 "DROP INDEX {}",
                 renderer.quote_with_schema(&schema_name, &name)
             )]),
@@ -398,10 +1058,20 @@ fn render_create_index(
     table_name: &str,
     index: &Index,
 ) -> String {
-    let Index { name, columns, tpe } = index;
+    let Index {
+        name,
+        columns,
+        tpe,
+        predicate,
+        storage_parameters,
+        deferrable,
+        ..
+    } = index;
     let index_type = match tpe {
         IndexType::Unique => "UNIQUE",
         IndexType::Normal => "",
+        IndexType::Fulltext => "FULLTEXT",
+        IndexType::Spatial => "SPATIAL",
     };
     let sql_family = database_info.sql_family();
     let index_name = match sql_family {
@@ -417,13 +1087,58 @@ fn render_create_index(
             .to_string(),
     };
     let columns = columns.iter().map(|c| renderer.quote(c));
+    let concurrently = if database_info.concurrent_index_creation() {
+        "CONCURRENTLY "
+    } else {
+        ""
+    };
+    // MySQL has no `CREATE INDEX IF NOT EXISTS`.
+    let if_not_exists = match sql_family {
+        SqlFamily::Postgres | SqlFamily::Sqlite if database_info.idempotent_ddl() => "IF NOT EXISTS ",
+        _ => "",
+    };
+    let with_clause = match sql_family {
+        SqlFamily::Postgres if !storage_parameters.is_empty() => {
+            format!(" WITH ({})", render_storage_parameters(storage_parameters))
+        }
+        _ => String::new(),
+    };
+
+    // Only constraints can be deferred, not indexes.
+    if let (SqlFamily::Postgres, Some(deferrable)) = (sql_family, deferrable) {
+        let initially = match deferrable {
+            Deferrable::InitiallyImmediate => "IMMEDIATE",
+            Deferrable::InitiallyDeferred => "DEFERRED",
+        };
+
+        return format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} UNIQUE ({}){} DEFERRABLE INITIALLY {}",
+            table_reference,
+            index_name,
+            columns.join(","),
+            with_clause,
+            initially
+        );
+    }
+
+    // Partial indexes on MySQL are rejected by `SqlFlavour::check_indexes` beforehand.
+    let where_clause = match (sql_family, predicate) {
+        (SqlFamily::Postgres, Some(predicate)) | (SqlFamily::Sqlite, Some(predicate)) => {
+            format!(" WHERE {}", predicate)
+        }
+        _ => String::new(),
+    };
 
     format!(
-        "CREATE {} INDEX {} ON {}({})",
+        "CREATE {} INDEX {}{}{} ON {}({}){}{}",
         index_type,
+        concurrently,
+        if_not_exists,
         index_name,
         table_reference,
-        columns.join(",")
+        columns.join(","),
+        with_clause,
+        where_clause
     )
 }
 
@@ -449,6 +1164,25 @@ fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
     }
 }
 
+/// Render storage parameters as the contents of a Postgres `WITH (...)` or `SET (...)` clause.
+fn render_storage_parameters(parameters: &[(String, String)]) -> String {
+    parameters
+        .iter()
+        .map(|(name, value)| format!("{} = {}", name, value))
+        .join(", ")
+}
+
+/// Render a table comment as a string literal. MySQL treats backslashes in string literals as
+/// escape characters.
+fn render_comment(comment: &str, sql_family: SqlFamily) -> Quoted<String> {
+    let comment = match sql_family {
+        SqlFamily::Mysql => comment.replace('\\', "\\\\"),
+        _ => comment.to_owned(),
+    };
+
+    Quoted::Single(comment.replace('\'', "''"))
+}
+
 fn safe_alter_column(
     renderer: &dyn SqlFlavour,
     previous_column: ColumnRef<'_>,
@@ -512,7 +1246,7 @@ fn safe_alter_column(
 
                 vec![format!(
                     "MODIFY {column_name} {column_type} {nullability} {default}",
-                    column_name = Quoted::mysql_ident(&next_column.name()),
+                    column_name = renderer.quote(&next_column.name()),
                     column_type = column_type,
                     nullability = if next_column.arity().is_required() {
                         "NOT NULL "
@@ -532,31 +1266,43 @@ fn safe_alter_column(
 
 fn render_create_enum(
     renderer: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
     create_enum: &CreateEnum,
 ) -> Result<Vec<String>, anyhow::Error> {
     match renderer.sql_family() {
         SqlFamily::Postgres => {
             let sql = format!(
                 r#"CREATE TYPE {enum_name} AS ENUM ({variants});"#,
-                enum_name = Quoted::postgres_ident(&create_enum.name),
+                enum_name = renderer.quote(&create_enum.name),
                 variants = create_enum.variants.iter().map(Quoted::postgres_string).join(", "),
             );
-            Ok(vec![sql])
+
+            if database_info.idempotent_ddl() {
+                Ok(vec![ignore_duplicate_object(&sql)])
+            } else {
+                Ok(vec![sql])
+            }
         }
         _ => Ok(Vec::new()),
     }
 }
 
+/// Wrap a Postgres statement in a block that ignores the error raised when the object it creates
+/// already exists. `CREATE TYPE` and `ADD CONSTRAINT` have no `IF NOT EXISTS`.
+fn ignore_duplicate_object(statement: &str) -> String {
+    format!(
+        "DO $$ BEGIN\n    {};\nEXCEPTION WHEN duplicate_object THEN NULL;\nEND $$;",
+        statement.trim_end().trim_end_matches(';')
+    )
+}
+
 fn render_drop_enum(
     renderer: &(dyn SqlFlavour + Send + Sync),
     drop_enum: &DropEnum,
 ) -> Result<Vec<String>, anyhow::Error> {
     match renderer.sql_family() {
         SqlFamily::Postgres => {
-            let sql = format!(
-                "DROP TYPE {enum_name}",
-                enum_name = Quoted::postgres_ident(&drop_enum.name),
-            );
+            let sql = format!("DROP TYPE {enum_name}", enum_name = renderer.quote(&drop_enum.name));
 
             Ok(vec![sql])
         }
@@ -565,6 +1311,7 @@ fn render_drop_enum(
 }
 
 fn postgres_alter_enum(
+    renderer: &dyn SqlFlavour,
     alter_enum: &AlterEnum,
     next_schema: &SqlSchema,
     schema_name: &str,
@@ -576,7 +1323,7 @@ fn postgres_alter_enum(
             .map(|created_value| {
                 format!(
                     "ALTER TYPE {enum_name} ADD VALUE {value}",
-                    enum_name = Quoted::postgres_ident(&alter_enum.name),
+                    enum_name = renderer.quote(&alter_enum.name),
                     value = Quoted::postgres_string(created_value)
                 )
             })
@@ -597,7 +1344,7 @@ fn postgres_alter_enum(
         {
             let create_new_enum = format!(
                 "CREATE TYPE {enum_name} AS ENUM ({variants})",
-                enum_name = Quoted::postgres_ident(&tmp_name),
+                enum_name = renderer.quote(&tmp_name),
                 variants = new_enum.values.iter().map(Quoted::postgres_string).join(", ")
             );
 
@@ -613,15 +1360,14 @@ fn postgres_alter_enum(
 
             for column in affected_columns {
                 let sql = format!(
-                    "ALTER TABLE {schema_name}.{table_name} \
+                    "ALTER TABLE {table_name} \
                         ALTER COLUMN {column_name} DROP DEFAULT,
                         ALTER COLUMN {column_name} TYPE {tmp_name} \
                             USING ({column_name}::text::{tmp_name}),
                         ALTER COLUMN {column_name} SET DEFAULT {new_enum_default}",
-                    schema_name = Quoted::postgres_ident(schema_name),
-                    table_name = Quoted::postgres_ident(column.table().name()),
-                    column_name = Quoted::postgres_ident(column.name()),
-                    tmp_name = Quoted::postgres_ident(&tmp_name),
+                    table_name = renderer.quote_with_schema(schema_name, column.table().name()),
+                    column_name = renderer.quote(column.name()),
+                    tmp_name = renderer.quote(&tmp_name),
                     new_enum_default = Quoted::postgres_string(new_enum.values.first().unwrap()),
                 );
 
@@ -633,8 +1379,8 @@ fn postgres_alter_enum(
         {
             let sql = format!(
                 "ALTER TYPE {enum_name} RENAME TO {tmp_old_name}",
-                enum_name = Quoted::postgres_ident(&alter_enum.name),
-                tmp_old_name = Quoted::postgres_ident(&tmp_old_name)
+                enum_name = renderer.quote(&alter_enum.name),
+                tmp_old_name = renderer.quote(&tmp_old_name)
             );
 
             stmts.push(sql);
@@ -644,8 +1390,8 @@ fn postgres_alter_enum(
         {
             let sql = format!(
                 "ALTER TYPE {tmp_name} RENAME TO {enum_name}",
-                tmp_name = Quoted::postgres_ident(&tmp_name),
-                enum_name = Quoted::postgres_ident(&new_enum.name)
+                tmp_name = renderer.quote(&tmp_name),
+                enum_name = renderer.quote(&new_enum.name)
             );
 
             stmts.push(sql)
@@ -653,10 +1399,7 @@ fn postgres_alter_enum(
 
         // drop old enum
         {
-            let sql = format!(
-                "DROP TYPE {tmp_old_name}",
-                tmp_old_name = Quoted::postgres_ident(&tmp_old_name),
-            );
+            let sql = format!("DROP TYPE {tmp_old_name}", tmp_old_name = renderer.quote(&tmp_old_name));
 
             stmts.push(sql)
         }
@@ -665,7 +1408,12 @@ fn postgres_alter_enum(
     }
 }
 
-fn mysql_alter_enum(alter_enum: &AlterEnum, next_schema: &SqlSchema, schema_name: &str) -> anyhow::Result<Vec<String>> {
+fn mysql_alter_enum(
+    renderer: &dyn SqlFlavour,
+    alter_enum: &AlterEnum,
+    next_schema: &SqlSchema,
+    schema_name: &str,
+) -> anyhow::Result<Vec<String>> {
     let column = sql_schema_helpers::walk_columns(next_schema)
         .find(|col| match &col.column_type().family {
             ColumnTypeFamily::Enum(enum_name) if enum_name.as_str() == alter_enum.name.as_str() => true,
@@ -681,12 +1429,214 @@ fn mysql_alter_enum(alter_enum: &AlterEnum, next_schema: &SqlSchema, schema_name
         .join(", ");
 
     let change_column = format!(
-        "ALTER TABLE {schema_name}.{table_name} CHANGE {column_name} {column_name} ENUM({enum_variants})",
-        schema_name = Quoted::mysql_ident(schema_name),
-        table_name = Quoted::mysql_ident(column.table().name()),
-        column_name = column.name(),
+        "ALTER TABLE {table_name} CHANGE {column_name} {column_name} ENUM({enum_variants})",
+        table_name = renderer.quote_with_schema(schema_name, column.table().name()),
+        column_name = renderer.quote(column.name()),
         enum_variants = enum_variants,
     );
 
     Ok(vec![change_column])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql_renderer::IdentifierQuoting;
+
+    fn column(name: &str) -> Column {
+        Column {
+            name: name.to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+            default: None,
+            auto_increment: false,
+        }
+    }
+
+    fn table(name: &str, columns: &[&str]) -> Table {
+        Table {
+            name: name.to_owned(),
+            columns: columns.iter().map(|name| column(name)).collect(),
+            indices: Vec::new(),
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_owned()],
+                sequence: None,
+                constraint_name: None,
+                autoincrement: false,
+            }),
+            foreign_keys: Vec::new(),
+            engine: None,
+            default_character_set: None,
+            default_collation: None,
+            description: None,
+            storage_parameters: Vec::new(),
+        }
+    }
+
+    fn cats_and_owners() -> SqlSchema {
+        let mut cat = table("Cat", &["id", "name"]);
+        cat.indices.push(Index {
+            name: "Cat.name".to_owned(),
+            columns: vec!["name".to_owned()],
+            tpe: IndexType::Unique,
+            is_constraint: false,
+            predicate: None,
+            storage_parameters: Vec::new(),
+            deferrable: None,
+        });
+
+        let mut owner = table("Owner", &["id", "catName"]);
+        owner.foreign_keys.push(ForeignKey {
+            constraint_name: None,
+            columns: vec!["catName".to_owned()],
+            referenced_table: "Cat".to_owned(),
+            referenced_schema: None,
+            referenced_columns: vec!["name".to_owned()],
+            on_delete_action: ForeignKeyAction::Restrict,
+        });
+
+        SqlSchema {
+            tables: vec![cat, owner],
+            ..SqlSchema::empty()
+        }
+    }
+
+    fn render_rename_cat_name(url: &str, database_version: &str) -> Vec<String> {
+        let connection_info = ConnectionInfo::from_url(url).unwrap();
+        let database_info = DatabaseInfo::from_version(connection_info.clone(), Some(database_version.to_owned()));
+        let flavour = crate::flavour::from_connection_info(&connection_info, IdentifierQuoting::Always);
+
+        assert!(!database_info.supports_rename_column());
+
+        render_rename_column(
+            "Cat",
+            "name",
+            "fullName",
+            &cats_and_owners(),
+            flavour.as_ref(),
+            &database_info,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn columns_named_after_reserved_words_are_quoted_when_quoting_only_when_necessary() {
+        for (url, quoted_order, quoted_id) in &[
+            ("mysql://localhost:3306/prisma", "`order`", "`id`"),
+            ("file:dev.db", r#""order""#, r#""id""#),
+        ] {
+            let connection_info = ConnectionInfo::from_url(url).unwrap();
+            let database_info = DatabaseInfo::from_version(connection_info.clone(), None);
+            let flavour = crate::flavour::from_connection_info(&connection_info, IdentifierQuoting::WhenNecessary);
+            let schema = SqlSchema {
+                tables: vec![table("Cat", &["id", "order"])],
+                ..SqlSchema::empty()
+            };
+            let step = SqlMigrationStep::CreateTable(CreateTable {
+                table: schema.tables[0].clone(),
+            });
+
+            let statements =
+                render_raw_sql(&step, flavour.as_ref(), &database_info, &SqlSchema::empty(), &schema).unwrap();
+
+            assert!(statements[0].contains(quoted_order), "{}", statements[0]);
+            assert!(!statements[0].contains(quoted_id), "{}", statements[0]);
+        }
+    }
+
+    #[test]
+    fn changing_a_char_column_to_varchar_sets_the_data_type_on_postgres() {
+        let connection_info = ConnectionInfo::from_url("postgresql://localhost:5432/prisma").unwrap();
+        let database_info = DatabaseInfo::from_version(connection_info.clone(), None);
+        let flavour = crate::flavour::from_connection_info(&connection_info, IdentifierQuoting::Always);
+        let schema_with_name_type = |data_type: &str, full_data_type: &str| {
+            let mut cat = table("Cat", &["id", "name"]);
+            cat.columns[1].tpe = ColumnType {
+                data_type: data_type.to_owned(),
+                full_data_type: full_data_type.to_owned(),
+                character_maximum_length: Some(10),
+                ..ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required)
+            };
+
+            SqlSchema {
+                tables: vec![cat],
+                ..SqlSchema::empty()
+            }
+        };
+        let previous = schema_with_name_type("character", "bpchar");
+        let next = schema_with_name_type("character varying", "varchar");
+        let step = SqlMigrationStep::AlterTable(AlterTable {
+            table: next.tables[0].clone(),
+            changes: vec![TableChange::AlterColumn(AlterColumn {
+                name: "name".to_owned(),
+                column: next.tables[0].columns[1].clone(),
+            })],
+        });
+
+        let statements = render_raw_sql(&step, flavour.as_ref(), &database_info, &previous, &next).unwrap();
+
+        assert_eq!(statements.len(), 1);
+        assert!(
+            statements[0].contains(r#"ALTER COLUMN "name" SET DATA TYPE varchar(10)"#),
+            "{}",
+            statements[0]
+        );
+        assert!(!statements[0].contains("DROP COLUMN"), "{}", statements[0]);
+    }
+
+    #[test]
+    fn array_columns_with_required_elements_are_created_with_a_check_on_postgres() {
+        let connection_info = ConnectionInfo::from_url("postgresql://localhost:5432/prisma").unwrap();
+        let database_info = DatabaseInfo::from_version(connection_info.clone(), None);
+        let flavour = crate::flavour::from_connection_info(&connection_info, IdentifierQuoting::Always);
+        let mut cat = table("Cat", &["id", "favouriteNumbers", "luckyNumbers"]);
+        cat.columns[1].tpe = ColumnType {
+            array_elements_required: true,
+            ..ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List)
+        };
+        cat.columns[2].tpe = ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List);
+        let schema = SqlSchema {
+            tables: vec![cat],
+            ..SqlSchema::empty()
+        };
+        let step = SqlMigrationStep::CreateTable(CreateTable {
+            table: schema.tables[0].clone(),
+        });
+
+        let statements = render_raw_sql(&step, flavour.as_ref(), &database_info, &SqlSchema::empty(), &schema).unwrap();
+
+        assert_eq!(statements.len(), 1);
+        assert!(
+            statements[0].contains(r#"CHECK (array_position("favouriteNumbers", NULL) IS NULL)"#),
+            "{}",
+            statements[0]
+        );
+        assert!(
+            !statements[0].contains(r#"array_position("luckyNumbers""#),
+            "{}",
+            statements[0]
+        );
+    }
+
+    #[test]
+    fn renaming_a_column_without_rename_column_uses_change_on_mysql() {
+        let statements = render_rename_cat_name("mysql://localhost:3306/prisma", "5.7.31");
+
+        assert_eq!(statements.len(), 1);
+        assert!(
+            statements[0].starts_with("ALTER TABLE `prisma`.`Cat` CHANGE `name` `fullName` varchar(191)"),
+            "{}",
+            statements[0]
+        );
+    }
+
+    #[test]
+    fn renaming_a_column_without_rename_column_rebuilds_the_table_and_its_references_on_sqlite() {
+        let statements = render_rename_cat_name("file:dev.db", "3.22.0");
+        let contains = |needle: &str| statements.iter().any(|statement| statement.contains(needle));
+
+        assert!(contains(r#"("id", "fullName") SELECT "id", "name" FROM"#));
+        assert!(contains(r#"CREATE UNIQUE INDEX "#) && contains(r#" ON "Cat"("fullName")"#));
+        assert!(contains(r#"REFERENCES "Cat"("fullName")"#));
+        assert!(contains(r#"("id", "catName") SELECT "id", "catName" FROM"#));
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
 };
 use destructive_check_plan::DestructiveCheckPlan;
 use migration_connector::{ConnectorResult, DestructiveChangeDiagnostics, DestructiveChangesChecker};
-use sql_schema_describer::SqlSchema;
+use sql_schema_describer::{DefaultValue, SqlSchema};
 use unexecutable_step_check::UnexecutableStepCheck;
 use warning_check::SqlMigrationWarningCheck;
 
@@ -63,15 +63,17 @@ impl SqlDestructiveChangesChecker<'_> {
     ///
     /// - There are existing rows
     /// - The new column is required
-    /// - There is no default value for the new column
+    /// - There is no default value we can render for the new column, and it is not autoincrementing
     fn check_add_column(
         &self,
         add_column: &AddColumn,
         table: &sql_schema_describer::Table,
         plan: &mut DestructiveCheckPlan,
     ) {
-        let column_is_required_without_default =
-            add_column.column.tpe.arity.is_required() && add_column.column.default.is_none();
+        let column = &add_column.column;
+        let column_is_required_without_default = column.tpe.arity.is_required()
+            && !column.auto_increment
+            && !default_can_be_rendered(column.default.as_ref());
 
         // Optional columns and columns with a default can safely be added.
         if !column_is_required_without_default {
@@ -180,12 +182,21 @@ impl SqlDestructiveChangesChecker<'_> {
             }
         }
 
-        let mut diagnostics = plan.execute(self.schema_name(), self.conn()).await?;
-
-        // Temporary, for better reporting.
-        diagnostics.warn_about_unexecutable_migrations();
+        plan.execute(self.schema_name(), self.conn()).await
+    }
+}
 
-        Ok(diagnostics)
+/// Whether the default will be part of the rendered column definition, and can thus fill in
+/// existing rows.
+fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
+    match default {
+        None => false,
+        Some(DefaultValue::VALUE(_)) => true,
+        Some(DefaultValue::LIST(_)) => true,
+        Some(DefaultValue::DBGENERATED(expr)) => !expr.is_empty(),
+        Some(DefaultValue::NOW) => true,
+        Some(DefaultValue::SEQUENCE(_)) => false,
+        Some(DefaultValue::IDENTITY(_)) => true,
     }
 }
 
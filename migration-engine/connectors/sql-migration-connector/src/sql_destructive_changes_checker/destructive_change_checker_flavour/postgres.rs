@@ -3,12 +3,12 @@ use crate::{
     expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
     flavour::PostgresFlavour,
     sql_destructive_changes_checker::{
-        destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
-        warning_check::SqlMigrationWarningCheck,
+        default_can_be_rendered, destructive_check_plan::DestructiveCheckPlan,
+        unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
     },
     sql_schema_differ::ColumnDiffer,
 };
-use sql_schema_describer::{ColumnArity, DefaultValue, Table};
+use sql_schema_describer::{ColumnArity, Table};
 
 impl DestructiveChangeCheckerFlavour for PostgresFlavour {
     fn check_alter_column(&self, previous_table: &Table, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan) {
@@ -65,13 +65,3 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
         }
     }
 }
-
-fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
-    match default {
-        None => false,
-        Some(DefaultValue::VALUE(_)) => true,
-        Some(DefaultValue::DBGENERATED(expr)) => !expr.is_empty(),
-        Some(DefaultValue::NOW) => true,
-        Some(DefaultValue::SEQUENCE(_)) => false,
-    }
-}
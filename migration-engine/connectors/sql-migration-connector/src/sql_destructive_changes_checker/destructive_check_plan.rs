@@ -35,6 +35,17 @@ impl DestructiveCheckPlan {
         self.unexecutable_migrations.push(unexecutable_migration)
     }
 
+    /// The checks for steps that may cause data loss. A step is either a warning or unexecutable,
+    /// never both.
+    pub(super) fn warnings(&self) -> &[SqlMigrationWarningCheck] {
+        &self.warnings
+    }
+
+    /// The checks for steps that cannot be executed at all, depending on the data in the database.
+    pub(super) fn unexecutable_steps(&self) -> &[UnexecutableStepCheck] {
+        &self.unexecutable_migrations
+    }
+
     /// Inspect the current database state to qualify and render destructive change warnings and
     /// errors.
     ///
@@ -48,12 +59,12 @@ impl DestructiveCheckPlan {
         let mut results = DatabaseInspectionResults::default();
 
         let inspection = async {
-            for unexecutable in &self.unexecutable_migrations {
+            for unexecutable in self.unexecutable_steps() {
                 self.inspect_for_check(unexecutable, &mut results, schema_name, conn)
                     .await?;
             }
 
-            for warning in &self.warnings {
+            for warning in self.warnings() {
                 self.inspect_for_check(warning, &mut results, schema_name, conn).await?;
             }
 
@@ -68,7 +79,7 @@ impl DestructiveCheckPlan {
 
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
-        for unexecutable in &self.unexecutable_migrations {
+        for unexecutable in self.unexecutable_steps() {
             if let Some(message) = unexecutable.evaluate(&results) {
                 diagnostics
                     .unexecutable_migrations
@@ -76,7 +87,7 @@ impl DestructiveCheckPlan {
             }
         }
 
-        for warning in &self.warnings {
+        for warning in self.warnings() {
             if let Some(message) = warning.evaluate(&results) {
                 diagnostics.warnings.push(MigrationWarning { description: message })
             }
@@ -41,7 +41,7 @@ pub enum SqlMigrationStep {
     AlterTable(AlterTable),
     DropForeignKey(DropForeignKey),
     DropTable(DropTable),
-    RenameTable { name: String, new_name: String },
+    RenameTable(RenameTable),
     RawSql { raw: String },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
@@ -51,6 +51,64 @@ pub enum SqlMigrationStep {
     AlterEnum(AlterEnum),
 }
 
+impl SqlMigrationStep {
+    /// Whether the step can be automatically undone, see `reverse()`.
+    pub fn is_reversible(&self) -> bool {
+        self.reverse().is_some()
+    }
+
+    /// The step undoing this one, for the steps that are trivially reversible: creating tables, adding columns and
+    /// creating indexes. Other steps, notably destructive ones, lose the information required to undo them.
+    pub fn reverse(&self) -> Option<SqlMigrationStep> {
+        match self {
+            SqlMigrationStep::CreateTable(CreateTable { table }) => Some(SqlMigrationStep::DropTable(DropTable {
+                name: table.name.clone(),
+            })),
+            SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+                let added_columns: Option<Vec<&str>> = changes
+                    .iter()
+                    .map(|change| match change {
+                        TableChange::AddColumn(AddColumn { column }) => Some(column.name.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                let added_columns = added_columns?;
+
+                let mut previous_table = table.clone();
+                previous_table
+                    .columns
+                    .retain(|column| !added_columns.contains(&column.name.as_str()));
+
+                Some(SqlMigrationStep::AlterTable(AlterTable {
+                    table: previous_table,
+                    changes: added_columns
+                        .iter()
+                        .rev()
+                        .map(|name| {
+                            TableChange::DropColumn(DropColumn {
+                                name: (*name).to_owned(),
+                            })
+                        })
+                        .collect(),
+                }))
+            }
+            SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
+                Some(SqlMigrationStep::DropIndex(DropIndex {
+                    table: table.clone(),
+                    name: index.name.clone(),
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    /// The steps undoing the given steps, in the order they should be applied. Returns `None` if any of the steps is
+    /// not reversible.
+    pub fn reverse_all(steps: &[SqlMigrationStep]) -> Option<Vec<SqlMigrationStep>> {
+        steps.iter().rev().map(SqlMigrationStep::reverse).collect()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateTable {
     pub table: Table,
@@ -61,6 +119,12 @@ pub struct DropTable {
     pub name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameTable {
+    pub name: String,
+    pub new_name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterTable {
     pub table: Table,
@@ -72,8 +136,16 @@ pub enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
     DropColumn(DropColumn),
-    DropPrimaryKey { constraint_name: Option<String> },
-    AddPrimaryKey { columns: Vec<String> },
+    DropPrimaryKey {
+        constraint_name: Option<String>,
+    },
+    AddPrimaryKey {
+        columns: Vec<String>,
+        constraint_name: Option<String>,
+    },
+    ConvertCharacterSet(ConvertCharacterSet),
+    SetComment(SetComment),
+    SetStorageParameters(SetStorageParameters),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -92,6 +164,25 @@ pub struct AlterColumn {
     pub column: Column,
 }
 
+/// Change the default character set of a table and convert its existing columns (MySQL).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ConvertCharacterSet {
+    pub character_set: String,
+    pub collation: Option<String>,
+}
+
+/// Set the comment of a table (MySQL and Postgres).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SetComment {
+    pub comment: String,
+}
+
+/// Set the storage parameters of a table, like `fillfactor` (Postgres).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SetStorageParameters {
+    pub parameters: Vec<(String, String)>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AddForeignKey {
     pub table: String,
@@ -126,6 +217,7 @@ pub struct AlterIndex {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateEnum {
     pub name: String,
+    /// The database values of the variants, i.e. their `@map`ped names when they have one.
     pub variants: Vec<String>,
 }
 
@@ -137,7 +229,9 @@ pub struct DropEnum {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterEnum {
     pub name: String,
+    /// The database values of the created variants, like in `CreateEnum`.
     pub created_variants: Vec<String>,
+    /// The database values of the dropped variants, like in `CreateEnum`.
     pub dropped_variants: Vec<String>,
 }
 
@@ -146,3 +240,53 @@ impl AlterEnum {
         self.created_variants.is_empty() && self.dropped_variants.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_create_table_step_reverses_to_a_drop_table_step() {
+        let create_table = SqlMigrationStep::CreateTable(CreateTable {
+            table: Table {
+                name: "Cat".to_owned(),
+                columns: Vec::new(),
+                indices: Vec::new(),
+                primary_key: None,
+                foreign_keys: Vec::new(),
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                description: None,
+                storage_parameters: Vec::new(),
+            },
+        });
+
+        assert!(create_table.is_reversible());
+        assert_eq!(
+            SqlMigrationStep::reverse_all(&[create_table]),
+            Some(vec![SqlMigrationStep::DropTable(DropTable { name: "Cat".to_owned() })])
+        );
+    }
+
+    #[test]
+    fn a_migration_with_a_drop_table_step_is_not_reversible() {
+        let steps = vec![
+            SqlMigrationStep::CreateIndex(CreateIndex {
+                table: "Cat".to_owned(),
+                index: Index {
+                    name: "Cat_name_idx".to_owned(),
+                    columns: vec!["name".to_owned()],
+                    tpe: sql_schema_describer::IndexType::Normal,
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
+                },
+            }),
+            SqlMigrationStep::DropTable(DropTable { name: "Dog".to_owned() }),
+        ];
+
+        assert_eq!(SqlMigrationStep::reverse_all(&steps), None);
+    }
+}
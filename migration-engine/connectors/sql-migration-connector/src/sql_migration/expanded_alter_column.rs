@@ -78,7 +78,12 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer<'_>) -> Option
                 &columns.next.column_type_family(),
             ) {
                 // Ints can be cast to text.
-                (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
+                (ColumnTypeFamily::Int, ColumnTypeFamily::String)
+                | (ColumnTypeFamily::BigInt, ColumnTypeFamily::String)
+                // Ints can be widened without loss.
+                | (ColumnTypeFamily::Int, ColumnTypeFamily::BigInt)
+                // Changes between CHAR and VARCHAR keep the data, only the padding changes.
+                | (ColumnTypeFamily::String, ColumnTypeFamily::String) => {
                     changes.push(PostgresAlterColumn::SetType(columns.next.column_type().clone()))
                 }
                 _ => return None,
@@ -164,6 +164,26 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
     }
 }
 
+impl SqlMigrationPersistence<'_> {
+    /// The names of the successfully applied migrations, in the order they were applied.
+    pub(crate) async fn applied_migration_names(&self) -> ConnectorResult<Vec<String>> {
+        crate::catch(self.connection_info(), async {
+            let query = Select::from_table(self.table())
+                .column(NAME_COLUMN)
+                .so_that(STATUS_COLUMN.equals(MigrationStatus::MigrationSuccess.code()))
+                .order_by(REVISION_COLUMN.ascend());
+
+            let result_set = self.conn().query(query.into()).await?;
+
+            Ok(result_set
+                .into_iter()
+                .map(|row| row.get(NAME_COLUMN).and_then(|name| name.to_string()).unwrap())
+                .collect())
+        })
+        .await
+    }
+}
+
 /// Returns the last 2 applied migrations, or a shorter vec in absence of applied migrations.
 async fn last_applied_migrations(
     conn: &dyn Queryable,
@@ -5,7 +5,7 @@ mod mysql_renderer;
 mod postgres_renderer;
 mod sqlite_renderer;
 
-pub(crate) use common::{IteratorJoin, Quoted, QuotedWithSchema};
+pub(crate) use common::{quote_ident, IteratorJoin, Quoted, QuotedWithSchema};
 pub(crate) use mysql_renderer::render_column_type as mysql_render_column_type;
 pub(crate) use postgres_renderer::render_column_type as postgres_render_column_type;
 
@@ -13,12 +13,21 @@ use crate::sql_schema_helpers::ColumnRef;
 use sql_schema_describer::*;
 use std::borrow::Cow;
 
+/// How the renderers quote table and column identifiers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum IdentifierQuoting {
+    /// Quote every identifier.
+    Always,
+    /// Only quote reserved words and identifiers that would not be valid unquoted.
+    WhenNecessary,
+}
+
 pub(crate) trait SqlRenderer {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
     fn quote_with_schema<'a, 'b>(&'a self, schema_name: &'a str, name: &'b str) -> QuotedWithSchema<'a, &'b str> {
         QuotedWithSchema {
-            schema_name,
+            schema_name: self.quote(schema_name),
             name: self.quote(name),
         }
     }
@@ -28,4 +37,10 @@ pub(crate) trait SqlRenderer {
     fn render_references(&self, schema_name: &str, foreign_key: &ForeignKey) -> String;
 
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str>;
+
+    /// Render a (possibly multi-line) SQL comment. The `-- ` syntax is understood by all the
+    /// flavours we support, MySQL requiring the trailing space.
+    fn render_comment(&self, comment: &str) -> String {
+        comment.lines().map(|line| format!("-- {}", line)).join("\n")
+    }
 }
@@ -1,4 +1,5 @@
 use crate::sql_schema_helpers::*;
+use quaint::prelude::SqlFamily;
 use sql_schema_describer::*;
 use std::fmt::{Display, Write as _};
 
@@ -7,17 +8,11 @@ pub(crate) enum Quoted<T> {
     Double(T),
     Single(T),
     Backticks(T),
+    /// An identifier that is valid without quotes.
+    Bare(T),
 }
 
 impl<T> Quoted<T> {
-    fn quote_that_too<U>(&self, u: U) -> Quoted<U> {
-        match self {
-            Quoted::Double(_) => Quoted::Double(u),
-            Quoted::Single(_) => Quoted::Single(u),
-            Quoted::Backticks(_) => Quoted::Backticks(u),
-        }
-    }
-
     pub(crate) fn mysql_string(contents: T) -> Quoted<T> {
         Quoted::Single(contents)
     }
@@ -45,16 +40,54 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Quoted::Double(inner) => write!(f, "\"{}\"", inner),
+            // Embedded quote characters in identifiers are escaped by doubling them.
+            Quoted::Double(inner) => write!(f, "\"{}\"", inner.to_string().replace('"', "\"\"")),
             Quoted::Single(inner) => write!(f, "'{}'", inner),
-            Quoted::Backticks(inner) => write!(f, "`{}`", inner),
+            Quoted::Backticks(inner) => write!(f, "`{}`", inner.to_string().replace('`', "``")),
+            Quoted::Bare(inner) => write!(f, "{}", inner),
         }
     }
 }
 
+/// Quote an identifier the way the given SQL family expects it. All identifiers emitted by the
+/// renderers should go through this function.
+pub(crate) fn quote_ident<T>(sql_family: SqlFamily, name: T) -> Quoted<T> {
+    match sql_family {
+        SqlFamily::Mysql => Quoted::mysql_ident(name),
+        SqlFamily::Postgres => Quoted::postgres_ident(name),
+        SqlFamily::Sqlite => Quoted::sqlite_ident(name),
+        SqlFamily::Mssql => todo!("Greetings from Redmond"),
+    }
+}
+
+/// Quote an identifier only if it is a reserved word of the SQL family, or if it would not be a
+/// valid identifier unquoted. Postgres folds unquoted identifiers to lowercase, so identifiers with
+/// uppercase characters are quoted there too.
+pub(crate) fn quote_ident_when_necessary<'a>(
+    sql_family: SqlFamily,
+    name: &'a str,
+    reserved_words: &[&str],
+) -> Quoted<&'a str> {
+    let starts_like_an_identifier = name
+        .chars()
+        .next()
+        .map(|first_char| first_char.is_ascii_alphabetic() || first_char == '_')
+        .unwrap_or(false);
+    let is_plain_identifier = starts_like_an_identifier
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !(matches!(sql_family, SqlFamily::Postgres) && name.chars().any(|c| c.is_ascii_uppercase()));
+    let is_reserved = reserved_words.iter().any(|word| word.eq_ignore_ascii_case(name));
+
+    if is_plain_identifier && !is_reserved {
+        Quoted::Bare(name)
+    } else {
+        quote_ident(sql_family, name)
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct QuotedWithSchema<'a, T> {
-    pub(crate) schema_name: &'a str,
+    pub(crate) schema_name: Quoted<&'a str>,
     pub(crate) name: Quoted<T>,
 }
 
@@ -63,9 +96,7 @@ where
     T: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let quoted_schema = self.name.quote_that_too(self.schema_name);
-
-        write!(f, "{}.{}", quoted_schema, self.name)
+        write!(f, "{}.{}", self.schema_name, self.name)
     }
 }
 
@@ -112,3 +143,78 @@ where
         out
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote_ident_escapes_double_quotes_on_postgres() {
+        let quoted = QuotedWithSchema {
+            schema_name: quote_ident(SqlFamily::Postgres, "public"),
+            name: quote_ident(SqlFamily::Postgres, "weird\"name"),
+        };
+
+        assert_eq!(quoted.to_string(), r#""public"."weird""name""#);
+    }
+
+    #[test]
+    fn quote_ident_escapes_backticks_on_mysql() {
+        let quoted = QuotedWithSchema {
+            schema_name: quote_ident(SqlFamily::Mysql, "prisma"),
+            name: quote_ident(SqlFamily::Mysql, "weird`name"),
+        };
+
+        assert_eq!(quoted.to_string(), "`prisma`.`weird``name`");
+    }
+
+    #[test]
+    fn quote_ident_leaves_regular_identifiers_untouched() {
+        assert_eq!(quote_ident(SqlFamily::Sqlite, "Cat").to_string(), r#""Cat""#);
+        assert_eq!(quote_ident(SqlFamily::Mysql, "Cat").to_string(), "`Cat`");
+    }
+
+    #[test]
+    fn quote_ident_when_necessary_only_quotes_reserved_words_on_mysql() {
+        let reserved_words = crate::sql_renderer::mysql_renderer::RESERVED_WORDS;
+
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Mysql, "order", reserved_words).to_string(),
+            "`order`"
+        );
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Mysql, "ORDER", reserved_words).to_string(),
+            "`ORDER`"
+        );
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Mysql, "customer_name", reserved_words).to_string(),
+            "customer_name"
+        );
+    }
+
+    #[test]
+    fn quote_ident_when_necessary_quotes_identifiers_that_are_not_valid_unquoted() {
+        let reserved_words = crate::sql_renderer::postgres_renderer::RESERVED_WORDS;
+
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Postgres, "Cat", reserved_words).to_string(),
+            r#""Cat""#
+        );
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Postgres, "cat food", reserved_words).to_string(),
+            r#""cat food""#
+        );
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Postgres, "1cat", reserved_words).to_string(),
+            r#""1cat""#
+        );
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Postgres, "user", reserved_words).to_string(),
+            r#""user""#
+        );
+        assert_eq!(
+            quote_ident_when_necessary(SqlFamily::Postgres, "cat", reserved_words).to_string(),
+            "cat"
+        );
+    }
+}
@@ -1,16 +1,50 @@
-use super::{common::*, SqlRenderer};
+use super::{common::*, IdentifierQuoting, SqlRenderer};
 use crate::{flavour::MysqlFlavour, sql_schema_helpers::ColumnRef};
-use once_cell::sync::Lazy;
-use prisma_models::PrismaValue;
-use regex::Regex;
+use quaint::prelude::SqlFamily;
 use sql_schema_describer::*;
 use std::borrow::Cow;
 
 const VARCHAR_LENGTH_PREFIX: &str = "(191)";
 
+/// The reserved words of MySQL 8.0, which must be quoted when used as table or column names. See
+/// https://dev.mysql.com/doc/refman/8.0/en/keywords.html.
+#[rustfmt::skip]
+pub(crate) const RESERVED_WORDS: &[&str] = &[
+    "accessible", "add", "all", "alter", "analyze", "and", "as", "asc", "asensitive", "before", "between", "bigint",
+    "binary", "blob", "both", "by", "call", "cascade", "case", "change", "char", "character", "check", "collate",
+    "column", "condition", "constraint", "continue", "convert", "create", "cross", "cube", "cume_dist", "current_date",
+    "current_time", "current_timestamp", "current_user", "cursor", "database", "databases", "day_hour",
+    "day_microsecond", "day_minute", "day_second", "dec", "decimal", "declare", "default", "delayed", "delete",
+    "dense_rank", "desc", "describe", "deterministic", "distinct", "distinctrow", "div", "double", "drop", "dual",
+    "each", "else", "elseif", "empty", "enclosed", "escaped", "except", "exists", "exit", "explain", "false", "fetch",
+    "first_value", "float", "float4", "float8", "for", "force", "foreign", "from", "fulltext", "function", "generated",
+    "get", "grant", "group", "grouping", "groups", "having", "high_priority", "hour_microsecond", "hour_minute",
+    "hour_second", "if", "ignore", "in", "index", "infile", "inner", "inout", "insensitive", "insert", "int", "int1",
+    "int2", "int3", "int4", "int8", "integer", "intersect", "interval", "into", "io_after_gtids", "io_before_gtids",
+    "is", "iterate", "join", "json_table", "key", "keys", "kill", "lag", "last_value", "lateral", "lead", "leading",
+    "leave", "left", "like", "limit", "linear", "lines", "load", "localtime", "localtimestamp", "lock", "long",
+    "longblob", "longtext", "loop", "low_priority", "master_bind", "master_ssl_verify_server_cert", "match", "maxvalue",
+    "mediumblob", "mediumint", "mediumtext", "middleint", "minute_microsecond", "minute_second", "mod", "modifies",
+    "natural", "not", "no_write_to_binlog", "nth_value", "ntile", "null", "numeric", "of", "on", "optimize",
+    "optimizer_costs", "option", "optionally", "or", "order", "out", "outer", "outfile", "over", "partition",
+    "percent_rank", "precision", "primary", "procedure", "purge", "range", "rank", "read", "reads", "read_write",
+    "real", "recursive", "references", "regexp", "release", "rename", "repeat", "replace", "require", "resignal",
+    "restrict", "return", "revoke", "right", "rlike", "row", "rows", "row_number", "schema", "schemas",
+    "second_microsecond", "select", "sensitive", "separator", "set", "show", "signal", "smallint", "spatial",
+    "specific", "sql", "sqlexception", "sqlstate", "sqlwarning", "sql_big_result", "sql_calc_found_rows",
+    "sql_small_result", "ssl", "starting", "stored", "straight_join", "system", "table", "terminated", "then",
+    "tinyblob", "tinyint", "tinytext", "to", "trailing", "trigger", "true", "undo", "union", "unique", "unlock",
+    "unsigned", "update", "usage", "use", "using", "utc_date", "utc_time", "utc_timestamp", "values", "varbinary",
+    "varchar", "varcharacter", "varying", "virtual", "when", "where", "while", "window", "with", "write", "xor",
+    "year_month", "zerofill"
+];
+
 impl SqlRenderer for MysqlFlavour {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
-        Quoted::Backticks(name)
+        match self.identifier_quoting {
+            IdentifierQuoting::Always => quote_ident(SqlFamily::Mysql, name),
+            IdentifierQuoting::WhenNecessary => quote_ident_when_necessary(SqlFamily::Mysql, name, RESERVED_WORDS),
+        }
     }
 
     fn render_column(&self, _schema_name: &str, column: ColumnRef<'_>, _add_fk_prefix: bool) -> String {
@@ -20,7 +54,7 @@ impl SqlRenderer for MysqlFlavour {
         let default_str = column
             .default()
             .filter(|default| {
-                !matches!(default, DefaultValue::DBGENERATED(_))
+                !matches!(default, DefaultValue::DBGENERATED(expr) if expr.is_empty())
                     // We do not want to render JSON defaults because they are not supported by MySQL.
                     && !matches!(column.column_type_family(), ColumnTypeFamily::Json)
             })
@@ -46,27 +80,18 @@ impl SqlRenderer for MysqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES `{}`.`{}`({}) {} ON UPDATE CASCADE",
-            schema_name,
-            foreign_key.referenced_table,
+            " REFERENCES {}({}) {} ON UPDATE CASCADE",
+            self.quote_with_schema(schema_name, &foreign_key.referenced_table),
             referenced_columns,
             render_on_delete(&foreign_key.on_delete_action)
         )
     }
 
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
-        match (default, family) {
-            (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
-            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
-            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
-                format!("'{}'", escape_string_literal(&val)).into()
-            }
-            (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
-            (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
-            (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
-            (DefaultValue::SEQUENCE(_), _) => todo!("rendering of sequence defaults"),
-        }
+        default
+            .to_sql(SqlFamily::Mysql, family)
+            .unwrap_or_else(|| unreachable!("{:?} default on MySQL", default))
+            .into()
     }
 }
 
@@ -81,8 +106,9 @@ pub(crate) fn render_column_type(column: &ColumnRef<'_>) -> anyhow::Result<Cow<'
                 Ok("datetime(3)".into())
             }
         }
-        ColumnTypeFamily::Float => Ok("Decimal(65,30)".into()),
+        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => Ok("Decimal(65,30)".into()),
         ColumnTypeFamily::Int => Ok("int".into()),
+        ColumnTypeFamily::BigInt => Ok("bigint".into()),
         // we use varchar right now as mediumtext doesn't allow default values
         // a bigger length would not allow to use such a column as primary key
         ColumnTypeFamily::String => Ok(format!("varchar{}", VARCHAR_LENGTH_PREFIX).into()),
@@ -97,12 +123,7 @@ pub(crate) fn render_column_type(column: &ColumnRef<'_>) -> anyhow::Result<Cow<'
             Ok(format!("ENUM({})", variants).into())
         }
         ColumnTypeFamily::Json => Ok("json".into()),
+        ColumnTypeFamily::Binary => Ok("longblob".into()),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
-
-fn escape_string_literal(s: &str) -> Cow<'_, str> {
-    static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
-
-    STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "'$0")
-}
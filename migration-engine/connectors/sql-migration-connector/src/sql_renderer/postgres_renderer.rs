@@ -1,14 +1,30 @@
-use super::common::*;
+use super::{common::*, IdentifierQuoting};
 use crate::{flavour::PostgresFlavour, sql_schema_helpers::*};
-use once_cell::sync::Lazy;
-use prisma_models::PrismaValue;
-use regex::Regex;
+use quaint::prelude::SqlFamily;
 use sql_schema_describer::*;
 use std::borrow::Cow;
 
+/// Reserved words that cannot be used as unquoted table or column names in PostgreSQL.
+#[rustfmt::skip]
+pub(crate) const RESERVED_WORDS: &[&str] = &[
+    "all", "analyse", "analyze", "and", "any", "array", "as", "asc", "asymmetric", "authorization", "binary", "both",
+    "case", "cast", "check", "collate", "column", "concurrently", "constraint", "create", "cross", "current_catalog",
+    "current_date", "current_role", "current_schema", "current_time", "current_timestamp", "current_user", "default",
+    "deferrable", "desc", "distinct", "do", "else", "end", "except", "false", "fetch", "for", "foreign", "freeze",
+    "from", "full", "grant", "group", "having", "ilike", "in", "initially", "inner", "intersect", "into", "is",
+    "isnull", "join", "lateral", "leading", "left", "like", "limit", "localtime", "localtimestamp", "natural", "not",
+    "notnull", "null", "offset", "on", "only", "or", "order", "outer", "overlaps", "placing", "primary", "references",
+    "returning", "right", "select", "session_user", "similar", "some", "symmetric", "table", "tablesample", "then",
+    "to", "trailing", "true", "union", "unique", "user", "using", "variadic", "verbose", "when", "where", "window",
+    "with",
+];
+
 impl super::SqlRenderer for PostgresFlavour {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
-        Quoted::postgres_ident(name)
+        match self.identifier_quoting {
+            IdentifierQuoting::Always => quote_ident(SqlFamily::Postgres, name),
+            IdentifierQuoting::WhenNecessary => quote_ident_when_necessary(SqlFamily::Postgres, name, RESERVED_WORDS),
+        }
     }
 
     fn render_column(&self, _schema_name: &str, column: ColumnRef<'_>, _add_fk_prefix: bool) -> String {
@@ -17,15 +33,30 @@ impl super::SqlRenderer for PostgresFlavour {
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
-            .filter(|default| !matches!(default, DefaultValue::DBGENERATED(_)))
+            .filter(|default| {
+                !matches!(default, DefaultValue::DBGENERATED(expr) if expr.is_empty())
+                    && !matches!(default, DefaultValue::IDENTITY(_))
+            })
             .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
             .unwrap_or_else(String::new);
+        let array_elements_check_str = if column.column_type().array_elements_required {
+            format!(" CHECK (array_position({}, NULL) IS NULL)", column_name)
+        } else {
+            String::new()
+        };
         let is_serial = column.auto_increment();
 
-        if is_serial {
+        if let Some(DefaultValue::IDENTITY(identity)) = column.default() {
+            format!("{} {} {} {}", column_name, tpe_str, nullability_str, identity.to_sql())
+        } else if is_serial && column.column.tpe.family == ColumnTypeFamily::BigInt {
+            format!("{} BIGSERIAL", column_name)
+        } else if is_serial {
             format!("{} SERIAL", column_name)
         } else {
-            format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
+            format!(
+                "{} {} {} {}{}",
+                column_name, tpe_str, nullability_str, default_str, array_elements_check_str
+            )
         }
     }
 
@@ -33,34 +64,43 @@ impl super::SqlRenderer for PostgresFlavour {
         let referenced_columns = foreign_key
             .referenced_columns
             .iter()
-            .map(Quoted::postgres_ident)
+            .map(|col| self.quote(col))
             .join(",");
 
         format!(
             "REFERENCES {}({}) {}  ON UPDATE CASCADE",
-            self.quote_with_schema(schema_name, &foreign_key.referenced_table),
+            self.quote_with_schema(
+                foreign_key.referenced_schema.as_deref().unwrap_or(schema_name),
+                &foreign_key.referenced_table
+            ),
             referenced_columns,
             render_on_delete(&foreign_key.on_delete_action)
         )
     }
 
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
-        match (default, family) {
-            (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
-            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
-            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
-                format!("E'{}'", escape_string_literal(&val)).into()
-            }
-            (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
-            (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
-            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
-            (DefaultValue::VALUE(val), _) => val.to_string().into(),
-            (DefaultValue::SEQUENCE(_), _) => todo!("rendering of sequence defaults"),
-        }
+        default
+            .to_sql(SqlFamily::Postgres, family)
+            .unwrap_or_else(|| unreachable!("{:?} default on Postgres", default))
+            .into()
     }
 }
 
+/// The described `char(n)` or `varchar(n)` type of a string column. Columns calculated from a
+/// datamodel have no database type, they are rendered as `text`.
+fn render_character_type(t: &ColumnType) -> Option<String> {
+    let base_type = match t.full_data_type.trim_start_matches('_').to_lowercase().as_str() {
+        "bpchar" | "char" | "character" => "char",
+        "varchar" | "character varying" => "varchar",
+        _ => return None,
+    };
+
+    Some(match t.character_maximum_length {
+        Some(length) => format!("{}({})", base_type, length),
+        None => base_type.to_owned(),
+    })
+}
+
 pub(crate) fn render_column_type(t: &ColumnType) -> String {
     let array = match t.arity {
         ColumnArity::List => "[]",
@@ -69,18 +109,18 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
 
     match &t.family {
         ColumnTypeFamily::Boolean => format!("boolean {}", array),
-        ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
-        ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
+        // Keep the introspected precision, so that re-rendering an existing column does not change it.
+        ColumnTypeFamily::DateTime => format!("timestamp({}) {}", t.datetime_precision.unwrap_or(3), array),
+        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => format!("Decimal(65,30) {}", array),
         ColumnTypeFamily::Int => format!("integer {}", array),
-        ColumnTypeFamily::String => format!("text {}", array),
-        ColumnTypeFamily::Enum(name) => format!("{}{}", Quoted::postgres_ident(name), array),
+        ColumnTypeFamily::BigInt => format!("bigint {}", array),
+        ColumnTypeFamily::String => match render_character_type(t) {
+            Some(character_type) => format!("{} {}", character_type, array),
+            None => format!("text {}", array),
+        },
+        ColumnTypeFamily::Enum(name) => format!("{}{}", quote_ident(SqlFamily::Postgres, name), array),
         ColumnTypeFamily::Json => format!("jsonb {}", array),
+        ColumnTypeFamily::Binary => format!("bytea {}", array),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
-
-fn escape_string_literal(s: &str) -> Cow<'_, str> {
-    static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'|\\"#).unwrap());
-
-    STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "\\$0")
-}
@@ -1,14 +1,34 @@
-use super::{common::*, SqlRenderer};
+use super::{common::*, IdentifierQuoting, SqlRenderer};
 use crate::{flavour::SqliteFlavour, sql_schema_helpers::*};
-use once_cell::sync::Lazy;
-use prisma_models::PrismaValue;
-use regex::Regex;
+use quaint::prelude::SqlFamily;
 use sql_schema_describer::*;
 use std::borrow::Cow;
 
+/// The keywords of SQLite, which must be quoted when used as table or column names. See
+/// https://www.sqlite.org/lang_keywords.html.
+#[rustfmt::skip]
+pub(crate) const RESERVED_WORDS: &[&str] = &[
+    "abort", "action", "add", "after", "all", "alter", "always", "analyze", "and", "as", "asc", "attach",
+    "autoincrement", "before", "begin", "between", "by", "cascade", "case", "cast", "check", "collate", "column",
+    "commit", "conflict", "constraint", "create", "cross", "current", "current_date", "current_time",
+    "current_timestamp", "database", "default", "deferrable", "deferred", "delete", "desc", "detach", "distinct", "do",
+    "drop", "each", "else", "end", "escape", "except", "exclude", "exclusive", "exists", "explain", "fail", "filter",
+    "first", "following", "for", "foreign", "from", "full", "generated", "glob", "group", "groups", "having", "if",
+    "ignore", "immediate", "in", "index", "indexed", "initially", "inner", "insert", "instead", "intersect", "into",
+    "is", "isnull", "join", "key", "last", "left", "like", "limit", "match", "materialized", "natural", "no", "not",
+    "nothing", "notnull", "null", "nulls", "of", "offset", "on", "or", "order", "others", "outer", "over", "partition",
+    "plan", "pragma", "preceding", "primary", "query", "raise", "range", "recursive", "references", "regexp", "reindex",
+    "release", "rename", "replace", "restrict", "returning", "right", "rollback", "row", "rows", "savepoint", "select",
+    "set", "table", "temp", "temporary", "then", "ties", "to", "transaction", "trigger", "unbounded", "union", "unique",
+    "update", "using", "vacuum", "values", "view", "virtual", "when", "where", "window", "with", "without"
+];
+
 impl SqlRenderer for SqliteFlavour {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
-        Quoted::Double(name)
+        match self.identifier_quoting {
+            IdentifierQuoting::Always => quote_ident(SqlFamily::Sqlite, name),
+            IdentifierQuoting::WhenNecessary => quote_ident_when_necessary(SqlFamily::Sqlite, name, RESERVED_WORDS),
+        }
     }
 
     fn render_column(&self, _schema_name: &str, column: ColumnRef<'_>, _add_fk_prefix: bool) -> String {
@@ -17,13 +37,14 @@ impl SqlRenderer for SqliteFlavour {
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
-            .filter(|default| !matches!(default, DefaultValue::DBGENERATED(_)))
+            .filter(|default| !matches!(default, DefaultValue::DBGENERATED(expr) if expr.is_empty()))
             .map(|default| format!(" DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
             .unwrap_or_else(String::new);
-        let auto_increment_str = if column.auto_increment() {
-            " PRIMARY KEY AUTOINCREMENT"
-        } else {
-            ""
+        // A rowid alias only uses the `AUTOINCREMENT` keyword when it was declared with it.
+        let auto_increment_str = match column.table().primary_key() {
+            _ if !column.auto_increment() => "",
+            Some(pk) if pk.autoincrement => " PRIMARY KEY AUTOINCREMENT",
+            _ => " PRIMARY KEY",
         };
 
         format!(
@@ -40,7 +61,7 @@ impl SqlRenderer for SqliteFlavour {
         let referenced_fields = foreign_key
             .referenced_columns
             .iter()
-            .map(Quoted::sqlite_ident)
+            .map(|col| self.quote(col))
             .join(",");
 
         format!(
@@ -52,18 +73,10 @@ impl SqlRenderer for SqliteFlavour {
     }
 
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
-        match (default, family) {
-            (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
-            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
-            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
-                format!("'{}'", escape_quotes(&val)).into()
-            }
-            (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
-            (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
-            (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
-            (DefaultValue::SEQUENCE(_), _) => unreachable!("rendering of sequence defaults"),
-        }
+        default
+            .to_sql(SqlFamily::Sqlite, family)
+            .unwrap_or_else(|| unreachable!("{:?} default on SQLite", default))
+            .into()
     }
 }
 
@@ -71,15 +84,11 @@ fn render_column_type(t: &ColumnType) -> String {
     match &t.family {
         ColumnTypeFamily::Boolean => "BOOLEAN".to_string(),
         ColumnTypeFamily::DateTime => "DATE".to_string(),
-        ColumnTypeFamily::Float => "REAL".to_string(),
+        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => "REAL".to_string(),
         ColumnTypeFamily::Int => "INTEGER".to_string(),
+        ColumnTypeFamily::BigInt => "BIGINT".to_string(),
         ColumnTypeFamily::String => "TEXT".to_string(),
+        ColumnTypeFamily::Binary => "BLOB".to_string(),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
-
-fn escape_quotes(s: &str) -> Cow<'_, str> {
-    static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
-
-    STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "'$0")
-}
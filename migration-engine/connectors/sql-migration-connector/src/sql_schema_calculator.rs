@@ -45,9 +45,29 @@ impl<'a> SqlSchemaCalculator<'a> {
             tables,
             enums,
             sequences,
+            composite_types: Vec::new(),
+            views: Vec::new(),
         })
     }
 
+    fn generated_index_name(&self, name: String) -> String {
+        generated_identifier(name, self.database_info.max_identifier_length())
+    }
+
+    /// The database would truncate (Postgres) or reject (MySQL) an index name that is too long, and
+    /// a name chosen by the user is not shortened behind their back.
+    fn check_index_name(&self, model_name: &str, name: &str) -> SqlResult<()> {
+        match self.database_info.max_identifier_length() {
+            Some(max_length) if name.len() > max_length => Err(SqlError::Generic(anyhow::anyhow!(
+                "The index name `{}` of the model {} is longer than the maximum identifier length of {} bytes.",
+                name,
+                model_name,
+                max_length
+            ))),
+            _ => Ok(()),
+        }
+    }
+
     fn calculate_enums(&self) -> Vec<sql::Enum> {
         match self.database_info.sql_family() {
             SqlFamily::Postgres => self
@@ -89,7 +109,7 @@ impl<'a> SqlSchemaCalculator<'a> {
         &'iter self,
     ) -> impl Iterator<Item = SqlResult<(ModelWalker<'a>, sql::Table)>> + 'iter {
         walk_models(self.data_model).map(move |model| {
-            let columns = model
+            let columns: Vec<sql::Column> = model
                 .scalar_fields()
                 .flat_map(|f| match f.field_type() {
                     TypeWalker::Base(_) => Some(sql::Column {
@@ -117,15 +137,30 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .map(|field| field.db_name().to_owned())
                     .collect(),
                 sequence: None,
-                constraint_name: None,
-            }).filter(|pk| !pk.columns.is_empty());
+                constraint_name: model.id_database_name().map(String::from),
+                autoincrement: false,
+            }).filter(|pk| !pk.columns.is_empty())
+            .map(|mut pk| {
+                // Autoincrementing primary keys are rendered with the `AUTOINCREMENT` keyword on SQLite.
+                let is_autoincrement = columns
+                    .iter()
+                    .any(|column| column.auto_increment && pk.columns.contains(&column.name));
+
+                pk.autoincrement = is_autoincrement && self.database_info.sql_family().is_sqlite();
+
+                pk
+            });
 
             let single_field_indexes = model.scalar_fields().filter_map(|f| {
                 if f.is_unique() {
                     Some(sql::Index {
-                        name: format!("{}.{}", &model.db_name(), &f.db_name()),
+                        name: self.generated_index_name(format!("{}.{}", &model.db_name(), &f.db_name())),
                         columns: vec![f.db_name().to_owned()],
                         tpe: sql::IndexType::Unique,
+                        is_constraint: false,
+                        predicate: None,
+                        storage_parameters: Vec::new(),
+                        deferrable: None,
                     })
                 } else {
                     None
@@ -139,14 +174,21 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .map(|field_name| model.find_scalar_field(field_name).expect("Unknown field in index directive."))
                     .collect();
 
-                sql::Index {
-                    name: index_definition.name.clone().unwrap_or_else(|| {
-                        format!(
-                            "{}.{}",
-                            &model.db_name(),
-                            referenced_fields.iter().map(|field| field.db_name()).join("_")
-                        )
-                    }),
+                // Only generated names are shortened, a name chosen by the user is used as it is.
+                let name = match &index_definition.name {
+                    Some(name) => {
+                        self.check_index_name(model.name(), name)?;
+                        name.clone()
+                    }
+                    None => self.generated_index_name(format!(
+                        "{}.{}",
+                        &model.db_name(),
+                        referenced_fields.iter().map(|field| field.db_name()).join("_")
+                    )),
+                };
+
+                Ok::<_, SqlError>(sql::Index {
+                    name,
                     // The model index definition uses the model field names, but the SQL Index
                     // wants the column names.
                     columns: referenced_fields
@@ -158,15 +200,41 @@ impl<'a> SqlSchemaCalculator<'a> {
                     } else {
                         sql::IndexType::Normal
                     },
-                }
+                    is_constraint: false,
+                    predicate: None,
+                    storage_parameters: Vec::new(),
+                    deferrable: None,
+                })
             });
 
+            let mut indices: Vec<sql::Index> = Vec::new();
+
+            for index in single_field_indexes.map(Ok).chain(multiple_field_indexes) {
+                let index = index?;
+
+                // Don't add a duplicate index, creating the same index twice fails.
+                if indices.iter().any(|existing| existing.columns == index.columns && existing.tpe == index.tpe) {
+                    continue;
+                }
+
+                indices.push(index);
+            }
+
             let table = sql::Table {
                 name: model.database_name().to_owned(),
                 columns,
-                indices: single_field_indexes.chain(multiple_field_indexes).collect(),
+                indices,
                 primary_key,
                 foreign_keys: Vec::new(),
+                engine: None,
+                default_character_set: None,
+                default_collation: None,
+                // SQLite has no table comments.
+                description: model
+                    .documentation()
+                    .filter(|_| !self.database_info.sql_family().is_sqlite())
+                    .and_then(table_comment),
+                storage_parameters: Vec::new(),
             };
 
             Ok((model, table))
@@ -183,7 +251,7 @@ impl<'a> SqlSchemaCalculator<'a> {
 
             // Optional unique index for 1:1 relations.
             if relation_field.is_one_to_one() {
-                add_one_to_one_relation_unique_index(table, &fk_columns);
+                add_one_to_one_relation_unique_index(table, &fk_columns, self.database_info.max_identifier_length());
             }
 
             // Foreign key
@@ -192,6 +260,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                     constraint_name: None,
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_table_name().to_owned(),
+                    referenced_schema: None,
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
                     on_delete_action: match column_arity(relation_field.arity()) {
                         ColumnArity::Required => sql::ForeignKeyAction::Cascade,
@@ -219,6 +288,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         constraint_name: None,
                         columns: a_columns.iter().map(|col| col.name.clone()).collect(),
                         referenced_table: model_a.db_name().to_owned(),
+                        referenced_schema: None,
                         referenced_columns: first_unique_criterion(model_a)
                             .map_err(SqlError::Generic)?
                             .into_iter()
@@ -230,6 +300,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         constraint_name: None,
                         columns: b_columns.iter().map(|col| col.name.clone()).collect(),
                         referenced_table: model_b.db_name().to_owned(),
+                        referenced_schema: None,
                         referenced_columns: first_unique_criterion(model_b)
                             .map_err(SqlError::Generic)?
                             .into_iter()
@@ -244,14 +315,22 @@ impl<'a> SqlSchemaCalculator<'a> {
 
                 let indexes = vec![
                     sql::Index {
-                        name: format!("{}_AB_unique", relation.table_name()),
+                        name: self.generated_index_name(format!("{}_AB_unique", relation.table_name())),
                         columns: columns.iter().map(|col| col.name.clone()).collect(),
                         tpe: sql::IndexType::Unique,
+                        is_constraint: false,
+                        predicate: None,
+                        storage_parameters: Vec::new(),
+                        deferrable: None,
                     },
                     sql::Index {
-                        name: format!("{}_B_index", relation.table_name()),
+                        name: self.generated_index_name(format!("{}_B_index", relation.table_name())),
                         columns: b_columns.into_iter().map(|col| col.name).collect(),
                         tpe: sql::IndexType::Normal,
+                        is_constraint: false,
+                        predicate: None,
+                        storage_parameters: Vec::new(),
+                        deferrable: None,
                     },
                 ];
 
@@ -261,6 +340,11 @@ impl<'a> SqlSchemaCalculator<'a> {
                     indices: indexes,
                     primary_key: None,
                     foreign_keys,
+                    engine: None,
+                    default_character_set: None,
+                    default_collation: None,
+                    description: None,
+                    storage_parameters: Vec::new(),
                 };
                 result.push(table);
             }
@@ -295,18 +379,12 @@ fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_descr
     }
 
     let value = match &field.default_value()? {
-        datamodel::DefaultValue::Single(s) => match field.field_type() {
-            TypeWalker::Enum(inum) => {
-                let corresponding_value = inum
-                    .r#enum
-                    .values()
-                    .find(|val| val.name.as_str() == s.to_string())
-                    .expect("could not find enum value");
-
-                PrismaValue::Enum(corresponding_value.final_database_name().to_owned())
-            }
-            _ => s.clone(),
-        },
+        datamodel::DefaultValue::Single(PrismaValue::List(values)) => {
+            let values = values.iter().map(|value| default_value_for(field, value)).collect();
+
+            return Some(sql_schema_describer::DefaultValue::LIST(values));
+        }
+        datamodel::DefaultValue::Single(s) => default_value_for(field, s),
         datamodel::DefaultValue::Expression(expression) if expression.name == "now" && expression.args.is_empty() => {
             return Some(sql_schema_describer::DefaultValue::NOW)
         }
@@ -315,12 +393,34 @@ fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_descr
         {
             return Some(sql_schema_describer::DefaultValue::DBGENERATED(String::new()))
         }
+        datamodel::DefaultValue::Expression(expression) if expression.name == "dbgenerated" => {
+            return match expression.args.as_slice() {
+                [PrismaValue::String(expr)] => Some(sql_schema_describer::DefaultValue::DBGENERATED(expr.clone())),
+                _ => None,
+            }
+        }
         datamodel::DefaultValue::Expression(_) => return None,
     };
 
     Some(sql_schema_describer::DefaultValue::VALUE(value))
 }
 
+/// Enum values are stored under their database name.
+fn default_value_for(field: &ScalarFieldWalker<'_>, value: &PrismaValue) -> PrismaValue {
+    match field.field_type() {
+        TypeWalker::Enum(inum) => {
+            let corresponding_value = inum
+                .r#enum
+                .values()
+                .find(|val| val.name.as_str() == value.to_string())
+                .expect("could not find enum value");
+
+            PrismaValue::Enum(corresponding_value.final_database_name().to_owned())
+        }
+        _ => value.clone(),
+    }
+}
+
 fn enum_column_type(field: &ScalarFieldWalker<'_>, database_info: &DatabaseInfo, db_name: &str) -> sql::ColumnType {
     let arity = column_arity(field.arity());
     match database_info.sql_family() {
@@ -360,15 +460,21 @@ fn column_arity(arity: FieldArity) -> sql::ColumnArity {
 fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnArity) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity),
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::BigInt, column_arity),
         ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean, column_arity),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime, column_arity),
         ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity),
+        ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary, column_arity),
     }
 }
 
-fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[String]) {
+fn add_one_to_one_relation_unique_index(
+    table: &mut sql::Table,
+    column_names: &[String],
+    max_identifier_length: Option<usize>,
+) {
     // Don't add a duplicate index.
     if table
         .indices
@@ -380,14 +486,51 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
 
     let columns_suffix = column_names.join("_");
     let index = sql::Index {
-        name: format!("{}_{}", table.name, columns_suffix),
+        name: generated_identifier(format!("{}_{}", table.name, columns_suffix), max_identifier_length),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        is_constraint: false,
+        predicate: None,
+        storage_parameters: Vec::new(),
+        deferrable: None,
     };
 
     table.indices.push(index);
 }
 
+/// Shorten a generated identifier that is too long for the database, see `truncate_identifier`.
+fn generated_identifier(name: String, max_identifier_length: Option<usize>) -> String {
+    match max_identifier_length {
+        Some(max_length) => truncate_identifier(&name, max_length),
+        None => name,
+    }
+}
+
+/// Deterministically shorten an identifier that does not fit in `max_length` bytes, by keeping a
+/// prefix and appending a hash of the full name, so distinct long names stay distinct.
+fn truncate_identifier(name: &str, max_length: usize) -> String {
+    if name.len() <= max_length {
+        return name.to_owned();
+    }
+
+    let hash = format!("{:08x}", fnv1a_32(name.as_bytes()));
+    let mut prefix_length = max_length - hash.len() - 1;
+
+    while !name.is_char_boundary(prefix_length) {
+        prefix_length -= 1;
+    }
+
+    format!("{}_{}", &name[..prefix_length], hash)
+}
+
+/// 32-bit FNV-1a. The hash has to be stable across platforms and compiler versions, which the
+/// standard library's `DefaultHasher` does not guarantee.
+fn fnv1a_32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
 /// This should match the logic in `prisma_models::Model::primary_identifier`.
 fn first_unique_criterion(model: ModelWalker<'_>) -> anyhow::Result<Vec<ScalarFieldWalker<'_>>> {
     // First candidate: the primary key.
@@ -421,3 +564,23 @@ fn first_unique_criterion(model: ModelWalker<'_>) -> anyhow::Result<Vec<ScalarFi
 
     anyhow::bail!("Could not find the first unique criteria on model {}", model.name());
 }
+
+/// The table comment of a model's documentation. The notes introspection adds to the documentation
+/// of views, of tables with unsupported indexes and of tables without a unique identifier are not
+/// part of it.
+fn table_comment(documentation: &str) -> Option<String> {
+    let is_generated = |line: &str| {
+        line == "@view"
+            || line == "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
+            || (line.starts_with("The index ")
+                && line.ends_with("This is not supported by Prisma yet, it is represented as a normal index."))
+    };
+
+    let comment = documentation.lines().filter(|line| !is_generated(*line)).join("\n");
+
+    if comment.is_empty() {
+        None
+    } else {
+        Some(comment)
+    }
+}
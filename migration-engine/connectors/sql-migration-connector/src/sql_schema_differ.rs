@@ -1,9 +1,13 @@
 mod column;
 mod enums;
+mod identifier_folding;
 mod index;
 mod table;
+#[cfg(test)]
+mod test_helpers;
 
 pub(crate) use column::{ColumnChange, ColumnChanges, ColumnDiffer};
+pub(crate) use identifier_folding::normalize_folded_identifiers;
 pub(crate) use table::TableDiffer;
 
 use crate::*;
@@ -13,12 +17,20 @@ use regex::RegexSet;
 use sql_schema_describer::*;
 use sql_schema_helpers::ForeignKeyRef;
 use sql_schema_helpers::TableRef;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug)]
 pub(crate) struct DiffingOptions {
     is_mariadb: bool,
     sql_family: SqlFamily,
     ignore_tables: &'static RegexSet,
+    /// User-configured tables to ignore, on top of the built-in `ignore_tables`.
+    additional_ignored_tables: RegexSet,
+    /// Tables managed outside of Prisma. Unlike ignored tables, they are also never altered.
+    unmanaged_tables: HashSet<String>,
+    /// Whether a dropped table and a created table with the same columns and indexes should be
+    /// diffed as a rename of the former. Off by default: the heuristic can pair unrelated tables.
+    detect_table_renames: bool,
 }
 
 impl DiffingOptions {
@@ -26,6 +38,14 @@ impl DiffingOptions {
         self.sql_family
     }
 
+    /// Whether the table is never diffed: the migration table, and the built-in and user-configured
+    /// ignored tables. Unmanaged tables are not included.
+    pub(crate) fn table_is_ignored(&self, table_name: &str) -> bool {
+        table_name == MIGRATION_TABLE_NAME
+            || self.ignore_tables.is_match(table_name)
+            || self.additional_ignored_tables.is_match(table_name)
+    }
+
     pub(crate) fn from_database_info(database_info: &DatabaseInfo) -> Self {
         DiffingOptions {
             is_mariadb: database_info.is_mariadb(),
@@ -34,6 +54,9 @@ impl DiffingOptions {
                 _ => &EMPTY_REGEXSET,
             },
             sql_family: database_info.sql_family(),
+            additional_ignored_tables: database_info.additional_ignored_tables().clone(),
+            unmanaged_tables: database_info.unmanaged_tables().clone(),
+            detect_table_renames: database_info.table_rename_detection(),
         }
     }
 }
@@ -45,6 +68,9 @@ impl Default for DiffingOptions {
             is_mariadb: false,
             ignore_tables: &EMPTY_REGEXSET,
             sql_family: SqlFamily::Postgres,
+            additional_ignored_tables: RegexSet::empty(),
+            unmanaged_tables: HashSet::new(),
+            detect_table_renames: false,
         }
     }
 }
@@ -55,6 +81,8 @@ pub struct SqlSchemaDiffer<'a> {
     next: &'a SqlSchema,
     sql_family: SqlFamily,
     diffing_options: &'a DiffingOptions,
+    /// The (previous, next) names of the tables detected as renamed.
+    table_renames: Vec<(&'a str, &'a str)>,
 }
 
 #[derive(Debug, Clone)]
@@ -62,6 +90,7 @@ pub struct SqlSchemaDiff {
     pub add_foreign_keys: Vec<AddForeignKey>,
     pub drop_foreign_keys: Vec<DropForeignKey>,
     pub drop_tables: Vec<DropTable>,
+    pub rename_tables: Vec<RenameTable>,
     pub create_tables: Vec<CreateTable>,
     pub alter_tables: Vec<AlterTable>,
     pub create_indexes: Vec<CreateIndex>,
@@ -80,6 +109,7 @@ impl SqlSchemaDiff {
             .chain(wrap_as_step(self.alter_enums, SqlMigrationStep::AlterEnum))
             .chain(wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex))
             .chain(wrap_as_step(self.drop_foreign_keys, SqlMigrationStep::DropForeignKey))
+            .chain(wrap_as_step(self.rename_tables, SqlMigrationStep::RenameTable))
             .chain(wrap_as_step(self.create_tables, SqlMigrationStep::CreateTable))
             .chain(wrap_as_step(self.alter_tables, SqlMigrationStep::AlterTable))
             // Order matters: we must create indexes after ALTER TABLEs because the indexes can be
@@ -93,6 +123,138 @@ impl SqlSchemaDiff {
             .chain(wrap_as_step(self.alter_indexes, SqlMigrationStep::AlterIndex))
             .collect()
     }
+
+    /// Detect the groups of created tables whose foreign keys reference each other in a cycle.
+    /// The table names in each group are sorted. Self-referencing tables are not reported, since
+    /// they can be created with a single CREATE TABLE.
+    pub fn foreign_key_cycles(&self) -> Vec<Vec<String>> {
+        let references: HashMap<&str, Vec<&str>> = self
+            .create_tables
+            .iter()
+            .map(|create_table| {
+                let table = &create_table.table;
+                let referenced_tables = table
+                    .foreign_keys
+                    .iter()
+                    .map(|fk| fk.referenced_table.as_str())
+                    .filter(|referenced_table| *referenced_table != table.name)
+                    .collect();
+
+                (table.name.as_str(), referenced_tables)
+            })
+            .collect();
+
+        let reachable: HashMap<&str, HashSet<&str>> = references
+            .keys()
+            .map(|table_name| (*table_name, reachable_tables(table_name, &references)))
+            .collect();
+
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut cycles = Vec::new();
+
+        for create_table in &self.create_tables {
+            let table_name = create_table.table.name.as_str();
+
+            if visited.contains(table_name) || !reachable[table_name].contains(table_name) {
+                continue;
+            }
+
+            let mut cycle: Vec<&str> = reachable[table_name]
+                .iter()
+                .copied()
+                .filter(|other| reachable[other].contains(table_name))
+                .collect();
+
+            cycle.sort();
+            visited.extend(cycle.iter().copied());
+            cycles.push(cycle.into_iter().map(String::from).collect());
+        }
+
+        cycles
+    }
+
+    /// The names of the indexes and foreign keys created and dropped by the diff, for display. The
+    /// indexes of created tables are included, since they are created by separate steps.
+    pub fn detailed_summary(&self) -> DetailedDiffSummary {
+        let created_indexes = self
+            .create_indexes
+            .iter()
+            .map(|create_index| SummaryEntry::new(&create_index.table, Some(&create_index.index.name)))
+            .collect();
+
+        let dropped_indexes = self
+            .drop_indexes
+            .iter()
+            .map(|drop_index| SummaryEntry::new(&drop_index.table, Some(&drop_index.name)))
+            .collect();
+
+        let created_foreign_keys = self
+            .add_foreign_keys
+            .iter()
+            .map(|add_foreign_key| {
+                SummaryEntry::new(
+                    &add_foreign_key.table,
+                    add_foreign_key.foreign_key.constraint_name.as_deref(),
+                )
+            })
+            .collect();
+
+        let dropped_foreign_keys = self
+            .drop_foreign_keys
+            .iter()
+            .map(|drop_foreign_key| SummaryEntry::new(&drop_foreign_key.table, Some(&drop_foreign_key.constraint_name)))
+            .collect();
+
+        DetailedDiffSummary {
+            created_indexes,
+            dropped_indexes,
+            created_foreign_keys,
+            dropped_foreign_keys,
+        }
+    }
+}
+
+/// The constraints and indexes touched by a diff. See `SqlSchemaDiff::detailed_summary`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DetailedDiffSummary {
+    pub created_indexes: Vec<SummaryEntry>,
+    pub dropped_indexes: Vec<SummaryEntry>,
+    pub created_foreign_keys: Vec<SummaryEntry>,
+    pub dropped_foreign_keys: Vec<SummaryEntry>,
+}
+
+/// An index or foreign key in a `DetailedDiffSummary`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryEntry {
+    pub table: String,
+    /// `None` for foreign keys that will be named by the database.
+    pub name: Option<String>,
+}
+
+impl SummaryEntry {
+    fn new(table: &str, name: Option<&str>) -> Self {
+        SummaryEntry {
+            table: table.to_owned(),
+            name: name.map(String::from),
+        }
+    }
+}
+
+/// All the tables reachable by following foreign keys from `start`, including `start` itself
+/// only if there is a path back to it.
+fn reachable_tables<'a>(start: &str, references: &HashMap<&'a str, Vec<&'a str>>) -> HashSet<&'a str> {
+    let mut reachable = HashSet::new();
+    let mut stack: Vec<&str> = references.get(start).cloned().unwrap_or_default();
+
+    while let Some(table_name) = stack.pop() {
+        if let Some(referenced_tables) = references.get(table_name) {
+            if reachable.insert(table_name) {
+                stack.extend(referenced_tables.iter().copied());
+            }
+        }
+    }
+
+    reachable
 }
 
 impl<'schema> SqlSchemaDiffer<'schema> {
@@ -102,12 +264,18 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         sql_family: SqlFamily,
         options: &DiffingOptions,
     ) -> SqlSchemaDiff {
-        let differ = SqlSchemaDiffer {
+        let mut differ = SqlSchemaDiffer {
             previous,
             next,
             sql_family,
             diffing_options: &options,
+            table_renames: Vec::new(),
         };
+
+        if options.detect_table_renames {
+            differ.table_renames = differ.detect_table_renames();
+        }
+
         differ.diff_internal()
     }
 
@@ -120,6 +288,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             add_foreign_keys: self.add_foreign_keys(),
             drop_foreign_keys,
             drop_tables,
+            rename_tables: self.rename_tables(),
             create_tables: self.create_tables(),
             alter_tables: self.alter_tables(),
             create_indexes: self.create_indexes(),
@@ -131,6 +300,39 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         }
     }
 
+    fn rename_tables(&self) -> Vec<RenameTable> {
+        self.table_renames
+            .iter()
+            .map(|(name, new_name)| RenameTable {
+                name: (*name).to_owned(),
+                new_name: (*new_name).to_owned(),
+            })
+            .collect()
+    }
+
+    /// Pair each dropped table with the first created table that has the same columns and indexes.
+    /// Tables without columns are never paired.
+    fn detect_table_renames(&self) -> Vec<(&'schema str, &'schema str)> {
+        let mut renames: Vec<(&'schema str, &'schema str)> = Vec::new();
+
+        for dropped_table in self.dropped_tables() {
+            if dropped_table.columns.is_empty() {
+                continue;
+            }
+
+            let created_table = self.created_tables().find(|created_table| {
+                !renames.iter().any(|(_, new_name)| *new_name == created_table.name)
+                    && tables_are_structurally_identical(dropped_table, created_table)
+            });
+
+            if let Some(created_table) = created_table {
+                renames.push((dropped_table.name.as_str(), created_table.name.as_str()));
+            }
+        }
+
+        renames
+    }
+
     fn create_tables(&self) -> Vec<CreateTable> {
         self.created_tables()
             .map(|created_table| CreateTable {
@@ -191,6 +393,9 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     .chain(Self::add_columns(&tables))
                     .chain(Self::alter_columns(&tables))
                     .chain(Self::add_primary_key(&tables))
+                    .chain(Self::convert_character_set(&tables))
+                    .chain(Self::set_comment(&tables))
+                    .chain(Self::set_storage_parameters(&tables))
                     .collect();
 
                 Some(changes)
@@ -259,9 +464,62 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(|pk| !pk.columns.is_empty())
             .map(|pk| TableChange::AddPrimaryKey {
                 columns: pk.columns.clone(),
+                constraint_name: pk.constraint_name.clone(),
             })
     }
 
+    /// Only emitted when both schemas know the default character set of the table, since the
+    /// schemas calculated from a datamodel do not have an opinion about it.
+    fn convert_character_set(differ: &TableDiffer<'_>) -> Option<TableChange> {
+        let previous = differ.previous.table;
+        let next = differ.next.table;
+
+        let previous_character_set = previous.default_character_set.as_ref()?;
+        let next_character_set = next.default_character_set.as_ref()?;
+
+        let collation_changed = match (&previous.default_collation, &next.default_collation) {
+            (Some(previous_collation), Some(next_collation)) => previous_collation != next_collation,
+            _ => false,
+        };
+
+        if previous_character_set == next_character_set && !collation_changed {
+            return None;
+        }
+
+        Some(TableChange::ConvertCharacterSet(ConvertCharacterSet {
+            character_set: next_character_set.clone(),
+            collation: next.default_collation.clone(),
+        }))
+    }
+
+    /// Only emitted when the next schema has a comment for the table. A table without a
+    /// documentation comment in the datamodel keeps whatever comment it has in the database.
+    fn set_comment(differ: &TableDiffer<'_>) -> Option<TableChange> {
+        let next_comment = differ.next.table.description.as_ref()?;
+
+        if differ.previous.table.description.as_ref() == Some(next_comment) {
+            return None;
+        }
+
+        Some(TableChange::SetComment(SetComment {
+            comment: next_comment.clone(),
+        }))
+    }
+
+    /// Only emitted when the next schema has storage parameters for the table, since the schemas
+    /// calculated from a datamodel never have any.
+    fn set_storage_parameters(differ: &TableDiffer<'_>) -> Option<TableChange> {
+        let next_parameters = &differ.next.table.storage_parameters;
+
+        if next_parameters.is_empty() || &differ.previous.table.storage_parameters == next_parameters {
+            return None;
+        }
+
+        Some(TableChange::SetStorageParameters(SetStorageParameters {
+            parameters: next_parameters.clone(),
+        }))
+    }
+
     fn drop_primary_key(differ: &TableDiffer<'_>) -> Option<TableChange> {
         differ.dropped_primary_key().map(|pk| TableChange::DropPrimaryKey {
             constraint_name: pk.constraint_name.clone(),
@@ -357,6 +615,10 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         'schema: 'a,
     {
         self.previous.tables.iter().filter_map(move |previous_table| {
+            if self.table_is_unmanaged(&previous_table.name) {
+                return None;
+            }
+
             self.next
                 .tables
                 .iter()
@@ -384,27 +646,33 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         alter_indexes
     }
 
-    fn created_tables<'a>(&'a self) -> impl Iterator<Item = &'a Table> + 'a {
-        self.next_tables()
-            .filter(move |next_table| !self.previous.has_table(&next_table.name))
+    fn created_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
+        self.next_tables().filter(move |next_table| {
+            !self.previous.has_table(&next_table.name)
+                && !self
+                    .table_renames
+                    .iter()
+                    .any(|(_, new_name)| *new_name == next_table.name)
+        })
     }
 
-    fn dropped_tables(&self) -> impl Iterator<Item = &Table> {
+    fn dropped_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
         self.previous_tables().filter(move |previous_table| {
             !self
                 .next_tables()
                 .any(|next_table| tables_match(previous_table, next_table))
+                && !self.table_renames.iter().any(|(name, _)| *name == previous_table.name)
         })
     }
 
-    fn previous_tables(&self) -> impl Iterator<Item = &Table> {
+    fn previous_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
         self.previous
             .tables
             .iter()
             .filter(move |table| !self.table_is_ignored(&table.name))
     }
 
-    fn next_tables(&self) -> impl Iterator<Item = &Table> {
+    fn next_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
         self.next
             .tables
             .iter()
@@ -412,7 +680,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     }
 
     fn table_is_ignored(&self, table_name: &str) -> bool {
-        table_name == MIGRATION_TABLE_NAME || self.diffing_options.ignore_tables.is_match(&table_name)
+        self.diffing_options.table_is_ignored(table_name) || self.table_is_unmanaged(table_name)
+    }
+
+    fn table_is_unmanaged(&self, table_name: &str) -> bool {
+        self.diffing_options.unmanaged_tables.contains(table_name)
     }
 
     fn enum_pairs(&self) -> impl Iterator<Item = EnumDiffer<'_>> {
@@ -470,7 +742,9 @@ fn push_foreign_keys_from_created_tables<'a>(
 /// should be considered equivalent for schema diffing purposes.
 fn foreign_keys_match(previous: &ForeignKeyRef<'_, '_>, next: &ForeignKeyRef<'_, '_>) -> bool {
     // Foreign keys point to different tables.
-    if previous.referenced_table().name() != next.referenced_table().name() {
+    if previous.referenced_table_name() != next.referenced_table_name()
+        || previous.referenced_schema() != next.referenced_schema()
+    {
         return false;
     }
 
@@ -500,6 +774,41 @@ fn tables_match(previous: &Table, next: &Table) -> bool {
     previous.name == next.name
 }
 
+/// Whether two differently named tables have the same columns, indexes, primary key and foreign
+/// keys, regardless of the constraint names, which are usually derived from the table name.
+fn tables_are_structurally_identical(previous: &Table, next: &Table) -> bool {
+    let columns_match = previous.columns.len() == next.columns.len()
+        && previous.columns.iter().all(|previous_column| {
+            next.columns.iter().any(|next_column| {
+                previous_column.name == next_column.name
+                    && previous_column.tpe.family == next_column.tpe.family
+                    && previous_column.tpe.arity == next_column.tpe.arity
+            })
+        });
+
+    let indexes_match = previous.indices.len() == next.indices.len()
+        && previous.indices.iter().all(|previous_index| {
+            next.indices
+                .iter()
+                .any(|next_index| previous_index.columns == next_index.columns && previous_index.tpe == next_index.tpe)
+        });
+
+    let primary_keys_match =
+        previous.primary_key.as_ref().map(|pk| &pk.columns) == next.primary_key.as_ref().map(|pk| &pk.columns);
+
+    let foreign_keys_match = previous.foreign_keys.len() == next.foreign_keys.len()
+        && previous.foreign_keys.iter().all(|previous_fk| {
+            next.foreign_keys.iter().any(|next_fk| {
+                previous_fk.columns == next_fk.columns
+                    && previous_fk.referenced_table == next_fk.referenced_table
+                    && previous_fk.referenced_columns == next_fk.referenced_columns
+                    && previous_fk.on_delete_action == next_fk.on_delete_action
+            })
+        });
+
+    columns_match && indexes_match && primary_keys_match && foreign_keys_match
+}
+
 fn enums_match(previous: &Enum, next: &Enum) -> bool {
     previous.name == next.name
 }
@@ -514,3 +823,448 @@ static POSTGRES_IGNORED_TABLES: Lazy<RegexSet> = Lazy::new(|| {
 });
 
 static EMPTY_REGEXSET: Lazy<RegexSet> = Lazy::new(|| RegexSet::new::<_, &&str>(&[]).unwrap());
+
+#[cfg(test)]
+mod tests {
+    use super::test_helpers::*;
+    use super::*;
+    use prisma_models::PrismaValue;
+
+    #[test]
+    fn user_ignored_tables_are_neither_created_nor_dropped() {
+        let options = DiffingOptions {
+            additional_ignored_tables: RegexSet::new(&["^audit_"]).unwrap(),
+            ..Default::default()
+        };
+
+        let previous = schema(vec![table("Cat"), table("audit_cats")]);
+        let next = schema(vec![table("Cat"), table("audit_dogs")]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &options);
+
+        assert!(diff.create_tables.is_empty());
+        assert!(diff.drop_tables.is_empty());
+    }
+
+    #[test]
+    fn changing_the_default_character_set_of_a_table_converts_it() {
+        let previous = schema(vec![Table {
+            default_character_set: Some("latin1".to_owned()),
+            default_collation: Some("latin1_swedish_ci".to_owned()),
+            ..table("Cat")
+        }]);
+
+        let next = schema(vec![Table {
+            default_character_set: Some("utf8mb4".to_owned()),
+            default_collation: Some("utf8mb4_unicode_ci".to_owned()),
+            ..table("Cat")
+        }]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Mysql, &DiffingOptions::default());
+
+        assert_eq!(
+            diff.alter_tables[0].changes,
+            &[TableChange::ConvertCharacterSet(ConvertCharacterSet {
+                character_set: "utf8mb4".to_owned(),
+                collation: Some("utf8mb4_unicode_ci".to_owned()),
+            })]
+        );
+    }
+
+    #[test]
+    fn changing_the_comment_of_a_table_sets_it() {
+        let previous = schema(vec![Table {
+            description: Some("A cat.".to_owned()),
+            ..table("Cat")
+        }]);
+
+        let next = schema(vec![Table {
+            description: Some("A feline.".to_owned()),
+            ..table("Cat")
+        }]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert_eq!(
+            diff.alter_tables[0].changes,
+            &[TableChange::SetComment(SetComment {
+                comment: "A feline.".to_owned(),
+            })]
+        );
+    }
+
+    #[test]
+    fn changing_the_storage_parameters_of_a_table_sets_them() {
+        let table_with_fillfactor = |fillfactor: Option<&str>| Table {
+            storage_parameters: fillfactor
+                .map(|fillfactor| vec![("fillfactor".to_owned(), fillfactor.to_owned())])
+                .unwrap_or_default(),
+            ..table("Cat")
+        };
+
+        let previous = schema(vec![table_with_fillfactor(Some("70"))]);
+        let next = schema(vec![table_with_fillfactor(Some("50"))]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert_eq!(
+            diff.alter_tables[0].changes,
+            &[TableChange::SetStorageParameters(SetStorageParameters {
+                parameters: vec![("fillfactor".to_owned(), "50".to_owned())],
+            })]
+        );
+
+        // Schemas calculated from a datamodel have no storage parameters, the existing ones are kept.
+        let next = schema(vec![table_with_fillfactor(None)]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert!(diff.alter_tables.is_empty());
+    }
+
+    #[test]
+    fn changing_the_storage_parameters_of_an_index_recreates_it() {
+        let table_with_index = |fillfactor: Option<&str>| Table {
+            indices: vec![Index {
+                storage_parameters: fillfactor
+                    .map(|fillfactor| vec![("fillfactor".to_owned(), fillfactor.to_owned())])
+                    .unwrap_or_default(),
+                ..index("name_idx", &["name"], IndexType::Normal)
+            }],
+            ..table("Cat")
+        };
+
+        let previous = schema(vec![table_with_index(Some("70"))]);
+        let next = schema(vec![table_with_index(Some("50"))]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert_eq!(diff.drop_indexes.len(), 1);
+        assert_eq!(
+            diff.create_indexes[0].index.storage_parameters,
+            &[("fillfactor".to_owned(), "50".to_owned())]
+        );
+
+        // Schemas calculated from a datamodel have no storage parameters, the existing ones are kept.
+        let next = schema(vec![table_with_index(None)]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert!(diff.drop_indexes.is_empty());
+        assert!(diff.create_indexes.is_empty());
+    }
+
+    #[test]
+    fn foreign_keys_to_other_schemas_are_not_dropped() {
+        let previous = schema(vec![Table {
+            columns: vec![column("owner_id", ColumnTypeFamily::Int)],
+            foreign_keys: vec![ForeignKey {
+                constraint_name: Some("Cat_owner_id_fkey".to_owned()),
+                referenced_schema: Some("auth".to_owned()),
+                ..foreign_key(&["owner_id"], "User")
+            }],
+            ..table("Cat")
+        }]);
+        let next = schema(vec![Table {
+            columns: vec![column("owner_id", ColumnTypeFamily::Int)],
+            ..table("Cat")
+        }]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert!(diff.drop_foreign_keys.is_empty());
+    }
+
+    #[test]
+    fn fulltext_and_spatial_indexes_match_normal_indexes() {
+        for tpe in &[IndexType::Fulltext, IndexType::Spatial] {
+            let previous = schema(vec![Table {
+                indices: vec![index("name_idx", &["name"], tpe.clone())],
+                ..table("Cat")
+            }]);
+            let next = schema(vec![Table {
+                indices: vec![index("name_idx", &["name"], IndexType::Normal)],
+                ..table("Cat")
+            }]);
+
+            let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Mysql, &DiffingOptions::default());
+
+            assert!(diff.drop_indexes.is_empty(), "{:?}", tpe);
+            assert!(diff.create_indexes.is_empty(), "{:?}", tpe);
+        }
+    }
+
+    #[test]
+    fn changing_a_char_column_to_varchar_alters_it() {
+        let table_with_string_column = |data_type: &str, full_data_type: &str| Table {
+            columns: vec![Column {
+                tpe: ColumnType {
+                    data_type: data_type.to_owned(),
+                    full_data_type: full_data_type.to_owned(),
+                    character_maximum_length: Some(10),
+                    ..ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required)
+                },
+                ..column("name", ColumnTypeFamily::String)
+            }],
+            ..table("Cat")
+        };
+
+        let previous = schema(vec![table_with_string_column("character", "bpchar")]);
+        let next = schema(vec![table_with_string_column("character varying", "varchar")]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        match diff.alter_tables[0].changes.as_slice() {
+            [TableChange::AlterColumn(AlterColumn { name, .. })] => assert_eq!(name, "name"),
+            other => panic!("Expected a single AlterColumn change, got {:?}", other),
+        }
+
+        // The columns calculated from a datamodel have no database type, they match both.
+        let next = schema(vec![Table {
+            columns: vec![column("name", ColumnTypeFamily::String)],
+            ..table("Cat")
+        }]);
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert!(diff.alter_tables.is_empty());
+    }
+
+    #[test]
+    fn identity_defaults_only_match_autoincrement_and_sequence_defaults() {
+        let identity = DefaultValue::IDENTITY(IdentityOptions {
+            always: false,
+            start: 1,
+            increment: 1,
+        });
+        let table_with_default = |default: Option<DefaultValue>, auto_increment: bool| Table {
+            columns: vec![Column {
+                default,
+                auto_increment,
+                ..column("id", ColumnTypeFamily::Int)
+            }],
+            ..table("Cat")
+        };
+        let diff = |previous: Table, next: Table| {
+            SqlSchemaDiffer::diff(
+                &schema(vec![previous]),
+                &schema(vec![next]),
+                SqlFamily::Postgres,
+                &DiffingOptions::default(),
+            )
+        };
+
+        let next = table_with_default(Some(identity.clone()), true);
+
+        for previous in &[
+            table_with_default(Some(identity.clone()), true),
+            table_with_default(Some(DefaultValue::SEQUENCE("Cat_id_seq".to_owned())), false),
+        ] {
+            assert!(diff(previous.clone(), next.clone()).alter_tables.is_empty());
+        }
+
+        let previous = table_with_default(Some(DefaultValue::VALUE(PrismaValue::Int(5))), false);
+
+        match diff(previous, next).alter_tables[0].changes.as_slice() {
+            [TableChange::AlterColumn(AlterColumn { name, .. })] => assert_eq!(name, "id"),
+            other => panic!("Expected a single AlterColumn change, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn foreign_key_cycles_between_created_tables_are_reported() {
+        let table_with_foreign_keys = |name: &str, referenced_tables: &[&str]| Table {
+            foreign_keys: referenced_tables
+                .iter()
+                .map(|referenced_table| {
+                    let column = format!("{}_id", referenced_table);
+
+                    foreign_key(&[column.as_str()], referenced_table)
+                })
+                .collect(),
+            ..table(name)
+        };
+
+        let next = schema(vec![
+            table_with_foreign_keys("Toy", &["Cat"]),
+            table_with_foreign_keys("Human", &["Cat", "Human"]),
+            table_with_foreign_keys("Cat", &["Human"]),
+        ]);
+
+        let diff = SqlSchemaDiffer::diff(
+            &SqlSchema::empty(),
+            &next,
+            SqlFamily::Sqlite,
+            &DiffingOptions::default(),
+        );
+
+        assert_eq!(
+            diff.foreign_key_cycles(),
+            vec![vec!["Cat".to_owned(), "Human".to_owned()]]
+        );
+    }
+
+    /// A table with an `id` primary key and a unique `name` column. The constraint names are
+    /// derived from the table name, like the ones Prisma creates.
+    fn table_with_columns(name: &str) -> Table {
+        Table {
+            columns: vec![
+                column("id", ColumnTypeFamily::String),
+                column("name", ColumnTypeFamily::String),
+            ],
+            indices: vec![index(&format!("{}.name_unique", name), &["name"], IndexType::Unique)],
+            primary_key: Some(primary_key(&["id"])),
+            ..table(name)
+        }
+    }
+
+    #[test]
+    fn structurally_identical_tables_with_different_names_are_renamed() {
+        let previous = schema(vec![table_with_columns("Cat")]);
+        let next = schema(vec![table_with_columns("Feline")]);
+
+        let options = DiffingOptions {
+            detect_table_renames: true,
+            ..Default::default()
+        };
+
+        let steps = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &options).into_steps();
+
+        assert_eq!(
+            steps,
+            &[SqlMigrationStep::RenameTable(RenameTable {
+                name: "Cat".to_owned(),
+                new_name: "Feline".to_owned(),
+            })]
+        );
+
+        let steps =
+            SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &DiffingOptions::default()).into_steps();
+
+        assert!(!steps
+            .iter()
+            .any(|step| matches!(step, SqlMigrationStep::RenameTable(_))));
+    }
+
+    #[test]
+    fn tables_with_foreign_keys_are_renamed_despite_their_constraint_names() {
+        let table_with_foreign_key = |name: &str| Table {
+            foreign_keys: vec![ForeignKey {
+                constraint_name: Some(format!("{}_name_fkey", name)),
+                on_delete_action: ForeignKeyAction::Cascade,
+                ..foreign_key(&["name"], "Human")
+            }],
+            ..table_with_columns(name)
+        };
+
+        let previous = schema(vec![table_with_foreign_key("Cat"), table_with_columns("Human")]);
+        let next = schema(vec![table_with_foreign_key("Feline"), table_with_columns("Human")]);
+
+        let options = DiffingOptions {
+            detect_table_renames: true,
+            ..Default::default()
+        };
+
+        let diff = SqlSchemaDiffer::diff(&previous, &next, SqlFamily::Postgres, &options);
+
+        assert_eq!(
+            diff.rename_tables,
+            &[RenameTable {
+                name: "Cat".to_owned(),
+                new_name: "Feline".to_owned(),
+            }]
+        );
+        assert!(diff.create_tables.is_empty());
+        assert!(diff.drop_tables.is_empty());
+    }
+
+    #[test]
+    fn detailed_summaries_list_index_and_foreign_key_names() {
+        let diff = SqlSchemaDiff {
+            add_foreign_keys: vec![AddForeignKey {
+                table: "Cat".to_owned(),
+                foreign_key: ForeignKey {
+                    on_delete_action: ForeignKeyAction::Cascade,
+                    ..foreign_key(&["ownerId"], "Owner")
+                },
+            }],
+            drop_foreign_keys: vec![DropForeignKey {
+                table: "Cat".to_owned(),
+                constraint_name: "Cat_humanId_fkey".to_owned(),
+            }],
+            drop_tables: Vec::new(),
+            rename_tables: Vec::new(),
+            create_tables: Vec::new(),
+            alter_tables: Vec::new(),
+            create_indexes: vec![CreateIndex {
+                table: "Cat".to_owned(),
+                index: index("Cat.name_unique", &["name"], IndexType::Unique),
+            }],
+            drop_indexes: vec![DropIndex {
+                table: "Cat".to_owned(),
+                name: "Cat.age_index".to_owned(),
+            }],
+            alter_indexes: Vec::new(),
+            create_enums: Vec::new(),
+            drop_enums: Vec::new(),
+            alter_enums: Vec::new(),
+        };
+
+        let summary = diff.detailed_summary();
+
+        assert_eq!(
+            summary.created_indexes,
+            &[SummaryEntry::new("Cat", Some("Cat.name_unique"))]
+        );
+        assert_eq!(
+            summary.dropped_indexes,
+            &[SummaryEntry::new("Cat", Some("Cat.age_index"))]
+        );
+        assert_eq!(summary.created_foreign_keys, &[SummaryEntry::new("Cat", None)]);
+        assert_eq!(
+            summary.dropped_foreign_keys,
+            &[SummaryEntry::new("Cat", Some("Cat_humanId_fkey"))]
+        );
+    }
+
+    #[test]
+    fn renaming_a_primary_key_on_postgres_drops_and_adds_it() {
+        let table_with_primary_key = |constraint_name: Option<&str>| Table {
+            primary_key: Some(PrimaryKey {
+                constraint_name: constraint_name.map(String::from),
+                ..primary_key(&["id"])
+            }),
+            ..table("Cat")
+        };
+
+        let diff = SqlSchemaDiffer::diff(
+            &schema(vec![table_with_primary_key(Some("Cat_pkey"))]),
+            &schema(vec![table_with_primary_key(Some("custom_pk"))]),
+            SqlFamily::Postgres,
+            &DiffingOptions::default(),
+        );
+
+        assert_eq!(
+            diff.alter_tables[0].changes,
+            &[
+                TableChange::DropPrimaryKey {
+                    constraint_name: Some("Cat_pkey".to_owned()),
+                },
+                TableChange::AddPrimaryKey {
+                    columns: vec!["id".to_owned()],
+                    constraint_name: Some("custom_pk".to_owned()),
+                },
+            ]
+        );
+
+        // A primary key without a name in the datamodel keeps the name it has.
+        let diff = SqlSchemaDiffer::diff(
+            &schema(vec![table_with_primary_key(Some("Cat_pkey"))]),
+            &schema(vec![table_with_primary_key(None)]),
+            SqlFamily::Postgres,
+            &DiffingOptions::default(),
+        );
+
+        assert!(diff.alter_tables.is_empty());
+    }
+}
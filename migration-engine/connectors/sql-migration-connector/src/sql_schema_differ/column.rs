@@ -1,6 +1,6 @@
 use crate::sql_schema_helpers::ColumnRef;
 use prisma_models::PrismaValue;
-use sql_schema_describer::{ColumnTypeFamily, DefaultValue};
+use sql_schema_describer::{ColumnType, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -61,7 +61,18 @@ impl<'a> ColumnDiffer<'a> {
             return false;
         }
 
-        self.previous.column_type_family() != self.next.column_type_family()
+        if self.previous.column_type_family() != self.next.column_type_family() {
+            return true;
+        }
+
+        // CHAR and VARCHAR columns are both strings, only their database types tell them apart.
+        match (
+            is_fixed_length_character_type(self.previous.column_type()),
+            is_fixed_length_character_type(self.next.column_type()),
+        ) {
+            (Some(previous_is_fixed), Some(next_is_fixed)) => previous_is_fixed != next_is_fixed,
+            _ => false,
+        }
     }
 
     /// There are workarounds to cope with current migration and introspection limitations.
@@ -118,17 +129,49 @@ impl<'a> ColumnDiffer<'a> {
             (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::VALUE(_))) => false,
             (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::NOW)) => false,
 
+            (Some(DefaultValue::LIST(prev)), Some(DefaultValue::LIST(next))) => prev == next,
+            (Some(DefaultValue::LIST(_)), Some(DefaultValue::SEQUENCE(_))) => true,
+            (Some(DefaultValue::LIST(_)), Some(DefaultValue::VALUE(_)))
+            | (Some(DefaultValue::LIST(_)), Some(DefaultValue::NOW))
+            | (Some(DefaultValue::LIST(_)), None) => false,
+            (_, Some(DefaultValue::LIST(_))) => false,
+
             (None, None) => true,
             (None, Some(DefaultValue::SEQUENCE(_))) => true,
             (None, Some(DefaultValue::VALUE(_))) => false,
             (None, Some(DefaultValue::NOW)) => false,
 
+            // Identity columns are autoincrementing, and the datamodel cannot express their options.
+            (Some(DefaultValue::IDENTITY(_)), Some(DefaultValue::IDENTITY(_)))
+            | (Some(DefaultValue::IDENTITY(_)), Some(DefaultValue::SEQUENCE(_)))
+            | (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::IDENTITY(_))) => true,
+            (Some(DefaultValue::IDENTITY(_)), None) => self.next.auto_increment(),
+
             // We can never migrate to @dbgenerated
             (_, Some(DefaultValue::DBGENERATED(_))) => true,
+
+            (Some(DefaultValue::IDENTITY(_)), _) | (_, Some(DefaultValue::IDENTITY(_))) => false,
         }
     }
 }
 
+/// Whether the column has a fixed-length (`CHAR`) rather than a variable-length (`VARCHAR`) character
+/// type. `None` for other types, and for the columns calculated from a datamodel, which have no
+/// database type.
+fn is_fixed_length_character_type(column_type: &ColumnType) -> Option<bool> {
+    [&column_type.data_type, &column_type.full_data_type]
+        .iter()
+        .find_map(|data_type| {
+            let base_type = data_type.trim_start_matches('_').split('(').next().unwrap_or_default();
+
+            match base_type.trim().to_lowercase().as_str() {
+                "char" | "character" | "bpchar" => Some(true),
+                "varchar" | "character varying" => Some(false),
+                _ => None,
+            }
+        })
+}
+
 fn json_defaults_match(previous: &str, next: &str) -> bool {
     serde_json::from_str::<serde_json::Value>(previous)
         .and_then(|previous| serde_json::from_str::<serde_json::Value>(next).map(|next| (previous, next)))
@@ -0,0 +1,198 @@
+//! Postgres folds unquoted identifiers to lower case: a table created with `CREATE TABLE User`
+//! is named `user`. Prisma always quotes identifiers, so the schema calculated from the datamodel
+//! spells the table `User`, and matching the names exactly would drop and recreate the table.
+
+use sql_schema_describer::{SqlSchema, Table};
+use std::collections::HashMap;
+
+/// Rename the tables and columns of `next` that only exist in `previous` under their folded (lower
+/// case) spelling, so they match. Names that match exactly, whose folded spelling is already taken
+/// in `next`, or that could not have been written unquoted in the first place, are left alone.
+/// Every rename is logged, since it also applies to a table that was created quoted in lower case.
+pub(crate) fn normalize_folded_identifiers(previous: &SqlSchema, next: &mut SqlSchema) {
+    let table_renames = folded_renames(
+        previous.tables.iter().map(|table| table.name.as_str()),
+        next.tables.iter().map(|table| table.name.as_str()),
+    );
+
+    let mut column_renames: HashMap<String, HashMap<String, String>> = HashMap::new();
+
+    for table in &mut next.tables {
+        if let Some(folded_name) = table_renames.get(&table.name) {
+            table.name = folded_name.clone();
+        }
+
+        let previous_table = match previous.table(&table.name) {
+            Ok(previous_table) => previous_table,
+            Err(_) => continue,
+        };
+
+        let renames = folded_renames(
+            previous_table.columns.iter().map(|column| column.name.as_str()),
+            table.columns.iter().map(|column| column.name.as_str()),
+        );
+
+        if renames.is_empty() {
+            continue;
+        }
+
+        rename_columns(table, &renames);
+        column_renames.insert(table.name.clone(), renames);
+    }
+
+    for table in &mut next.tables {
+        for foreign_key in &mut table.foreign_keys {
+            if let Some(folded_name) = table_renames.get(&foreign_key.referenced_table) {
+                foreign_key.referenced_table = folded_name.clone();
+            }
+
+            if let Some(renames) = column_renames.get(&foreign_key.referenced_table) {
+                rename_all(&mut foreign_key.referenced_columns, renames);
+            }
+        }
+    }
+}
+
+/// The names in `next` that have no exact match in `previous`, mapped to their folded spelling when
+/// `previous` has it.
+fn folded_renames<'a>(
+    previous: impl Iterator<Item = &'a str> + Clone,
+    next: impl Iterator<Item = &'a str> + Clone,
+) -> HashMap<String, String> {
+    next.clone()
+        .filter(|name| !previous.clone().any(|previous_name| previous_name == *name))
+        .filter(|name| could_have_been_folded(name))
+        .filter_map(|name| {
+            let folded = name.to_ascii_lowercase();
+
+            let folded_exists_in_previous = previous.clone().any(|previous_name| previous_name == folded);
+            let folded_is_taken_in_next = next.clone().any(|next_name| next_name == folded);
+
+            if folded_exists_in_previous && !folded_is_taken_in_next {
+                tracing::warn!(
+                    "Matching `{}` with `{}`, assuming Postgres folded the unquoted identifier to lower case.",
+                    name,
+                    folded
+                );
+
+                Some((name.to_owned(), folded))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Postgres only folds identifiers written without quotes, and those can only contain ASCII letters,
+/// digits, underscores and dollar signs.
+fn could_have_been_folded(name: &str) -> bool {
+    let starts_like_an_identifier = name
+        .chars()
+        .next()
+        .map(|first_char| first_char.is_ascii_alphabetic() || first_char == '_')
+        .unwrap_or(false);
+
+    starts_like_an_identifier
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        && name.chars().any(|c| c.is_ascii_uppercase())
+}
+
+fn rename_columns(table: &mut Table, renames: &HashMap<String, String>) {
+    for column in &mut table.columns {
+        if let Some(folded_name) = renames.get(&column.name) {
+            column.name = folded_name.clone();
+        }
+    }
+
+    for index in &mut table.indices {
+        rename_all(&mut index.columns, renames);
+    }
+
+    if let Some(primary_key) = &mut table.primary_key {
+        rename_all(&mut primary_key.columns, renames);
+    }
+
+    for foreign_key in &mut table.foreign_keys {
+        rename_all(&mut foreign_key.columns, renames);
+    }
+}
+
+fn rename_all(names: &mut [String], renames: &HashMap<String, String>) {
+    for name in names {
+        if let Some(folded_name) = renames.get(name.as_str()) {
+            *name = folded_name.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helpers::*;
+    use super::*;
+    use sql_schema_describer::*;
+
+    /// A table with `Int` columns, the first one being the primary key.
+    fn table_with_columns(name: &str, columns: &[&str]) -> Table {
+        Table {
+            columns: columns.iter().map(|name| column(name, ColumnTypeFamily::Int)).collect(),
+            primary_key: Some(primary_key(&columns[..1])),
+            ..table(name)
+        }
+    }
+
+    #[test]
+    fn folded_tables_and_columns_are_matched() {
+        let previous = schema(vec![
+            table_with_columns("user", &["id", "firstname"]),
+            table_with_columns("Post", &["id"]),
+        ]);
+
+        let mut next = schema(vec![
+            table_with_columns("User", &["id", "firstName"]),
+            table_with_columns("Post", &["id", "userId"]),
+        ]);
+
+        next.tables[1].foreign_keys.push(foreign_key(&["userId"], "User"));
+
+        normalize_folded_identifiers(&previous, &mut next);
+
+        let user = next.table("user").unwrap();
+        assert!(user.column("firstname").is_some());
+
+        let post = next.table("Post").unwrap();
+        assert!(post.column("userId").is_some());
+        assert_eq!(post.foreign_keys[0].referenced_table, "user");
+    }
+
+    #[test]
+    fn exact_matches_are_preferred_over_folded_ones() {
+        let previous = schema(vec![
+            table_with_columns("user", &["id"]),
+            table_with_columns("User", &["id"]),
+        ]);
+
+        let mut next = schema(vec![table_with_columns("User", &["id"])]);
+
+        normalize_folded_identifiers(&previous, &mut next);
+
+        assert!(next.table("User").is_ok());
+    }
+
+    #[test]
+    fn names_that_must_be_quoted_are_not_folded() {
+        let previous = schema(vec![
+            table_with_columns("user profile", &["id"]),
+            table_with_columns("café", &["id", "naïve"]),
+        ]);
+
+        let mut next = schema(vec![
+            table_with_columns("User Profile", &["id"]),
+            table_with_columns("café", &["id", "NAÏVE"]),
+        ]);
+
+        normalize_folded_identifiers(&previous, &mut next);
+
+        assert!(next.table("User Profile").is_ok());
+        assert!(next.table("café").unwrap().column("NAÏVE").is_some());
+    }
+}
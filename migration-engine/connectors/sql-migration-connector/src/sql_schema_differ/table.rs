@@ -1,7 +1,8 @@
 use super::column::ColumnDiffer;
 use crate::sql_schema_helpers::ForeignKeyRef;
 use crate::sql_schema_helpers::{ColumnRef, TableRef};
-use sql_schema_describer::{Index, PrimaryKey};
+use quaint::prelude::SqlFamily;
+use sql_schema_describer::{Index, IndexType, PrimaryKey};
 
 pub(crate) struct TableDiffer<'a> {
     pub(crate) diffing_options: &'a super::DiffingOptions,
@@ -48,12 +49,16 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// Foreign keys to tables in other schemas are never dropped: the datamodel can't represent
+    /// them, so they are missing from any schema calculated from it.
     pub(crate) fn dropped_foreign_keys(&self) -> impl Iterator<Item = ForeignKeyRef<'_, 'schema>> {
-        self.previous_foreign_keys().filter(move |previous_fk| {
-            self.next_foreign_keys()
-                .find(|next_fk| super::foreign_keys_match(previous_fk, next_fk))
-                .is_none()
-        })
+        self.previous_foreign_keys()
+            .filter(|previous_fk| previous_fk.referenced_schema().is_none())
+            .filter(move |previous_fk| {
+                self.next_foreign_keys()
+                    .find(|next_fk| super::foreign_keys_match(previous_fk, next_fk))
+                    .is_none()
+            })
     }
 
     pub(crate) fn created_indexes<'a>(&'a self) -> impl Iterator<Item = &'schema Index> + 'a {
@@ -86,7 +91,7 @@ impl<'schema> TableDiffer<'schema> {
             (None, Some(pk)) => Some(pk),
             (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(next_pk),
             (Some(previous_pk), Some(next_pk)) => {
-                if self.primary_key_column_changed(previous_pk) {
+                if self.primary_key_column_changed(previous_pk) || self.primary_key_renamed(previous_pk, next_pk) {
                     Some(next_pk)
                 } else {
                     None
@@ -101,8 +106,8 @@ impl<'schema> TableDiffer<'schema> {
         match (self.previous.primary_key(), self.next.primary_key()) {
             (Some(pk), None) => Some(pk),
             (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(previous_pk),
-            (Some(previous_pk), Some(_next_pk)) => {
-                if self.primary_key_column_changed(previous_pk) {
+            (Some(previous_pk), Some(next_pk)) => {
+                if self.primary_key_column_changed(previous_pk) || self.primary_key_renamed(previous_pk, next_pk) {
                     Some(previous_pk)
                 } else {
                     None
@@ -112,6 +117,14 @@ impl<'schema> TableDiffer<'schema> {
         }
     }
 
+    /// Returns true if the datamodel names the primary key constraint differently. Only Postgres keeps the names of
+    /// primary keys, and a primary key without a name in `next` keeps whatever name it has.
+    fn primary_key_renamed(&self, previous_pk: &PrimaryKey, next_pk: &PrimaryKey) -> bool {
+        self.diffing_options.sql_family() == SqlFamily::Postgres
+            && next_pk.constraint_name.is_some()
+            && previous_pk.constraint_name != next_pk.constraint_name
+    }
+
     /// Returns true if any of the columns of the primary key changed type.
     fn primary_key_column_changed(&self, previous_pk: &PrimaryKey) -> bool {
         self.column_pairs()
@@ -153,7 +166,17 @@ pub(crate) fn columns_match(a: &ColumnRef<'_>, b: &ColumnRef<'_>) -> bool {
     a.name() == b.name()
 }
 
-/// Compare two SQL indexes and return whether they only differ by name.
+/// Compare two SQL indexes and return whether they only differ by name. The datamodel cannot
+/// express deferrable constraints or storage parameters, so these are only compared when `second`
+/// sets them. Fulltext and spatial indexes are introspected as normal indexes, so they match those.
 fn indexes_match(first: &Index, second: &Index) -> bool {
-    first.columns == second.columns && first.tpe == second.tpe
+    let types_match = match (&first.tpe, &second.tpe) {
+        (IndexType::Fulltext, IndexType::Normal) | (IndexType::Spatial, IndexType::Normal) => true,
+        (first, second) => first == second,
+    };
+
+    first.columns == second.columns
+        && types_match
+        && (second.deferrable.is_none() || first.deferrable == second.deferrable)
+        && (second.storage_parameters.is_empty() || first.storage_parameters == second.storage_parameters)
 }
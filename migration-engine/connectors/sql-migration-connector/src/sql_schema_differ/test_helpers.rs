@@ -0,0 +1,73 @@
+//! Schema fixtures shared by the differ's unit tests. Fields that a test does not care about get
+//! the values a schema calculated from a datamodel would have.
+
+use sql_schema_describer::*;
+
+/// A schema with only the given tables.
+pub(super) fn schema(tables: Vec<Table>) -> SqlSchema {
+    SqlSchema {
+        tables,
+        ..SqlSchema::empty()
+    }
+}
+
+/// A table without columns, indexes or keys.
+pub(super) fn table(name: &str) -> Table {
+    Table {
+        name: name.to_owned(),
+        columns: Vec::new(),
+        indices: Vec::new(),
+        primary_key: None,
+        foreign_keys: Vec::new(),
+        engine: None,
+        default_character_set: None,
+        default_collation: None,
+        description: None,
+        storage_parameters: Vec::new(),
+    }
+}
+
+/// A required column without a database type or default.
+pub(super) fn column(name: &str, family: ColumnTypeFamily) -> Column {
+    Column {
+        name: name.to_owned(),
+        tpe: ColumnType::pure(family, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+    }
+}
+
+/// An index without a predicate or storage parameters.
+pub(super) fn index(name: &str, columns: &[&str], tpe: IndexType) -> Index {
+    Index {
+        name: name.to_owned(),
+        columns: columns.iter().map(|column| (*column).to_owned()).collect(),
+        tpe,
+        is_constraint: false,
+        predicate: None,
+        storage_parameters: Vec::new(),
+        deferrable: None,
+    }
+}
+
+/// A primary key without a constraint name or sequence.
+pub(super) fn primary_key(columns: &[&str]) -> PrimaryKey {
+    PrimaryKey {
+        columns: columns.iter().map(|column| (*column).to_owned()).collect(),
+        sequence: None,
+        constraint_name: None,
+        autoincrement: false,
+    }
+}
+
+/// A foreign key without a constraint name, referencing the `id` column of the table.
+pub(super) fn foreign_key(columns: &[&str], referenced_table: &str) -> ForeignKey {
+    ForeignKey {
+        constraint_name: None,
+        columns: columns.iter().map(|column| (*column).to_owned()).collect(),
+        referenced_table: referenced_table.to_owned(),
+        referenced_schema: None,
+        referenced_columns: vec!["id".to_owned()],
+        on_delete_action: ForeignKeyAction::NoAction,
+    }
+}
@@ -140,15 +140,13 @@ impl<'a, 'schema> ForeignKeyRef<'a, 'schema> {
         self.foreign_key.referenced_columns.len()
     }
 
-    pub(crate) fn referenced_table(&self) -> TableRef<'a> {
-        TableRef {
-            schema: self.table.schema,
-            table: self
-                .table
-                .schema
-                .table(&self.foreign_key.referenced_table)
-                .expect("foreign key references unknown table"),
-        }
+    pub(crate) fn referenced_table_name(&self) -> &'a str {
+        &self.foreign_key.referenced_table
+    }
+
+    /// The schema of the referenced table, if it is not the schema of the constrained table.
+    pub(crate) fn referenced_schema(&self) -> Option<&'a str> {
+        self.foreign_key.referenced_schema.as_deref()
     }
 
     pub(crate) fn table(&self) -> &'a TableRef<'schema> {
@@ -147,8 +147,10 @@ impl<'a> ApplyMigrationCommand<'a> {
             .check(&database_migration)
             .await?;
 
-        match (diagnostics.has_warnings(), self.input.force.unwrap_or(false)) {
-            // We have no warnings, or the force flag is passed.
+        let needs_force = diagnostics.has_warnings() || diagnostics.has_unexecutable_migrations();
+
+        match (needs_force, self.input.force.unwrap_or(false)) {
+            // We have no warnings or unexecutable steps, or the force flag is passed.
             (false, _) | (true, true) => {
                 tracing::debug!("Applying the migration");
                 let saved_migration = migration_persistence.create(migration).await?;
@@ -160,7 +162,7 @@ impl<'a> ApplyMigrationCommand<'a> {
 
                 tracing::debug!("Migration applied");
             }
-            // We have warnings, but no force flag was passed.
+            // We have warnings or unexecutable steps, but no force flag was passed.
             (true, false) => tracing::info!("The force flag was not passed, the migration will not be applied."),
         }
 
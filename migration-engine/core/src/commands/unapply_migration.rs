@@ -62,7 +62,9 @@ impl<'a> MigrationCommand for UnapplyMigrationCommand<'a> {
 
                 let warnings = destructive_changes_checker.check(&database_migration).await?;
 
-                match (warnings.has_warnings(), input.force) {
+                let needs_force = warnings.has_warnings() || warnings.has_unexecutable_migrations();
+
+                match (needs_force, input.force) {
                     (false, _) | (true, None) | (true, Some(true)) => {
                         connector
                             .migration_applier()
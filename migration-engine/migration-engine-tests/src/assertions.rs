@@ -224,6 +224,18 @@ impl<'a> TableAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_engine(self, expected: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.engine.as_deref() == Some(expected),
+            "Assertion failed: expected the {} table to use the {} engine, found {:?}.",
+            self.0.name,
+            expected,
+            self.0.engine
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_indexes_count(self, n: usize) -> AssertionResult<Self> {
         let idx_count = self.0.indices.len();
         anyhow::ensure!(
@@ -323,6 +335,34 @@ impl<'a> ColumnAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_full_data_type(self, expected: &str) -> AssertionResult<Self> {
+        let found = &self.0.tpe.full_data_type;
+
+        anyhow::ensure!(
+            found == expected,
+            "Assertion failed. Expected the full data type for `{}` to be `{}`, found `{}`",
+            self.0.name,
+            expected,
+            found,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_datetime_precision(self, expected: Option<u32>) -> AssertionResult<Self> {
+        let found = self.0.tpe.datetime_precision;
+
+        anyhow::ensure!(
+            found == expected,
+            "Assertion failed. Expected the datetime precision for `{}` to be `{:?}`, found `{:?}`",
+            self.0.name,
+            expected,
+            found,
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_type_is_string(self) -> AssertionResult<Self> {
         let found = &self.0.tpe.family;
 
@@ -35,9 +35,14 @@ pub struct TestApi {
     database: Arc<dyn Queryable + Send + Sync + 'static>,
     api: MigrationApi<sql_migration_connector::SqlMigrationConnector, sql_migration_connector::SqlMigration>,
     connection_info: ConnectionInfo,
+    url: String,
 }
 
 impl TestApi {
+    pub fn connector(&self) -> &sql_migration_connector::SqlMigrationConnector {
+        self.api.connector()
+    }
+
     pub fn connector_name(&self) -> &str {
         self.connector_name
     }
@@ -66,6 +71,11 @@ impl TestApi {
         self.api.migration_persistence()
     }
 
+    /// The connection string of the test database.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
     pub fn connection_info(&self) -> &ConnectionInfo {
         &self.connection_info
     }
@@ -265,6 +275,7 @@ pub async fn mysql_8_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -278,6 +289,7 @@ pub async fn mysql_5_6_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -291,6 +303,7 @@ pub async fn mysql_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -304,6 +317,7 @@ pub async fn mysql_mariadb_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -317,6 +331,7 @@ pub async fn postgres9_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -330,6 +345,7 @@ pub async fn postgres_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -343,6 +359,7 @@ pub async fn postgres11_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -356,11 +373,13 @@ pub async fn postgres12_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
 pub async fn sqlite_test_api(db_name: &str) -> TestApi {
-    let connection_info = ConnectionInfo::from_url(&sqlite_test_url(db_name)).unwrap();
+    let url = sqlite_test_url(db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
     let connector = sqlite_migration_connector(db_name).await;
 
     TestApi {
@@ -368,5 +387,6 @@ pub async fn sqlite_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
@@ -435,3 +435,33 @@ async fn json_fields_must_be_rejected(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn named_primary_keys_must_be_rejected_on_mysql(api: &TestApi) -> TestResult {
+    let dm = format!(
+        r#"
+        {}
+
+        model Blog {{
+            id       Int
+            authorId String
+
+            @@id([id, authorId], map: "custom_pk")
+        }}
+        "#,
+        api.datasource()
+    );
+
+    let result = api.infer(dm).send().await?;
+
+    assert_eq!(
+        result
+            .errors
+            .into_iter()
+            .map(|error| error.description.clone())
+            .collect::<Vec<String>>(),
+        &["The primary key of the model Blog is named `custom_pk`, but primary keys cannot be named on MySQL."]
+    );
+
+    Ok(())
+}
@@ -113,3 +113,43 @@ async fn adding_a_required_field_without_default_to_an_existing_table_without_da
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql", "sqlite"))]
+async fn adding_a_required_field_with_a_db_generated_default_to_a_populated_table_is_unexecutable(
+    api: &TestApi,
+) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("name", "george")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            name String
+            age Int @default(dbgenerated())
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .force(Some(false))
+        .send()
+        .await?
+        .assert_unexecutable(&["Added the required column `age` to the `Test` table without a default value. There are 1 rows in this table, it is not possible to execute this migration.".into()])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Test", |table| table.assert_does_not_have_column("age"))?;
+
+    Ok(())
+}
@@ -165,3 +165,27 @@ async fn migration_is_already_applied_must_work(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn applied_migrations_must_return_the_names_in_application_order(api: &TestApi) -> TestResult {
+    let persistence = api.migration_persistence();
+
+    for (name, status) in &[
+        ("migration_c", MigrationStatus::MigrationSuccess),
+        ("migration_a", MigrationStatus::MigrationSuccess),
+        ("migration_failed", MigrationStatus::MigrationFailure),
+        ("migration_b", MigrationStatus::MigrationSuccess),
+    ] {
+        let mut migration = empty_migration(name.to_string());
+        migration.status = *status;
+
+        persistence.create(migration).await?;
+    }
+
+    assert_eq!(
+        api.connector().applied_migrations().await?,
+        &["migration_c", "migration_a", "migration_b"]
+    );
+
+    Ok(())
+}
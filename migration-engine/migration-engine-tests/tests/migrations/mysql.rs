@@ -1,4 +1,6 @@
 use migration_engine_tests::sql::*;
+use sql_migration_connector::{AlterTable, ConvertCharacterSet, SqlMigration, SqlMigrationStep, TableChange};
+use sql_schema_describer::SqlSchema;
 
 /// We need to test this specifically for mysql, because foreign keys are indexes, and they are
 /// inferred as both foreign key and index by the sql-schema-describer. We do not want to
@@ -94,3 +96,108 @@ async fn enums_work_when_table_name_is_remapped(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn table_engines_are_preserved_when_tables_are_recreated(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.database()
+        .raw_cmd(&format!("ALTER TABLE `{}`.`Cat` ENGINE=MyISAM", api.schema_name()))
+        .await?;
+
+    api.infer_apply("").send().await?.assert_green()?;
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    api.unapply_migration().send().await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_engine("MyISAM"))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn changing_the_default_character_set_of_a_table_converts_it(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let utf8_table = api.describe_database().await?.table_bang("Cat").clone();
+    assert_eq!(utf8_table.default_character_set.as_deref(), Some("utf8mb4"));
+
+    api.database()
+        .raw_cmd(&format!(
+            "ALTER TABLE `{}`.`Cat` CONVERT TO CHARACTER SET latin1",
+            api.schema_name()
+        ))
+        .await?;
+
+    let latin1_table = api.describe_database().await?.table_bang("Cat").clone();
+    assert_eq!(latin1_table.default_character_set.as_deref(), Some("latin1"));
+
+    let step = SqlMigrationStep::AlterTable(AlterTable {
+        table: utf8_table,
+        changes: vec![TableChange::ConvertCharacterSet(ConvertCharacterSet {
+            character_set: "utf8mb4".to_owned(),
+            collation: Some("utf8mb4_unicode_ci".to_owned()),
+        })],
+    });
+
+    let migration = SqlMigration {
+        corrected_steps: vec![step.clone()],
+        ..SqlMigration::empty()
+    };
+
+    let script = api.connector().render_steps_to_sql(&migration, None)?;
+    assert!(script.contains("CONVERT TO CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci"));
+
+    api.connector().apply_single_step(&step).await?;
+
+    let converted_table = api.describe_database().await?.table_bang("Cat").clone();
+    assert_eq!(converted_table.default_character_set.as_deref(), Some("utf8mb4"));
+    assert_eq!(converted_table.default_collation.as_deref(), Some("utf8mb4_unicode_ci"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn partial_indexes_are_rejected_on_mysql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String @unique
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let mut schema: SqlSchema = api.describe_database().await?;
+    let index = schema
+        .tables
+        .iter_mut()
+        .find(|table| table.name == "Cat")
+        .and_then(|table| table.indices.iter_mut().find(|index| index.columns == ["name"]))
+        .unwrap();
+
+    index.predicate = Some("name <> ''".to_owned());
+    let index_name = index.name.clone();
+
+    let errors = api.connector().check_index_compatibility(&schema);
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(errors[0].description.contains(&index_name), "{}", errors[0].description);
+
+    Ok(())
+}
@@ -1,5 +1,12 @@
 use migration_engine_tests::*;
-use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
+use prisma_value::PrismaValue;
+use sql_migration_connector::{
+    CreateIndex, CreateTable, SqlMigrationConnector, SqlMigrationStep, StatementInterceptor,
+};
+use sql_schema_describer::{
+    ColumnArity, ColumnTypeFamily, DefaultValue, Deferrable, IdentityOptions, Index, IndexType,
+};
+use std::sync::{Arc, Mutex};
 
 #[test_each_connector(tags("postgres"))]
 async fn enums_can_be_dropped_on_postgres(api: &TestApi) -> TestResult {
@@ -96,3 +103,882 @@ async fn existing_postgis_tables_must_not_be_migrated(api: &TestApi) -> TestResu
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn indexes_backing_unique_constraints_do_not_produce_spurious_steps(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}"."Cat" ( id INTEGER PRIMARY KEY, name TEXT NOT NULL, CONSTRAINT "Cat.name" UNIQUE (name) )"#,
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_indexes_count(1)?
+            .assert_index_on_columns(&["name"], |index| index.assert_is_unique()?.assert_name("Cat.name"))
+    })?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String @unique
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_indexes_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn datetime_precision_is_introspected_and_round_tripped(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id        Int      @id
+            birthday  DateTime
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("birthday", |col| col.assert_datetime_precision(Some(3)))
+    })?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("birthday", |col| col.assert_datetime_precision(Some(3)))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn timezone_awareness_of_timestamps_is_preserved(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}"."Cat" ( id INTEGER PRIMARY KEY, birthday TIMESTAMPTZ(3) NOT NULL, adopted_at TIMESTAMP(3) NOT NULL )"#,
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    let dm = r#"
+        model Cat {
+            id         Int      @id
+            birthday   DateTime
+            adopted_at DateTime
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_column("birthday", |col| {
+                col.assert_full_data_type("timestamptz")?
+                    .assert_datetime_precision(Some(3))
+            })?
+            .assert_column("adopted_at", |col| {
+                col.assert_full_data_type("timestamp")?
+                    .assert_datetime_precision(Some(3))
+            })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn overlong_index_names_are_truncated_deterministically(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            averyveryverylongcolumnnamenumberone String
+            averyveryverylongcolumnnamenumbertwo String
+
+            @@index([averyveryverylongcolumnnamenumberone, averyveryverylongcolumnnamenumbertwo])
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(
+            &[
+                "averyveryverylongcolumnnamenumberone",
+                "averyveryverylongcolumnnamenumbertwo",
+            ],
+            |idx| idx.assert_name("Cat.averyveryverylongcolumnnamenumberone_averyveryvery_8eed85d8"),
+        )
+    })?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn overlong_index_names_chosen_by_the_user_are_rejected(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@index([name], name: "an_index_name_that_is_much_longer_than_postgres_allows_identifiers_to_be")
+        }
+    "#;
+
+    let error = match api.infer_apply(dm).send().await {
+        Ok(_) => panic!("Expected the overlong index name to be rejected."),
+        Err(err) => format!("{:?}", err),
+    };
+
+    assert!(error.contains("longer than the maximum identifier length"), "{}", error);
+
+    Ok(())
+}
+
+struct LockTimeoutInterceptor {
+    statements: Mutex<Vec<String>>,
+}
+
+impl StatementInterceptor for LockTimeoutInterceptor {
+    fn intercept(&self, statement: String) -> Vec<String> {
+        let statements = vec!["SET lock_timeout='5s'".to_owned(), statement];
+
+        self.statements.lock().unwrap().extend(statements.iter().cloned());
+
+        statements
+    }
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn statement_interceptors_run_before_each_ddl_statement(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+    let expected_schema = api.describe_database().await?;
+
+    api.infer_apply("").send().await?.assert_green()?;
+
+    let interceptor = Arc::new(LockTimeoutInterceptor {
+        statements: Mutex::new(Vec::new()),
+    });
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_statement_interceptor(interceptor.clone());
+
+    let step = SqlMigrationStep::CreateTable(CreateTable {
+        table: expected_schema.table_bang("Cat").clone(),
+    });
+
+    connector.apply_single_step(&step).await?;
+
+    api.assert_schema().await?.assert_equals(&expected_schema)?;
+
+    let statements = interceptor.statements.lock().unwrap();
+
+    assert!(!statements.is_empty());
+
+    for pair in statements.chunks(2) {
+        assert_eq!(pair[0], "SET lock_timeout='5s'");
+        assert!(pair[1].starts_with("CREATE TABLE"), "{}", pair[1]);
+    }
+
+    let lock_timeout = connector.database.query_raw("SHOW lock_timeout", &[]).await?;
+    let lock_timeout = lock_timeout.into_single()?;
+
+    assert_eq!(
+        lock_timeout.get("lock_timeout").and_then(|value| value.to_string()),
+        Some("5s".to_owned())
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn partial_indexes_are_accepted_on_postgres(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let index = Index {
+        name: "Cat.name_not_empty".to_owned(),
+        columns: vec!["name".to_owned()],
+        tpe: IndexType::Unique,
+        is_constraint: false,
+        predicate: Some("name <> ''".to_owned()),
+        storage_parameters: Vec::new(),
+        deferrable: None,
+    };
+
+    let mut schema = api.describe_database().await?;
+    schema
+        .tables
+        .iter_mut()
+        .find(|table| table.name == "Cat")
+        .unwrap()
+        .indices
+        .push(index.clone());
+
+    assert!(api.connector().check_index_compatibility(&schema).is_empty());
+
+    let step = SqlMigrationStep::CreateIndex(CreateIndex {
+        table: "Cat".to_owned(),
+        index,
+    });
+
+    api.connector().apply_single_step(&step).await?;
+
+    let schema = api.describe_database().await?;
+    let index = schema
+        .table_bang("Cat")
+        .indices
+        .iter()
+        .find(|index| index.name == "Cat.name_not_empty")
+        .unwrap();
+
+    assert!(
+        index
+            .predicate
+            .as_deref()
+            .map(|predicate| predicate.contains("name"))
+            .unwrap_or(false),
+        "{:?}",
+        index.predicate
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn array_element_nullability_is_preserved_without_spurious_diffs(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}"."Cat" ( id INTEGER PRIMARY KEY, "favouriteNumbers" INTEGER[] CHECK (array_position("favouriteNumbers", NULL) IS NULL) )"#,
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    let dm = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgres://localhost:5432"
+        }
+
+        model Cat {
+            id               Int   @id
+            favouriteNumbers Int[]
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(
+        schema
+            .table_bang("Cat")
+            .column_bang("favouriteNumbers")
+            .tpe
+            .array_elements_required
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn migrations_applied_to_a_shadow_database_leave_the_real_database_untouched(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let shadow = api.connector().create_shadow_database().await?;
+
+    // The shadow database starts with a copy of the schema.
+    assert!(shadow.describe_table("Cat").await?.is_some());
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let migration = shadow.diff_against_datamodel(&datamodel).await?;
+    shadow.apply_migration(&migration).await?;
+
+    assert!(shadow.describe_table("Dog").await?.is_some());
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.table("Dog").is_err());
+    assert!(schema.table_bang("Cat").column("name").is_none());
+
+    let shadow_database_name = shadow.shadow_database_name().unwrap().to_owned();
+    shadow.drop_shadow_database().await?;
+
+    let remaining = api
+        .database()
+        .query_raw(
+            "SELECT 1 FROM pg_database WHERE datname = $1",
+            &[shadow_database_name.as_str().into()],
+        )
+        .await?;
+
+    assert!(remaining.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn shadow_databases_are_dropped_with_their_connector(api: &TestApi) -> TestResult {
+    let shadow = api.connector().create_shadow_database().await?;
+    let shadow_database_name = shadow.shadow_database_name().unwrap().to_owned();
+
+    drop(shadow);
+
+    let remaining = api
+        .database()
+        .query_raw(
+            "SELECT 1 FROM pg_database WHERE datname = $1",
+            &[shadow_database_name.as_str().into()],
+        )
+        .await?;
+
+    assert!(remaining.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn identity_column_sequence_options_are_round_tripped(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}"."Cat" ( id INTEGER GENERATED BY DEFAULT AS IDENTITY (START WITH 100 INCREMENT BY 5) PRIMARY KEY )"#,
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    let expected_default = Some(DefaultValue::IDENTITY(IdentityOptions {
+        always: false,
+        start: 100,
+        increment: 5,
+    }));
+
+    let dm = r#"
+        model Cat {
+            id Int @id @default(autoincrement())
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // The shadow database is created by rendering the described schema.
+    let shadow = api.connector().create_shadow_database().await?;
+    let table = shadow.describe_table("Cat").await?.unwrap();
+
+    assert_eq!(table.column_bang("id").default, expected_default);
+
+    shadow.drop_shadow_database().await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn indexes_can_be_created_concurrently_outside_of_a_transaction(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@index([name], name: "name_idx")
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_concurrent_index_creation();
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+    let script = connector.render_steps_to_sql(&migration, None)?;
+
+    assert!(script.contains("INDEX CONCURRENTLY"), "{}", script);
+
+    // Postgres rejects `CREATE INDEX CONCURRENTLY` inside a transaction block, so this only
+    // succeeds if the step is applied on its own.
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| index.assert_name("name_idx"))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn concurrent_index_creation_runs_after_the_other_steps_are_committed(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@index([name], name: "name_idx")
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_concurrent_index_creation();
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table.assert_index_on_columns(&["name"], |index| index.assert_name("name_idx"))
+        })?
+        .assert_table("Dog", |table| table.assert_indexes_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn foreign_keys_on_concurrently_created_unique_indexes_are_added_after_the_index(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String @unique
+            dogs Dog[]
+        }
+
+        model Dog {
+            id      Int    @id
+            catName String
+            cat     Cat    @relation(fields: [catName], references: [name])
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_concurrent_index_creation();
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+
+    // The foreign key references the unique index, so it can only be added once the index exists.
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table.assert_index_on_columns(&["name"], |index| index.assert_is_unique())
+        })?
+        .assert_table("Dog", |table| {
+            table.assert_fk_on_columns(&["catName"], |fk| fk.assert_references("Cat", &["name"]))
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn a_failing_concurrent_index_creation_leaves_no_invalid_index(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+    api.insert("Cat")
+        .value("id", 2)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String @unique
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_concurrent_index_creation();
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+
+    assert!(connector.apply_migration(&migration).await.is_err());
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_indexes_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn enums_and_foreign_keys_rendered_with_idempotent_ddl_can_be_applied_twice(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int     @id
+            mood    CatMood
+            ownerId Int
+            owner   Human   @relation(fields: [ownerId], references: [id])
+        }
+
+        model Human {
+            id Int @id
+        }
+
+        enum CatMood {
+            ANGRY
+            HUNGRY
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url()).await?.with_idempotent_ddl();
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+    let script = connector.render_steps_to_sql(&migration, None)?;
+
+    assert!(script.contains("EXCEPTION WHEN duplicate_object"), "{}", script);
+
+    connector.apply_migration(&migration).await?;
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("CatMood", |r#enum| r#enum.assert_values(&["ANGRY", "HUNGRY"]))?
+        .assert_table("Cat", |table| table.assert_foreign_keys_count(1))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unquoted_identifiers_folded_to_lower_case_match_the_datamodel(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}".User (id INTEGER PRIMARY KEY, firstName TEXT NOT NULL)"#,
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("user", |table| table.assert_has_column("firstname"))?;
+
+    let dm = r#"
+        model User {
+            id Int @id
+            firstName String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn storage_parameters_are_introspected_and_round_tripped(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}"."Cat" ( id INTEGER PRIMARY KEY ) WITH (fillfactor = 70)"#,
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    let expected_parameters = vec![("fillfactor".to_owned(), "70".to_owned())];
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(schema.table_bang("Cat").storage_parameters, expected_parameters);
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // The shadow database is created by rendering the described schema.
+    let shadow = api.connector().create_shadow_database().await?;
+    let table = shadow.describe_table("Cat").await?.unwrap();
+
+    assert_eq!(table.storage_parameters, expected_parameters);
+
+    shadow.drop_shadow_database().await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn named_compound_primary_keys_are_created_and_round_tripped(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Blog {
+            id       Int
+            authorId String
+
+            @@id([id, authorId], map: "custom_pk")
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let primary_key = schema.table_bang("Blog").primary_key.as_ref().unwrap();
+
+    assert_eq!(primary_key.columns, &["id", "authorId"]);
+    assert_eq!(primary_key.constraint_name.as_deref(), Some("custom_pk"));
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn renaming_a_compound_primary_key_renames_the_constraint(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Blog {
+            id       Int
+            authorId String
+
+            @@id([id, authorId])
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Blog {
+            id       Int
+            authorId String
+
+            @@id([id, authorId], map: "custom_pk")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let primary_key = schema.table_bang("Blog").primary_key.as_ref().unwrap();
+
+    assert_eq!(primary_key.constraint_name.as_deref(), Some("custom_pk"));
+
+    api.infer_apply(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Removing the name from the datamodel keeps the constraint as it is.
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn nullability_only_changes_are_rendered_as_minimal_alter_column_clauses(api: &TestApi) -> TestResult {
+    let required = r#"
+        model Cat {
+            id   Int    @id
+            name String @default("Felix")
+        }
+    "#;
+
+    let optional = r#"
+        model Cat {
+            id   Int     @id
+            name String? @default("Felix")
+        }
+    "#;
+
+    api.infer_apply(required).send().await?.assert_green()?;
+
+    let connector = api.connector();
+
+    let migration = connector
+        .diff_against_datamodel(&datamodel::parse_datamodel(optional).unwrap())
+        .await?;
+    let rendered = connector.render_steps_detailed(&migration)?;
+    let statements: Vec<&String> = rendered.iter().flat_map(|(_, statements)| statements).collect();
+
+    assert_eq!(
+        statements,
+        &[&format!(
+            "ALTER TABLE \"{}\".\"Cat\" ALTER COLUMN \"name\" DROP NOT NULL;",
+            api.schema_name()
+        )]
+    );
+
+    connector.apply_migration(&migration).await?;
+
+    let migration = connector
+        .diff_against_datamodel(&datamodel::parse_datamodel(required).unwrap())
+        .await?;
+    let rendered = connector.render_steps_detailed(&migration)?;
+    let statements: Vec<&String> = rendered.iter().flat_map(|(_, statements)| statements).collect();
+
+    assert_eq!(
+        statements,
+        &[&format!(
+            "ALTER TABLE \"{}\".\"Cat\" ALTER COLUMN \"name\" SET NOT NULL;",
+            api.schema_name()
+        )]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn deferrable_unique_constraints_are_introspected_and_round_tripped(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{}"."Cat" ( id INTEGER PRIMARY KEY, name TEXT CONSTRAINT "Cat.name" UNIQUE DEFERRABLE INITIALLY DEFERRED )"#,
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(
+        schema.table_bang("Cat").indices[0].deferrable,
+        Some(Deferrable::InitiallyDeferred)
+    );
+
+    let dm = r#"
+        model Cat {
+            id   Int     @id
+            name String? @unique
+        }
+    "#;
+
+    // The datamodel cannot express deferrability, so the constraint is left alone.
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // The shadow database is created by rendering the described schema.
+    let shadow = api.connector().create_shadow_database().await?;
+    let table = shadow.describe_table("Cat").await?.unwrap();
+
+    assert_eq!(table.indices[0].name, "Cat.name");
+    assert_eq!(table.indices[0].deferrable, Some(Deferrable::InitiallyDeferred));
+
+    shadow.drop_shadow_database().await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn scalar_list_defaults_are_created_and_round_tripped(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int      @id
+            ints    Int[]    @default([1, 2, 3])
+            names   String[] @default(["Felix", "Mr. Mistoffelees", "it's"])
+            moods   Mood[]   @default([HUNGRY, CUDDLY])
+            nothing Int[]    @default([])
+        }
+
+        enum Mood {
+            HUNGRY
+            CUDDLY
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_column("ints", |column| {
+                column.assert_default(Some(DefaultValue::LIST(vec![
+                    PrismaValue::Int(1),
+                    PrismaValue::Int(2),
+                    PrismaValue::Int(3),
+                ])))
+            })?
+            .assert_column("names", |column| {
+                column.assert_default(Some(DefaultValue::LIST(vec![
+                    PrismaValue::String("Felix".to_owned()),
+                    PrismaValue::String("Mr. Mistoffelees".to_owned()),
+                    PrismaValue::String("it's".to_owned()),
+                ])))
+            })?
+            .assert_column("moods", |column| {
+                column.assert_default(Some(DefaultValue::LIST(vec![
+                    PrismaValue::Enum("HUNGRY".to_owned()),
+                    PrismaValue::Enum("CUDDLY".to_owned()),
+                ])))
+            })?
+            .assert_column("nothing", |column| {
+                column.assert_default(Some(DefaultValue::LIST(Vec::new())))
+            })
+    })?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
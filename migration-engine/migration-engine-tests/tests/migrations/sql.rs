@@ -1,5 +1,11 @@
+use migration_connector::DatabaseMigrationInferrer;
 use migration_engine_tests::sql::*;
+use sql_migration_connector::{
+    AlterTable, CreateTable, MigrationApplyResult, RenameTable, SetComment, SqlMigration, SqlMigrationConnector,
+    SqlMigrationStep, StatementInterceptor, TableChange,
+};
 use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
 
 #[test_each_connector(tags("sql"))]
 async fn creating_tables_without_primary_key_must_work(api: &TestApi) -> TestResult {
@@ -146,6 +152,66 @@ async fn enum_value_with_database_names_must_work(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_each_connector(capabilities("enums"), tags("sql"))]
+async fn enum_values_mapped_to_invalid_identifiers_are_stored_with_their_database_value(api: &TestApi) -> TestResult {
+    let enum_name = if api.is_mysql() { "Cat_mood" } else { "CatMood" };
+
+    let dm1 = r##"
+        model Cat {
+            id String @id
+            mood CatMood @default(ONE)
+        }
+
+        enum CatMood {
+            ONE @map("1")
+            UNDEFINED
+        }
+    "##;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["1", "UNDEFINED"]))?;
+
+    let insert = quaint::ast::Insert::single_into(api.render_table_name("Cat")).value("id", "the-id");
+    api.database().execute(insert.into()).await?;
+
+    let dm2 = r##"
+        model Cat {
+            id String @id
+            mood CatMood @default(ONE)
+        }
+
+        enum CatMood {
+            ONE @map("1")
+            TWO @map("2")
+            UNDEFINED
+        }
+    "##;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["1", "2", "UNDEFINED"]))?;
+
+    let record = api
+        .database()
+        .query(
+            quaint::ast::Select::from_table(api.render_table_name("Cat"))
+                .column("mood")
+                .into(),
+        )
+        .await?
+        .into_single()?;
+
+    assert_eq!(
+        record.get("mood").and_then(|mood| mood.to_string()).as_deref(),
+        Some("1")
+    );
+
+    Ok(())
+}
+
 #[derive(serde::Deserialize, Debug, PartialEq)]
 struct Cat<'a> {
     id: Cow<'a, str>,
@@ -388,3 +454,800 @@ async fn dropping_mutually_referencing_tables_works(api: &TestApi) -> TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("sql"))]
+async fn rendered_migration_scripts_can_start_with_a_header_comment(api: &TestApi) -> TestResult {
+    let migration = SqlMigration {
+        corrected_steps: vec![SqlMigrationStep::RawSql {
+            raw: "SELECT 1".to_owned(),
+        }],
+        ..SqlMigration::empty()
+    };
+
+    let script = api.connector().render_steps_to_sql(&migration, Some("init"))?;
+    assert_eq!(script, "-- Migration: init\nSELECT 1;\n");
+
+    let script = api.connector().render_steps_to_sql(&migration, None)?;
+    assert_eq!(script, "SELECT 1;\n");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn duplicate_index_definitions_create_a_single_index(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id        Int    @id
+            name      String @unique
+            birthYear Int
+
+            @@unique([name])
+            @@index([name, birthYear])
+            @@index([name, birthYear])
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_indexes_count(2)?
+            .assert_index_on_columns(&["name"], |index| index.assert_is_unique())?
+            .assert_index_on_columns(&["name", "birthYear"], |index| index.assert_is_not_unique())
+    })?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn detailed_rendering_maps_each_step_to_its_statements(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String @unique
+        }
+    "#;
+
+    let empty = datamodel::parse_datamodel("").unwrap();
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = api
+        .connector()
+        .database_migration_inferrer()
+        .infer(&empty, &datamodel, &[])
+        .await?;
+
+    let rendered = api.connector().render_steps_detailed(&migration)?;
+
+    assert_eq!(rendered.len(), migration.corrected_steps.len());
+
+    for ((step, statements), corrected_step) in rendered.iter().zip(migration.corrected_steps.iter()) {
+        assert_eq!(step, corrected_step);
+
+        match step {
+            SqlMigrationStep::CreateTable(_) => {
+                assert_eq!(statements.len(), 1);
+                assert!(statements[0].starts_with("CREATE TABLE"), "{}", statements[0]);
+            }
+            SqlMigrationStep::CreateIndex(_) => {
+                assert_eq!(statements.len(), 1);
+                assert!(statements[0].starts_with("CREATE UNIQUE INDEX"), "{}", statements[0]);
+            }
+            other => panic!("Unexpected step: {:?}", other),
+        }
+    }
+
+    let script = api.connector().render_steps_to_sql(&migration, None)?;
+    let detailed_script: String = rendered
+        .into_iter()
+        .flat_map(|(_, statements)| statements)
+        .map(|statement| format!("{};\n", statement))
+        .collect();
+
+    assert_eq!(script, detailed_script);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn renaming_a_populated_column_keeps_its_data(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String @unique
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let insert = quaint::ast::Insert::single_into(api.render_table_name("Cat"))
+        .value("id", 1)
+        .value("name", "Garfield");
+    api.database().execute(insert.into()).await?;
+
+    api.connector().rename_column("Cat", "name", "fullName").await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_does_not_have_column("name")?
+            .assert_has_column("fullName")?
+            .assert_index_on_columns(&["fullName"], |index| index.assert_is_unique())
+    })?;
+
+    let record = api
+        .database()
+        .query(
+            quaint::ast::Select::from_table(api.render_table_name("Cat"))
+                .column("fullName")
+                .into(),
+        )
+        .await?
+        .into_single()?;
+
+    assert_eq!(
+        record.get("fullName").and_then(|name| name.to_string()).as_deref(),
+        Some("Garfield")
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn steps_applied_one_by_one_produce_the_same_schema_as_a_whole_migration(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+    let expected_schema = api.describe_database().await?;
+
+    api.infer_apply("").send().await?.assert_green()?;
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    for table in &expected_schema.tables {
+        let step = SqlMigrationStep::CreateTable(CreateTable { table: table.clone() });
+
+        api.connector().apply_single_step(&step).await?;
+    }
+
+    api.assert_schema().await?.assert_equals(&expected_schema)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn a_single_table_can_be_described(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String @unique
+        }
+
+        model Dog {
+            id Int @id
+            name String
+            friendId Int
+            friend Cat @relation(fields: [friendId], references: [id])
+
+            @@index([name])
+        }
+
+        model Mouse {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+    let full_schema = api.describe_database().await?;
+
+    let dog = api.connector().describe_table("Dog").await?;
+    assert_eq!(dog.as_ref(), Some(full_schema.table_bang("Dog")));
+
+    let cat = api.connector().describe_table("Cat").await?;
+    assert_eq!(cat.as_ref(), Some(full_schema.table_bang("Cat")));
+
+    assert!(api.connector().describe_table("Bird").await?.is_none());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn applying_an_empty_migration_reports_zero_applied_steps(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = api
+        .connector()
+        .database_migration_inferrer()
+        .infer(&datamodel, &datamodel, &[])
+        .await?;
+
+    assert!(migration.corrected_steps.is_empty());
+
+    let result = api.connector().apply_migration(&migration).await?;
+
+    assert_eq!(result, MigrationApplyResult { steps_applied: 0 });
+    assert!(result.is_noop());
+
+    api.assert_schema().await?.assert_has_table("Cat")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn applying_a_migration_reports_the_number_of_applied_steps(api: &TestApi) -> TestResult {
+    let migration = SqlMigration {
+        corrected_steps: vec![
+            SqlMigrationStep::RawSql {
+                raw: "CREATE TABLE cats (id INTEGER PRIMARY KEY)".to_owned(),
+            },
+            SqlMigrationStep::RawSql {
+                raw: "CREATE TABLE dogs (id INTEGER PRIMARY KEY)".to_owned(),
+            },
+        ],
+        ..SqlMigration::empty()
+    };
+
+    let result = api.connector().apply_migration(&migration).await?;
+
+    assert_eq!(result.steps_applied, 2);
+    assert!(!result.is_noop());
+
+    api.assert_schema()
+        .await?
+        .assert_has_table("cats")?
+        .assert_has_table("dogs")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn unmanaged_tables_are_never_migrated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_unmanaged_tables(vec!["Dog"]);
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id    Int    @id
+            breed String
+        }
+    "#;
+
+    let dm3 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    for dm in &[dm2, dm3] {
+        let datamodel = datamodel::parse_datamodel(dm).unwrap();
+        let migration = connector
+            .database_migration_inferrer()
+            .infer(&datamodel, &datamodel, &[])
+            .await?;
+
+        assert!(migration.corrected_steps.is_empty(), "{:?}", migration.corrected_steps);
+    }
+
+    Ok(())
+}
+
+// On SQLite, migrations are wrapped in raw PRAGMA statements, which are not reversible.
+#[test_each_connector(tags("sql"), ignore("sqlite"))]
+async fn rollback_scripts_are_only_rendered_for_reversible_migrations(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String?
+        }
+    "#;
+
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let add_column = api
+        .connector()
+        .database_migration_inferrer()
+        .infer(&datamodel, &datamodel, &[])
+        .await?;
+
+    let rollback = api
+        .connector()
+        .render_rollback_script(&add_column.corrected_steps)?
+        .expect("Adding a column should be reversible.");
+
+    assert!(
+        rollback.iter().any(|statement| statement.contains("DROP COLUMN")),
+        "{:?}",
+        rollback
+    );
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let datamodel = datamodel::parse_datamodel(dm1).unwrap();
+    let drop_column = api
+        .connector()
+        .database_migration_inferrer()
+        .infer(&datamodel, &datamodel, &[])
+        .await?;
+
+    assert!(api
+        .connector()
+        .render_rollback_script(&drop_column.corrected_steps)?
+        .is_none());
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("transactional_ddl"))]
+async fn ddl_statements_in_a_rolled_back_transaction_are_undone(api: &TestApi) -> TestResult {
+    let create_table = format!(r#"CREATE TABLE "{}"."Cat" (id INTEGER PRIMARY KEY)"#, api.schema_name());
+
+    api.database().raw_cmd("BEGIN").await?;
+    api.database().raw_cmd(&create_table).await?;
+    api.database().raw_cmd("ROLLBACK").await?;
+
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    Ok(())
+}
+
+// MySQL has no `CREATE INDEX IF NOT EXISTS`.
+#[test_each_connector(tags("sql"), ignore("mysql"))]
+async fn migrations_rendered_with_idempotent_ddl_can_be_applied_twice(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String @unique
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url()).await?.with_idempotent_ddl();
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = connector
+        .database_migration_inferrer()
+        .infer(&datamodel, &datamodel, &[])
+        .await?;
+
+    let script = connector.render_steps_to_sql(&migration, None)?;
+
+    assert!(script.contains("CREATE TABLE IF NOT EXISTS"), "{}", script);
+    assert!(script.contains("INDEX IF NOT EXISTS"), "{}", script);
+
+    connector.apply_migration(&migration).await?;
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| index.assert_is_unique())
+    })?;
+
+    Ok(())
+}
+
+// SQLite cannot drop columns.
+#[test_each_connector(tags("sql"), ignore("sqlite"))]
+async fn columns_dropped_by_hand_are_added_back_when_diffing_against_the_datamodel(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let drop_column = if api.is_mysql() {
+        format!("ALTER TABLE `{}`.`Cat` DROP COLUMN `name`", api.schema_name())
+    } else {
+        format!(r#"ALTER TABLE "{}"."Cat" DROP COLUMN "name""#, api.schema_name())
+    };
+
+    api.database().raw_cmd(&drop_column).await?;
+
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = api.connector().diff_against_datamodel(&datamodel).await?;
+
+    match migration.corrected_steps.as_slice() {
+        [SqlMigrationStep::AlterTable(AlterTable { table, changes })] => {
+            assert_eq!(table.name, "Cat");
+
+            match changes.as_slice() {
+                [TableChange::AddColumn(add_column)] => assert_eq!(add_column.column.name, "name"),
+                other => panic!("Expected a single AddColumn change, got {:?}", other),
+            }
+        }
+        other => panic!("Expected a single AlterTable step, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+// SQLite has no table comments.
+#[test_each_connector(tags("sql"), ignore("sqlite"))]
+async fn changing_the_documentation_of_a_model_changes_the_table_comment(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        /// A cat.
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let table = api.describe_database().await?.table_bang("Cat").clone();
+    assert_eq!(table.description.as_deref(), Some("A cat."));
+
+    let dm2 = r#"
+        /// A feline.
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let migration = api.connector().diff_against_datamodel(&datamodel).await?;
+
+    match migration.corrected_steps.as_slice() {
+        [SqlMigrationStep::AlterTable(AlterTable { changes, .. })] => assert_eq!(
+            changes.as_slice(),
+            &[TableChange::SetComment(SetComment {
+                comment: "A feline.".to_owned()
+            })]
+        ),
+        other => panic!("Expected a single AlterTable step, got {:?}", other),
+    }
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let table = api.describe_database().await?.table_bang("Cat").clone();
+    assert_eq!(table.description.as_deref(), Some("A feline."));
+
+    Ok(())
+}
+
+// SQLite has no table comments.
+#[test_each_connector(tags("sql"), ignore("sqlite"))]
+async fn table_comments_with_backslashes_and_quotes_round_trip(api: &TestApi) -> TestResult {
+    let dm = r#"
+        /// The cat's home is in C:\cats.
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let table = api.describe_database().await?.table_bang("Cat").clone();
+    assert_eq!(table.description.as_deref(), Some(r"The cat's home is in C:\cats."));
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+// SQLite has no table comments.
+#[test_each_connector(tags("sql"), ignore("sqlite"))]
+async fn documentation_generated_by_introspection_is_not_a_table_comment(api: &TestApi) -> TestResult {
+    let dm = r#"
+        /// A cat.
+        /// The index Cat.name is a fulltext index in the database. This is not supported by Prisma yet, it is represented as a normal index.
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@index([name])
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let table = api.describe_database().await?.table_bang("Cat").clone();
+    assert_eq!(table.description.as_deref(), Some("A cat."));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn is_empty_is_true_until_a_table_is_created(api: &TestApi) -> TestResult {
+    assert!(api.connector().is_empty().await?);
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    assert!(!api.connector().is_empty().await?);
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct RecordingInterceptor {
+    statements: Mutex<Vec<String>>,
+}
+
+impl StatementInterceptor for RecordingInterceptor {
+    fn intercept(&self, statement: String) -> Vec<String> {
+        self.statements.lock().unwrap().push(statement.clone());
+
+        vec![statement]
+    }
+}
+
+#[test_each_connector(tags("sql"))]
+async fn foreign_key_checks_can_be_disabled_while_applying(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let interceptor = Arc::new(RecordingInterceptor::default());
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_foreign_key_checks_disabled()
+        .with_statement_interceptor(interceptor.clone());
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema().await?.assert_has_table("Cat")?;
+
+    let (disable, enable) = if api.is_sqlite() {
+        ("PRAGMA foreign_keys=OFF", "PRAGMA foreign_keys=ON")
+    } else if api.is_mysql() {
+        ("SET FOREIGN_KEY_CHECKS=0", "SET FOREIGN_KEY_CHECKS=1")
+    } else {
+        ("SET CONSTRAINTS ALL DEFERRED", "SET CONSTRAINTS ALL IMMEDIATE")
+    };
+
+    let statements = interceptor.statements.lock().unwrap();
+
+    assert_eq!(statements.first().map(String::as_str), Some(disable));
+    assert_eq!(statements.last().map(String::as_str), Some(enable));
+    assert!(statements.iter().any(|statement| statement.starts_with("CREATE TABLE")));
+
+    Ok(())
+}
+
+struct FailingInterceptor;
+
+impl StatementInterceptor for FailingInterceptor {
+    fn intercept(&self, statement: String) -> Vec<String> {
+        if statement.starts_with("CREATE TABLE") {
+            vec!["SELECT * FROM this_table_does_not_exist".to_owned()]
+        } else {
+            vec![statement]
+        }
+    }
+}
+
+#[test_each_connector(tags("mysql", "sqlite"))]
+async fn foreign_key_checks_are_turned_back_on_when_a_step_fails(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_foreign_key_checks_disabled()
+        .with_statement_interceptor(Arc::new(FailingInterceptor));
+    let datamodel = datamodel::parse_datamodel(dm).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+
+    assert!(connector.apply_migration(&migration).await.is_err());
+
+    let query = if api.is_sqlite() {
+        "PRAGMA foreign_keys"
+    } else {
+        "SELECT @@FOREIGN_KEY_CHECKS AS foreign_keys"
+    };
+
+    let row = connector.database.query_raw(query, &[]).await?.into_single()?;
+
+    assert_eq!(row.get("foreign_keys").and_then(|value| value.as_i64()), Some(1));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn sqlite_table_redefinitions_do_not_toggle_foreign_keys_when_foreign_key_checks_are_disabled(
+    api: &TestApi,
+) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let interceptor = Arc::new(RecordingInterceptor::default());
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_foreign_key_checks_disabled()
+        .with_statement_interceptor(interceptor.clone());
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    let statements = interceptor.statements.lock().unwrap();
+    let pragmas: Vec<&str> = statements
+        .iter()
+        .map(|statement| statement.trim_end_matches(';'))
+        .filter(|statement| statement.starts_with("PRAGMA foreign_keys"))
+        .collect();
+
+    assert_eq!(pragmas, &["PRAGMA foreign_keys=OFF", "PRAGMA foreign_keys=ON"]);
+    assert_eq!(statements.first().map(String::as_str), Some("PRAGMA foreign_keys=OFF"));
+    assert_eq!(statements.last().map(String::as_str), Some("PRAGMA foreign_keys=ON"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn tables_with_the_same_structure_are_renamed_when_table_rename_detection_is_on(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Dog {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let connector = SqlMigrationConnector::new(api.url())
+        .await?
+        .with_table_rename_detection();
+    let datamodel = datamodel::parse_datamodel(dm2).unwrap();
+    let migration = connector.diff_against_datamodel(&datamodel).await?;
+
+    match migration.original_steps.as_slice() {
+        [SqlMigrationStep::RenameTable(RenameTable { name, new_name })] => {
+            assert_eq!(name, "Cat");
+            assert_eq!(new_name, "Dog");
+        }
+        other => panic!("Expected a single RenameTable step, got {:?}", other),
+    }
+
+    connector.apply_migration(&migration).await?;
+
+    api.assert_schema().await?.assert_has_table("Dog")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8", "sqlite"))]
+async fn dbgenerated_expressions_are_rendered_as_defaults(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String @default(dbgenerated("lower('ANONYMOUS')"))
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.insert("Cat").value("id", 1).result_raw().await?;
+
+    let row = api.dump_table("Cat").await?.into_single()?;
+
+    assert_eq!(
+        row.get("name").and_then(|name| name.to_string()).as_deref(),
+        Some("anonymous")
+    );
+
+    Ok(())
+}
+
+// MySQL commits implicitly after each DDL statement, so its migrations are applied step by step.
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn an_interrupted_migration_leaves_the_schema_unchanged(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    for id in &["felix", "garfield"] {
+        let insert = quaint::ast::Insert::single_into(api.render_table_name("Cat"))
+            .value("id", *id)
+            .value("name", "Tom");
+
+        api.database().execute(insert.into()).await?;
+    }
+
+    let schema_before = api.describe_database().await?;
+
+    // Creating the Dog table succeeds, but the unique index on the duplicate names makes the
+    // migration fail afterwards.
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String @unique
+        }
+
+        model Dog {
+            id String @id
+        }
+    "#;
+
+    assert!(api.infer_apply(dm2).send().await.is_err());
+
+    let schema_after = api.describe_database().await?;
+
+    assert_eq!(schema_before, schema_after);
+
+    Ok(())
+}
@@ -73,3 +73,120 @@ async fn sqlite_must_recreate_multi_field_indexes(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn dropping_a_middle_column_preserves_the_data_in_the_other_columns(api: &TestApi) -> TestResult {
+    // SQLite cannot drop columns, so the table is rebuilt: created under a temporary name, filled from the old
+    // table, and renamed after the old table is dropped.
+    let dm1 = r#"
+        model Cat {
+            id      Int    @id
+            name    String @unique
+            age     Int
+            ownerId Int
+            owner   Owner  @relation(fields: [ownerId], references: [id])
+        }
+
+        model Owner {
+            id   Int @id
+            cats Cat[]
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Owner").value("id", 1).result_raw().await?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .value("age", 7)
+        .value("ownerId", 1)
+        .result_raw()
+        .await?;
+    api.insert("Cat")
+        .value("id", 2)
+        .value("name", "Garfield")
+        .value("age", 42)
+        .value("ownerId", 1)
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int    @id
+            name    String @unique
+            ownerId Int
+            owner   Owner  @relation(fields: [ownerId], references: [id])
+        }
+
+        model Owner {
+            id   Int @id
+            cats Cat[]
+        }
+    "#;
+
+    api.infer_apply(&dm2).force(Some(true)).send().await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_columns_count(3)?
+            .assert_does_not_have_column("age")?
+            .assert_index_on_columns(&["name"], |idx| idx.assert_is_unique())?
+            .assert_fk_on_columns(&["ownerId"], |fk| fk.assert_references("Owner", &["id"]))
+    })?;
+
+    let cats = api.dump_table("Cat").await?;
+    let cats: Vec<String> = cats
+        .into_iter()
+        .map(|row| format!("{:?} {:?} {:?}", row.get("id"), row.get("name"), row.get("ownerId")))
+        .collect();
+
+    assert_eq!(
+        cats,
+        &[
+            r#"Some(Integer(Some(1))) Some(Text(Some("Felix"))) Some(Integer(Some(1)))"#,
+            r#"Some(Integer(Some(2))) Some(Text(Some("Garfield"))) Some(Integer(Some(1)))"#,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn the_autoincrement_keyword_is_preserved_on_round_trip(api: &TestApi) -> TestResult {
+    use sql_migration_connector::{CreateTable, SqlMigration, SqlMigrationStep};
+
+    api.database()
+        .raw_cmd(&format!(
+            r#"CREATE TABLE "{}"."with_keyword" (id INTEGER PRIMARY KEY AUTOINCREMENT)"#,
+            api.schema_name()
+        ))
+        .await?;
+    api.database()
+        .raw_cmd(&format!(
+            r#"CREATE TABLE "{}"."rowid_alias" (id INTEGER PRIMARY KEY)"#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let schema = api.describe_database().await?;
+
+    for (table_name, has_keyword) in &[("with_keyword", true), ("rowid_alias", false)] {
+        let table = schema.get_table(table_name).unwrap();
+
+        assert!(table.columns[0].auto_increment);
+        assert_eq!(table.primary_key.as_ref().unwrap().autoincrement, *has_keyword);
+
+        let migration = SqlMigration {
+            after: schema.clone(),
+            corrected_steps: vec![SqlMigrationStep::CreateTable(CreateTable { table: table.clone() })],
+            ..SqlMigration::empty()
+        };
+        let script = api.connector().render_steps_to_sql(&migration, None)?;
+
+        assert!(script.contains("PRIMARY KEY"), "{}", script);
+        assert_eq!(script.contains("AUTOINCREMENT"), *has_keyword, "{}", script);
+    }
+
+    Ok(())
+}
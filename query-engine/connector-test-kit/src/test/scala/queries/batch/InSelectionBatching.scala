@@ -123,4 +123,36 @@ class InSelectionBatching extends FlatSpec with Matchers with ApiSpecBase {
       """{"data":{"findManyA":[{"id":5},{"id":4},{"id":3},{"id":2},{"id":1}]}}""".stripMargin
     )
   }
+
+  "a negative take on batched IN queries" should "return the last records of the ordering in the requested order" in {
+    val res = server.query(
+      """query idInTest {
+        |   findManyA(where: { id_in: [5,4,3,2,1,1,1,2,3,4,5,6,7,6,5,4,3,2,1,2,3,4,5,6] }, take: -3, orderBy: id_ASC) { id }
+        |}
+        |""".stripMargin,
+      project = project,
+      legacy = false,
+      batchSize = 2,
+    )
+
+    res.toString should be(
+      """{"data":{"findManyA":[{"id":3},{"id":4},{"id":5}]}}""".stripMargin
+    )
+  }
+
+  "a negative take and a skip on batched IN queries" should "skip from the end of the ordering" in {
+    val res = server.query(
+      """query idInTest {
+        |   findManyA(where: { id_in: [5,4,3,2,1,1,1,2,3,4,5,6,7,6,5,4,3,2,1,2,3,4,5,6] }, take: -2, skip: 1, orderBy: id_DESC) { id }
+        |}
+        |""".stripMargin,
+      project = project,
+      legacy = false,
+      batchSize = 2,
+    )
+
+    res.toString should be(
+      """{"data":{"findManyA":[{"id":3},{"id":2}]}}""".stripMargin
+    )
+  }
 }
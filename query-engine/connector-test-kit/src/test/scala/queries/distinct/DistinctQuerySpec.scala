@@ -100,6 +100,27 @@ class DistinctQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.toString() should be("""{"data":{"findManyModelA":[{"fieldA":"2","fieldB":2}]}}""")
   }
 
+  "Select distinct with take" should "return the requested number of distinct records" in {
+    createRecord("1", 1)
+    createRecord("1", 1)
+    createRecord("1", 1)
+    createRecord("2", 2)
+    createRecord("3", 3)
+
+    val result = server.query(
+      s"""{
+         |  findManyModelA(distinct: [fieldA, fieldB], take: 2) {
+         |    fieldA
+         |    fieldB
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyModelA":[{"fieldA":"1","fieldB":1},{"fieldA":"2","fieldB":2}]}}""")
+  }
+
   "Select distinct with skip and ordering" should "return only distinct records after the skip, ordered correctly" in {
     createRecord("1", 1)
     createRecord("2", 2)
@@ -0,0 +1,29 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.PostgresConnectorTag
+import util._
+
+class JsonFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  // Json fields are stored as jsonb on Postgres, which compares values semantically.
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag)
+
+  val project: Project = ProjectDsl.fromString { """
+                                                   |model Model {
+                                                   |  id    Int  @id
+                                                   |  field Json
+                                                   |}""".stripMargin }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  "A Json equality filter" should "match semantically equal values with a different key order" in {
+    server.query("""mutation { createModel(data: { id: 1, field: "{\"a\": 1, \"b\": [1, 2]}" }) { id } }""", project)
+
+    val res = server.query("""query { models(where: { field: "{\"b\": [1, 2], \"a\": 1}" }) { id } }""", project)
+
+    res.toString should be("""{"data":{"models":[{"id":1}]}}""")
+  }
+}
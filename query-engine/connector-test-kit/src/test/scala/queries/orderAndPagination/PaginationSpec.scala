@@ -265,6 +265,23 @@ class PaginationSpec extends FlatSpec with Matchers with ApiSpecBase {
     data.toString() should be("""{"data":{"findManyTestModel":[{"id":10}]}}""")
   }
 
+  "Taking -3 with an ascending order" should "return the last three records in ascending order" in {
+    val data = server
+      .query(
+        """
+          |query {
+          |  findManyTestModel(take: -3, orderBy: id_ASC) {
+          |    id
+          |  }
+          |}
+        """,
+        project,
+        legacy = false
+      )
+
+    data.toString() should be("""{"data":{"findManyTestModel":[{"id":8},{"id":9},{"id":10}]}}""")
+  }
+
   /*********************
     * Skip only tests. *
     ********************/
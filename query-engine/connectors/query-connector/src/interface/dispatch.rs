@@ -1,5 +1,6 @@
 use super::*;
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use prisma_value::PrismaValue;
 
 #[async_trait]
@@ -50,6 +51,18 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
             Self::Transaction(tx) => tx.aggregate_records(model, aggregators, query_arguments).await,
         }
     }
+
+    fn export_stream<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'a ModelProjection,
+    ) -> BoxStream<'a, crate::Result<Record>> {
+        match self {
+            Self::Connection(c) => c.export_stream(model, query_arguments, selected_fields),
+            Self::Transaction(tx) => tx.export_stream(model, query_arguments, selected_fields),
+        }
+    }
 }
 
 #[async_trait]
@@ -111,10 +124,17 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
-    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<usize> {
+    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<RawExecution> {
         match self {
             Self::Connection(c) => c.execute_raw(query, parameters).await,
             Self::Transaction(tx) => tx.execute_raw(query, parameters).await,
         }
     }
+
+    async fn upsert_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<UpsertOutcome> {
+        match self {
+            Self::Connection(c) => c.upsert_raw(query, parameters).await,
+            Self::Transaction(tx) => tx.upsert_raw(query, parameters).await,
+        }
+    }
 }
@@ -5,6 +5,7 @@ pub use dispatch::*;
 use crate::{Filter, QueryArguments, WriteArgs};
 use async_trait::async_trait;
 use dml::FieldArity;
+use futures::stream::BoxStream;
 use prisma_models::*;
 use prisma_value::PrismaValue;
 
@@ -16,6 +17,21 @@ pub trait Connector {
 #[async_trait]
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// Start a transaction running with the given isolation level instead of the database default.
+    async fn start_transaction_with_isolation_level<'a>(
+        &'a self,
+        isolation_level: IsolationLevel,
+    ) -> crate::Result<Box<dyn Transaction + 'a>>;
+}
+
+/// The transaction isolation levels defined by the SQL standard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
 }
 
 #[async_trait]
@@ -80,6 +96,24 @@ impl From<RecordProjection> for RecordFilter {
     }
 }
 
+/// The outcome of executing a raw statement.
+#[derive(Debug, Clone)]
+pub struct RawExecution {
+    /// The number of affected rows.
+    pub count: usize,
+    /// The rows returned by a `RETURNING` or `OUTPUT` clause, as JSON.
+    pub rows: Option<serde_json::Value>,
+}
+
+/// Whether a raw upsert statement inserted a new row or hit an existing one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpsertOutcome {
+    Inserted,
+    /// The statement hit an existing row. The row is not necessarily changed, e.g. with
+    /// `ON CONFLICT DO NOTHING` or when the new values equal the old ones.
+    Updated,
+}
+
 #[derive(Debug, Clone)]
 pub enum Aggregator {
     /// Counts all records of the model that match the query.
@@ -189,6 +223,19 @@ pub trait ReadOperations {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> crate::Result<Vec<AggregationResult>>;
+
+    /// Streams the records matching the query arguments, for exports too
+    /// large to be held in memory at once. Batchable filters are split like
+    /// in `get_many_records`, and the batches are read one after another.
+    ///
+    /// Records are ordered within a batch, and batches follow the order of
+    /// the values of the batched filter.
+    fn export_stream<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'a ModelProjection,
+    ) -> BoxStream<'a, crate::Result<Record>>;
 }
 
 #[async_trait]
@@ -229,8 +276,15 @@ pub trait WriteOperations {
     /// Execute the raw query in the database as-is. The `parameters` are
     /// parameterized values for databases that support prepared statements.
     ///
-    /// Returns the number of rows affected.
-    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<usize>;
+    /// Returns the number of rows affected, and the returned rows if the
+    /// statement has a `RETURNING` or `OUTPUT` clause.
+    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<RawExecution>;
+
+    /// Execute a raw upsert statement (`INSERT ... ON CONFLICT` on Postgres,
+    /// `INSERT ... ON DUPLICATE KEY UPDATE` on MySQL), reporting whether a row
+    /// was inserted or an existing row was hit. On Postgres, the statement must
+    /// not have a `RETURNING` clause.
+    async fn upsert_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<UpsertOutcome>;
 
     /// Execute the raw query in the database as-is. The `parameters` are
     /// parameterized values for databases that support prepared statements.
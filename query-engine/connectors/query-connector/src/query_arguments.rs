@@ -10,7 +10,8 @@ pub struct SkipAndLimit {
 /// `QueryArguments` define various constraints queried data should fulfill:
 /// - `cursor`, `take`, `skip` page through the data.
 /// - `filter` scopes the data by defining conditions (akin to `WHERE` in SQL).
-/// - `order_by` defines the ordering of records.
+/// - `order_by` defines the ordering of records, by the first field, then the second one, and so on.
+/// - `order_by_aggregate` orders records by an aggregation over one of their relations.
 /// - `distinct` designates the fields on which the records should be distinct.
 /// - The `ignore_*` flags are a temporary bandaid to tell the connector to do not
 ///   include certain constraints when building queries, because the core is already
@@ -21,7 +22,8 @@ pub struct QueryArguments {
     pub take: Option<i64>,
     pub skip: Option<i64>,
     pub filter: Option<Filter>,
-    pub order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
+    pub order_by_aggregate: Option<OrderByAggregate>,
     pub distinct: Option<ModelProjection>,
     pub ignore_skip: bool,
     pub ignore_take: bool,
@@ -34,23 +36,35 @@ impl QueryArguments {
     }
 
     fn needs_implicit_ordering(&self) -> bool {
-        self.skip.is_some() || self.cursor.is_some() || self.take.is_some() || self.order_by.is_some()
+        self.skip.is_some()
+            || self.cursor.is_some()
+            || self.take.is_some()
+            || !self.order_by.is_empty()
+            || self.order_by_aggregate.is_some()
     }
 
     pub fn ordering_directions(&self) -> OrderDirections {
         OrderDirections {
             needs_to_be_reverse_order: self.needs_reversed_order(),
             needs_implicit_id_ordering: self.needs_implicit_ordering(),
-            primary_order_by: self.order_by.clone(),
+            order_by: self.order_by.clone(),
+            aggregate_order_by: self.order_by_aggregate.clone(),
         }
     }
 
+    /// Whether the arguments select no records, regardless of the data: `take: 0` is the only case
+    /// that can be determined without querying. Connectors can return an empty result right away.
+    pub fn is_definitely_empty(&self) -> bool {
+        self.take == Some(0)
+    }
+
     pub fn take_abs(&self) -> Option<i64> {
         self.take.clone().map(|t| if t < 0 { t * -1 } else { t })
     }
 
+    /// Aggregate orderings can't be applied in-memory after batching.
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+        self.order_by_aggregate.is_none() && self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -60,6 +74,7 @@ impl QueryArguments {
                 let take = self.take;
                 let skip = self.skip;
                 let order_by = self.order_by;
+                let order_by_aggregate = self.order_by_aggregate;
                 let distinct = self.distinct;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
@@ -73,6 +88,7 @@ impl QueryArguments {
                         skip: skip.clone(),
                         filter: Some(filter),
                         order_by: order_by.clone(),
+                        order_by_aggregate: order_by_aggregate.clone(),
                         distinct: distinct.clone(),
                         ignore_skip,
                         ignore_take,
@@ -98,5 +114,34 @@ where
 pub struct OrderDirections {
     pub needs_implicit_id_ordering: bool,
     pub needs_to_be_reverse_order: bool,
-    pub primary_order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
+    pub aggregate_order_by: Option<OrderByAggregate>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taking_zero_records_is_definitely_empty() {
+        let args = QueryArguments {
+            take: Some(0),
+            skip: Some(10),
+            ..Default::default()
+        };
+
+        assert!(args.is_definitely_empty());
+    }
+
+    #[test]
+    fn paging_arguments_are_not_definitely_empty() {
+        let args = QueryArguments {
+            take: Some(-10),
+            skip: Some(10),
+            ..Default::default()
+        };
+
+        assert!(!args.is_definitely_empty());
+        assert!(!QueryArguments::default().is_definitely_empty());
+    }
 }
@@ -1,62 +1,133 @@
+use crate::ordering::Ordering;
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
 
+/// Builds the condition selecting the records at or after the cursor in the ordering. With several
+/// orderings, a record comes after the cursor if its first key that differs from the cursor's is
+/// after it. Records with equal keys are ordered by the cursor fields. An aggregate ordering is
+/// compared through the same correlated subquery the ordering uses.
 pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree<'static> {
-    match (query_arguments.cursor.as_ref(), query_arguments.order_by.as_ref()) {
-        (None, _) => ConditionTree::NoCondition,
-        (Some(cursor), order_by) => {
-            // If there's a sort order defined for the cursor, take that one, else implicitly order by ID.
-            let (comparison_fields, sort_order) = match order_by {
-                Some(x) => (vec![x.field.clone()], x.sort_order),
-                None => (
-                    model.primary_identifier().scalar_fields().collect(),
-                    SortOrder::Ascending,
-                ),
-            };
+    let cursor = match query_arguments.cursor.as_ref() {
+        None => return ConditionTree::NoCondition,
+        Some(cursor) => cursor,
+    };
+
+    // The aggregate ordering comes first, then the field orderings. Without any, implicitly order by ID.
+    let mut keys: Vec<(Vec<Expression<'static>>, SortOrder)> = query_arguments
+        .order_by_aggregate
+        .iter()
+        .map(|order_by| (vec![Ordering::aggregate_expression(order_by)], order_by.sort_order))
+        .chain(
+            query_arguments
+                .order_by
+                .iter()
+                .map(|order_by| (vec![order_by.field.as_column().into()], order_by.sort_order)),
+        )
+        .collect();
+
+    if keys.is_empty() {
+        let comparison_fields: Vec<_> = model.primary_identifier().scalar_fields().collect();
+        let columns = comparison_fields.as_columns().map(Expression::from).collect();
+
+        keys.push((columns, SortOrder::Ascending));
+    }
+
+    let fields: Vec<_> = cursor.fields().collect();
+    let values: Vec<_> = cursor.values().collect();
+
+    let cursor_columns: Vec<_> = fields.as_slice().as_columns().collect();
+    let cursor_row = Row::from(cursor_columns);
+
+    let where_condition = cursor_row.clone().equals(values.clone());
+
+    // A negative `take` value signifies that values should be taken before the cursor, requiring a different ordering.
+    let before_cursor = query_arguments.take.map(|t| t < 0).unwrap_or(false);
+
+    let cursor_compare: ConditionTree<'static> = if before_cursor {
+        cursor_row.less_than_or_equals(values).into()
+    } else {
+        cursor_row.greater_than_or_equals(values).into()
+    };
+
+    let compare = keys
+        .into_iter()
+        .rev()
+        .fold(cursor_compare, |ties, (expressions, sort_order)| {
+            let order_row = Row::from(expressions.clone());
 
-            let columns: Vec<_> = comparison_fields.as_columns().collect();
-            let order_row = Row::from(columns.clone());
-            let fields: Vec<_> = cursor.fields().collect();
-            let values: Vec<_> = cursor.values().collect();
-
-            let cursor_columns: Vec<_> = fields.as_slice().as_columns().collect();
-            let cursor_row = Row::from(cursor_columns);
-
-            let where_condition = cursor_row.clone().equals(values.clone());
-
-            let select_query = Select::from_table(model.as_table())
-                .columns(columns.clone())
-                .so_that(where_condition);
-
-            // A negative `take` value signifies that values should be taken before the cursor, requiring a different ordering.
-            let compare = match (query_arguments.take, sort_order) {
-                (Some(t), SortOrder::Ascending) if t < 0 => order_row
-                    .clone()
-                    .equals(select_query.clone())
-                    .and(cursor_row.clone().less_than_or_equals(values))
-                    .or(order_row.less_than(select_query)),
-
-                (Some(t), SortOrder::Descending) if t < 0 => order_row
-                    .clone()
-                    .equals(select_query.clone())
-                    .and(cursor_row.clone().less_than_or_equals(values))
-                    .or(order_row.greater_than(select_query)),
-
-                (_, SortOrder::Ascending) => order_row
-                    .clone()
-                    .equals(select_query.clone())
-                    .and(cursor_row.clone().greater_than_or_equals(values))
-                    .or(order_row.greater_than(select_query)),
-
-                (_, SortOrder::Descending) => order_row
-                    .clone()
-                    .equals(select_query.clone())
-                    .and(cursor_row.clone().greater_than_or_equals(values))
-                    .or(order_row.less_than(select_query)),
+            let select_query = expressions
+                .into_iter()
+                .fold(Select::from_table(model.as_table()), |select, expression| {
+                    select.value(expression)
+                })
+                .so_that(where_condition.clone());
+
+            let past_cursor = match (sort_order, before_cursor) {
+                (SortOrder::Ascending, true) | (SortOrder::Descending, false) => {
+                    order_row.clone().less_than(select_query.clone())
+                }
+                (SortOrder::Descending, true) | (SortOrder::Ascending, false) => {
+                    order_row.clone().greater_than(select_query.clone())
+                }
             };
 
-            ConditionTree::single(compare)
-        }
+            order_row.equals(select_query).and(ties).or(past_cursor)
+        });
+
+    ConditionTree::single(compare)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::visitor::{Sqlite, Visitor};
+
+    #[test]
+    fn cursor_condition_compares_the_relation_count_before_the_fields() {
+        let datamodel = r#"
+            model User {
+                id    Int    @id
+                name  String
+                posts Post[]
+            }
+
+            model Post {
+                id       Int  @id
+                authorId Int
+                author   User @relation(fields: [authorId], references: [id])
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let user = internal_data_model.find_model("User").unwrap();
+        let posts = user.fields().find_from_relation_fields("posts").unwrap();
+        let name = user.fields().find_from_scalar("name").unwrap();
+        let id = user.fields().find_from_scalar("id").unwrap();
+
+        let query_arguments = QueryArguments {
+            cursor: Some(RecordProjection::from((id, PrismaValue::Int(1)))),
+            order_by: vec![OrderBy {
+                field: name,
+                sort_order: SortOrder::Ascending,
+            }],
+            order_by_aggregate: Some(OrderByAggregate {
+                field: posts,
+                aggregation: AggregationKind::Count,
+                sort_order: SortOrder::Descending,
+            }),
+            ..Default::default()
+        };
+
+        let condition = build(&query_arguments, user.clone());
+        let select = Select::from_table(user.as_table()).column("id").so_that(condition);
+        let (sql, _) = Sqlite::build(select).unwrap();
+
+        let count_position = sql.find("(SELECT COUNT(*) FROM `test`.`Post`").unwrap();
+        let name_position = sql.find("`test`.`User`.`name`").unwrap();
+
+        assert!(count_position < name_position);
+        assert!(sql.contains("SELECT (SELECT COUNT(*) FROM `test`.`Post` AS `orderby_aggregate`"));
+        assert!(sql.contains("SELECT `test`.`User`.`name` FROM `test`.`User` WHERE"));
     }
 }
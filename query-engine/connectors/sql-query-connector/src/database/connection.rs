@@ -2,12 +2,16 @@ use super::transaction::SqlConnectorTransaction;
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationResult, Aggregator, Connection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationResult, Aggregator, Connection, IsolationLevel, QueryArguments,
+    RawExecution, ReadOperations, RecordFilter, Transaction, UpsertOutcome, WriteArgs, WriteOperations,
 };
+use futures::stream::{BoxStream, StreamExt};
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
-use quaint::{connector::TransactionCapable, prelude::ConnectionInfo};
+use quaint::{
+    connector::{Queryable, SqlFamily, TransactionCapable},
+    prelude::ConnectionInfo,
+};
 
 pub struct SqlConnection<C> {
     inner: C,
@@ -48,6 +52,48 @@ where
         })
         .await
     }
+
+    async fn start_transaction_with_isolation_level<'a>(
+        &'a self,
+        isolation_level: IsolationLevel,
+    ) -> connector::Result<Box<dyn Transaction + 'a>> {
+        let connection_info = &self.connection_info;
+        let set_isolation_level = format!(
+            "SET TRANSACTION ISOLATION LEVEL {}",
+            render_isolation_level(isolation_level)
+        );
+
+        self.catch(async move {
+            let tx: quaint::connector::Transaction = match connection_info.sql_family() {
+                // Postgres only accepts the isolation level as the first statement inside the transaction.
+                SqlFamily::Postgres => {
+                    let tx = self.inner.start_transaction().await?;
+                    tx.raw_cmd(&set_isolation_level).await?;
+                    tx
+                }
+                // MySQL and SQL Server apply the isolation level to the next transaction, and refuse to change it
+                // while one is in progress.
+                SqlFamily::Mysql | SqlFamily::Mssql => {
+                    self.inner.raw_cmd(&set_isolation_level).await?;
+                    self.inner.start_transaction().await?
+                }
+                // SQLite transactions are always serializable.
+                SqlFamily::Sqlite => self.inner.start_transaction().await?,
+            };
+
+            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+        })
+        .await
+    }
+}
+
+fn render_isolation_level(isolation_level: IsolationLevel) -> &'static str {
+    match isolation_level {
+        IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+        IsolationLevel::ReadCommitted => "READ COMMITTED",
+        IsolationLevel::RepeatableRead => "REPEATABLE READ",
+        IsolationLevel::Serializable => "SERIALIZABLE",
+    }
 }
 
 #[async_trait]
@@ -93,6 +139,17 @@ where
         self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
             .await
     }
+
+    fn export_stream<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'a ModelProjection,
+    ) -> BoxStream<'a, connector::Result<Record>> {
+        read::export_stream(&self.inner, model, query_arguments, selected_fields)
+            .map(move |result| result.map_err(|err| err.into_connector_error(&self.connection_info)))
+            .boxed()
+    }
 }
 
 #[async_trait]
@@ -140,8 +197,17 @@ where
             .await
     }
 
-    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
+    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<RawExecution> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::execute_raw(&self.inner, sql_family, query, parameters).await })
+            .await
+    }
+
+    async fn upsert_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<UpsertOutcome> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::upsert_raw(&self.inner, sql_family, query, parameters).await })
             .await
     }
 
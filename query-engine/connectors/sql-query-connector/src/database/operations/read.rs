@@ -3,9 +3,13 @@ use crate::{
     QueryExt, SqlError,
 };
 use connector_interface::*;
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures::{
+    future,
+    stream::{self, FuturesUnordered, Stream, StreamExt},
+};
 use prisma_models::*;
 use quaint::ast::*;
+use std::collections::HashSet;
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
@@ -40,9 +44,26 @@ pub async fn get_many_records(
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
     let mut records = ManyRecords::new(field_names);
 
+    if query_arguments.is_definitely_empty() {
+        return Ok(records);
+    }
+
+    let distinct = query_arguments.distinct.take();
+
     if query_arguments.can_batch() {
-        // We don't need to order in the database due to us ordering in this function.
-        let order = query_arguments.order_by.take();
+        // We don't need to order in the database due to us ordering in this function. Skip and take
+        // must apply to the merged batches rather than to each of them, so they move here too.
+        let order = std::mem::take(&mut query_arguments.order_by);
+        let skip = if query_arguments.ignore_skip {
+            None
+        } else {
+            query_arguments.skip.take()
+        };
+        let take = if query_arguments.ignore_take {
+            None
+        } else {
+            query_arguments.take.take()
+        };
 
         let batches = query_arguments.batched();
         let mut futures = FuturesUnordered::new();
@@ -58,24 +79,139 @@ pub async fn get_many_records(
             }
         }
 
-        if let Some(ref order_by) = order {
-            records.order_by(order_by)
+        if !order.is_empty() {
+            records.order_by(&order)
+        } else if skip.is_some() || take.is_some() || distinct.is_some() {
+            records.order_by(&identifier_ordering(model))
+        }
+
+        if let Some(distinct) = &distinct {
+            remove_duplicates(&mut records, distinct)?;
+        }
+
+        paginate(&mut records, skip, take);
+    } else if let Some(distinct) = distinct {
+        // Limiting the rows on the database level would cut off records before the duplicates are
+        // removed and return too few of them. Skip and take apply to the distinct records instead.
+        let skip = if query_arguments.ignore_skip {
+            None
+        } else {
+            query_arguments.skip.take()
+        };
+        let take = if query_arguments.ignore_take {
+            None
+        } else {
+            query_arguments.take.take()
+        };
+
+        // The first record of each set of duplicates is kept, so the ordering must be deterministic.
+        if query_arguments.order_by.is_empty() && query_arguments.order_by_aggregate.is_none() {
+            query_arguments.order_by = identifier_ordering(model);
+        }
+
+        let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+
+        for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
+            records.push(Record::from(item))
         }
+
+        remove_duplicates(&mut records, &distinct)?;
+        paginate(&mut records, skip, take);
     } else {
         let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
 
         for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
             records.push(Record::from(item))
         }
-    }
 
-    if reversed {
-        records.reverse();
+        // The database returns the last records of the ordering in reverse, restore the requested order.
+        if reversed {
+            records.reverse();
+        }
     }
 
     Ok(records)
 }
 
+/// Ascending ordering by the primary identifier, the implicit ordering of paginated queries.
+fn identifier_ordering(model: &ModelRef) -> Vec<OrderBy> {
+    model
+        .primary_identifier()
+        .scalar_fields()
+        .map(|field| OrderBy {
+            field,
+            sort_order: SortOrder::Ascending,
+        })
+        .collect()
+}
+
+/// Keeps the first of the records with the same values for the `distinct` fields.
+fn remove_duplicates(records: &mut ManyRecords, distinct: &ModelProjection) -> crate::Result<()> {
+    let projections = records.projections(distinct)?;
+    let mut seen = HashSet::with_capacity(projections.len());
+
+    records.records = std::mem::take(&mut records.records)
+        .into_iter()
+        .zip(projections)
+        .filter(|(_, projection)| seen.insert(projection.clone()))
+        .map(|(record, _)| record)
+        .collect();
+
+    Ok(())
+}
+
+/// Applies `skip` and `take` to records that are in the requested order. A negative `take` selects
+/// the last records, and `skip` then counts from the end.
+fn paginate(records: &mut ManyRecords, skip: Option<i64>, take: Option<i64>) {
+    let skip = skip.unwrap_or(0).max(0) as usize;
+    let len = records.records.len();
+
+    match take {
+        Some(take) if take < 0 => {
+            let end = len.saturating_sub(skip);
+            let start = end.saturating_sub((-take) as usize);
+
+            records.records.truncate(end);
+            records.records.drain(..start);
+        }
+        take => {
+            records.records.drain(..skip.min(len));
+
+            if let Some(take) = take {
+                records.records.truncate(take as usize);
+            }
+        }
+    }
+}
+
+/// Reads the records one batch at a time, yielding the records of each batch
+/// as soon as it arrives. See `ReadOperations::export_stream`.
+pub fn export_stream<'a>(
+    conn: &'a dyn QueryExt,
+    model: &'a ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &'a ModelProjection,
+) -> impl Stream<Item = crate::Result<Record>> + Send + 'a {
+    let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
+    let batches = if query_arguments.can_batch() {
+        query_arguments.batched()
+    } else {
+        vec![query_arguments]
+    };
+
+    stream::iter(batches)
+        .then(move |args| {
+            let query = read::get_records(model, selected_fields.as_columns(), args);
+            let idents = idents.clone();
+
+            async move { conn.filter(query.into(), idents.as_slice()).await }
+        })
+        .flat_map(|result| match result {
+            Ok(rows) => stream::iter(rows.into_iter().map(|row| Ok(Record::from(row)))).left_stream(),
+            Err(err) => stream::once(future::ready(Err(err))).right_stream(),
+        })
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
     from_field: &RelationFieldRef,
@@ -160,3 +296,38 @@ pub async fn aggregate(
 
     Ok(row.into_aggregation_results(&aggregators))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_applies_to_the_distinct_records() {
+        let datamodel = r#"
+            model Cat {
+                id    Int    @id
+                color String
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let cat = internal_data_model.find_model("Cat").unwrap();
+        let color = cat.fields().find_from_scalar("color").unwrap();
+
+        let mut records = ManyRecords::new(vec!["id".to_owned(), "color".to_owned()]);
+
+        for (id, color) in &[(1, "black"), (2, "black"), (3, "black"), (4, "white"), (5, "ginger")] {
+            records.push(Record::new(vec![
+                PrismaValue::Int(*id),
+                PrismaValue::String(color.to_string()),
+            ]));
+        }
+
+        remove_duplicates(&mut records, &ModelProjection::from(Field::Scalar(color))).unwrap();
+        paginate(&mut records, None, Some(2));
+
+        let ids: Vec<&PrismaValue> = records.records.iter().map(|record| &record.values[0]).collect();
+
+        assert_eq!(ids, vec![&PrismaValue::Int(1), &PrismaValue::Int(4)]);
+    }
+}
@@ -2,7 +2,7 @@ use crate::{error::SqlError, query_builder::write, QueryExt};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::{connector::SqlFamily, error::ErrorKind};
 use std::{collections::HashMap, convert::TryFrom};
 use user_facing_errors::query_engine::DatabaseConstraint;
 
@@ -141,12 +141,57 @@ pub async fn disconnect(
 }
 
 /// Execute a plain SQL query with the given parameters, returning the number of
-/// affected rows.
-pub async fn execute_raw(conn: &dyn QueryExt, query: String, parameters: Vec<PrismaValue>) -> crate::Result<usize> {
-    let value = conn.raw_count(query, parameters).await?;
+/// affected rows and the rows returned by a `RETURNING` or `OUTPUT` clause.
+pub async fn execute_raw(
+    conn: &dyn QueryExt,
+    sql_family: SqlFamily,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> crate::Result<RawExecution> {
+    let value = conn.raw_execute(query, parameters, sql_family).await?;
     Ok(value)
 }
 
+/// Execute a plain SQL upsert with the given parameters, returning whether a row
+/// was inserted or updated. Postgres reports this through the `xmax` system
+/// column of the returned row, which is zero for freshly inserted rows. MySQL
+/// counts an updated row as two affected rows, and an unchanged one as zero.
+pub async fn upsert_raw(
+    conn: &dyn QueryExt,
+    sql_family: SqlFamily,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> crate::Result<UpsertOutcome> {
+    match sql_family {
+        SqlFamily::Postgres => {
+            let query = format!(
+                "{} RETURNING (xmax = 0) AS inserted",
+                query.trim_end().trim_end_matches(';')
+            );
+            let rows = conn.raw_json(query, parameters).await?;
+            let inserted = rows
+                .get(0)
+                .and_then(|row| row.get("inserted"))
+                .and_then(|inserted| inserted.as_bool())
+                .unwrap_or(false);
+
+            Ok(if inserted {
+                UpsertOutcome::Inserted
+            } else {
+                UpsertOutcome::Updated
+            })
+        }
+        SqlFamily::Mysql => match conn.raw_count(query, parameters).await? {
+            1 => Ok(UpsertOutcome::Inserted),
+            _ => Ok(UpsertOutcome::Updated),
+        },
+        _ => Err(SqlError::RawError {
+            code: String::from("N/A"),
+            message: format!("Raw upserts are not supported on {}.", sql_family.as_str()),
+        }),
+    }
+}
+
 /// Execute a plain SQL query with the given parameters, returning the answer as
 /// a JSON `Value`.
 pub async fn query_raw(
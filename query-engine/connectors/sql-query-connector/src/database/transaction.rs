@@ -2,9 +2,10 @@ use crate::database::operations::*;
 use crate::SqlError;
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationResult, Aggregator, QueryArguments, ReadOperations, RecordFilter,
-    Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationResult, Aggregator, QueryArguments, RawExecution, ReadOperations,
+    RecordFilter, Transaction, UpsertOutcome, WriteArgs, WriteOperations,
 };
+use futures::stream::{BoxStream, StreamExt};
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
 use quaint::prelude::ConnectionInfo;
@@ -87,6 +88,17 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
             .await
     }
+
+    fn export_stream<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'a ModelProjection,
+    ) -> BoxStream<'a, connector::Result<Record>> {
+        read::export_stream(&self.inner, model, query_arguments, selected_fields)
+            .map(move |result| result.map_err(|err| err.into_connector_error(&self.connection_info)))
+            .boxed()
+    }
 }
 
 #[async_trait]
@@ -131,8 +143,17 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
             .await
     }
 
-    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
+    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<RawExecution> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::execute_raw(&self.inner, sql_family, query, parameters).await })
+            .await
+    }
+
+    async fn upsert_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<UpsertOutcome> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::upsert_raw(&self.inner, sql_family, query, parameters).await })
             .await
     }
 
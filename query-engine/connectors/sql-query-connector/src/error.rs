@@ -100,6 +100,9 @@ pub enum SqlError {
 
     #[fail(display = "Database error. error code: {}, error message: {}", code, message)]
     RawError { code: String, message: String },
+
+    #[fail(display = "Error writing the exported rows: {}", _0)]
+    ExportWriteError(std::io::Error),
 }
 
 impl SqlError {
@@ -174,6 +177,7 @@ impl SqlError {
                 .ok(),
                 kind: ErrorKind::RawError { code, message },
             },
+            SqlError::ExportWriteError(e) => ConnectorError::from_kind(ErrorKind::QueryError(Box::new(e))),
         }
     }
 }
@@ -8,10 +8,11 @@ mod query_ext;
 mod row;
 
 use filter_conversion::*;
-use query_ext::QueryExt;
 use row::*;
 
 pub use database::*;
 pub use error::SqlError;
+pub use query_builder::read::RowLock;
+pub use query_ext::QueryExt;
 
 type Result<T> = std::result::Result<T, error::SqlError>;
@@ -2,6 +2,8 @@ use connector_interface::OrderDirections;
 use prisma_models::*;
 use quaint::ast::*;
 
+const AGGREGATE_ALIAS: &str = "orderby_aggregate";
+
 pub type OrderVec<'a> = Vec<(Expression<'a>, Option<Order>)>;
 
 pub struct Ordering;
@@ -9,75 +11,239 @@ pub struct Ordering;
 /// Tooling for generating orderings for different query types.
 impl Ordering {
     pub fn for_model(model: &ModelRef, order_directive: OrderDirections) -> OrderVec<'static> {
+        let aggregate = order_directive
+            .aggregate_order_by
+            .as_ref()
+            .map(|order_by| (Self::aggregate_expression(order_by), order_by.sort_order));
+
         Self::by_fields(
-            order_directive
-                .primary_order_by
-                .as_ref()
-                .map(|oby| oby.field.as_column()),
             model.primary_identifier().as_columns().collect(),
+            aggregate,
             order_directive,
         )
     }
 
+    /// Orders by the aggregate, if any, then by the fields in sequence. Unless one of the fields is
+    /// unique or part of the identifier, the identifier is appended when an implicit ordering is
+    /// needed, to keep the order stable. Aggregates are never unique.
     fn by_fields(
-        first_column: Option<Column<'static>>,
         identifier: Vec<Column<'static>>,
+        aggregate: Option<(Expression<'static>, SortOrder)>,
         order_directive: OrderDirections,
     ) -> OrderVec<'static> {
-        match order_directive.primary_order_by {
-            Some(order_by) => {
-                let first = first_column.unwrap();
-                let size_hint = identifier.len() + 1;
-
-                if !identifier.contains(&first)
-                    && order_directive.needs_implicit_id_ordering
-                    && !order_by.field.unique()
-                {
-                    match (order_by.sort_order, order_directive.needs_to_be_reverse_order) {
-                        (SortOrder::Ascending, true) => {
-                            Self::merge_columns(first.descend(), identifier.into_iter().map(|c| c.descend()), size_hint)
-                        }
-                        (SortOrder::Descending, true) => {
-                            Self::merge_columns(first.ascend(), identifier.into_iter().map(|c| c.descend()), size_hint)
-                        }
-                        (SortOrder::Ascending, false) => {
-                            Self::merge_columns(first.ascend(), identifier.into_iter().map(|c| c.ascend()), size_hint)
-                        }
-                        (SortOrder::Descending, false) => {
-                            Self::merge_columns(first.descend(), identifier.into_iter().map(|c| c.ascend()), size_hint)
-                        }
-                    }
-                } else {
-                    match (order_by.sort_order, order_directive.needs_to_be_reverse_order) {
-                        (SortOrder::Ascending, true) => vec![first.descend()],
-                        (SortOrder::Descending, true) => vec![first.ascend()],
-                        (SortOrder::Ascending, false) => vec![first.ascend()],
-                        (SortOrder::Descending, false) => vec![first.descend()],
-                    }
-                }
-            }
-            None if order_directive.needs_implicit_id_ordering && order_directive.needs_to_be_reverse_order => {
-                identifier.into_iter().map(|c| c.descend()).collect()
-            }
-            None if order_directive.needs_implicit_id_ordering && !order_directive.needs_to_be_reverse_order => {
-                identifier.into_iter().map(|c| c.ascend()).collect()
-            }
-            None => Vec::new(),
+        let reverse = order_directive.needs_to_be_reverse_order;
+
+        if aggregate.is_none() && order_directive.order_by.is_empty() {
+            return match order_directive.needs_implicit_id_ordering {
+                true if reverse => identifier.into_iter().map(|c| c.descend()).collect(),
+                true => identifier.into_iter().map(|c| c.ascend()).collect(),
+                false => Vec::new(),
+            };
         }
-    }
 
-    fn merge_columns(
-        first: OrderDefinition<'static>,
-        rest: impl IntoIterator<Item = OrderDefinition<'static>>,
-        size_hint: usize,
-    ) -> OrderVec<'static> {
+        let keys: Vec<(Expression<'static>, SortOrder)> = aggregate
+            .into_iter()
+            .chain(
+                order_directive
+                    .order_by
+                    .iter()
+                    .map(|order_by| (order_by.field.as_column().into(), order_by.sort_order)),
+            )
+            .collect();
+
+        let needs_identifier = order_directive.needs_implicit_id_ordering
+            && !order_directive
+                .order_by
+                .iter()
+                .any(|order_by| order_by.field.unique() || identifier.contains(&order_by.field.as_column()));
+
+        let size_hint = keys.len() + identifier.len();
         let mut order_vec = Vec::with_capacity(size_hint);
-        order_vec.push(first);
 
-        for col in rest.into_iter() {
-            order_vec.push(col);
+        for (expression, sort_order) in keys {
+            order_vec.push(match (sort_order, reverse) {
+                (SortOrder::Ascending, false) | (SortOrder::Descending, true) => (expression, Some(Order::Asc)),
+                (SortOrder::Descending, false) | (SortOrder::Ascending, true) => (expression, Some(Order::Desc)),
+            });
+        }
+
+        if needs_identifier {
+            order_vec.extend(
+                identifier
+                    .into_iter()
+                    .map(|c| if reverse { c.descend() } else { c.ascend() }),
+            );
         }
 
         order_vec
     }
+
+    /// A correlated subquery aggregating the related records, e.g. for a count:
+    /// ```sql
+    /// (SELECT COUNT(*) FROM `Post` AS `orderby_aggregate` WHERE `orderby_aggregate`.`authorId` = `User`.`id`)
+    /// ```
+    pub(crate) fn aggregate_expression(order_by: &OrderByAggregate) -> Expression<'static> {
+        let field = &order_by.field;
+
+        let relation_columns: Vec<Column<'static>> = field
+            .relation_columns(false)
+            .map(|c| c.table(AGGREGATE_ALIAS))
+            .collect();
+
+        let parent_columns: Vec<Column<'static>> = field.linking_fields().as_columns().collect();
+        let table = field.relation().as_table().alias(AGGREGATE_ALIAS);
+
+        let select = match order_by.aggregation {
+            AggregationKind::Count => Select::from_table(table).value(count(asterisk())),
+        };
+
+        select
+            .so_that(Row::from(relation_columns).equals(Row::from(parent_columns)))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::visitor::{Sqlite, Visitor};
+
+    #[test]
+    fn ordering_by_a_relation_count_descending() {
+        let datamodel = r#"
+            model User {
+                id    Int    @id
+                posts Post[]
+            }
+
+            model Post {
+                id       Int  @id
+                authorId Int
+                author   User @relation(fields: [authorId], references: [id])
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let user = internal_data_model.find_model("User").unwrap();
+        let posts = user.fields().find_from_relation_fields("posts").unwrap();
+
+        let order_directive = OrderDirections {
+            needs_implicit_id_ordering: true,
+            needs_to_be_reverse_order: false,
+            order_by: Vec::new(),
+            aggregate_order_by: Some(OrderByAggregate {
+                field: posts,
+                aggregation: AggregationKind::Count,
+                sort_order: SortOrder::Descending,
+            }),
+        };
+
+        let ordering = Ordering::for_model(&user, order_directive);
+        let select = ordering
+            .into_iter()
+            .fold(Select::from_table(user.as_table()).column("id"), |acc, ord| {
+                acc.order_by(ord)
+            });
+
+        let (sql, _) = Sqlite::build(select).unwrap();
+
+        assert!(sql.starts_with(
+            "SELECT `id` FROM `test`.`User` ORDER BY (SELECT COUNT(*) FROM `test`.`Post` AS `orderby_aggregate` WHERE"
+        ));
+        assert!(sql.contains("`orderby_aggregate`.`authorId`"));
+        assert!(sql.ends_with(") DESC, `test`.`User`.`id` ASC"));
+    }
+
+    #[test]
+    fn ordering_by_a_relation_count_then_by_a_field() {
+        let datamodel = r#"
+            model User {
+                id    Int    @id
+                name  String
+                posts Post[]
+            }
+
+            model Post {
+                id       Int  @id
+                authorId Int
+                author   User @relation(fields: [authorId], references: [id])
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let user = internal_data_model.find_model("User").unwrap();
+        let posts = user.fields().find_from_relation_fields("posts").unwrap();
+        let name = user.fields().find_from_scalar("name").unwrap();
+
+        let order_directive = OrderDirections {
+            needs_implicit_id_ordering: true,
+            needs_to_be_reverse_order: true,
+            order_by: vec![OrderBy {
+                field: name,
+                sort_order: SortOrder::Ascending,
+            }],
+            aggregate_order_by: Some(OrderByAggregate {
+                field: posts,
+                aggregation: AggregationKind::Count,
+                sort_order: SortOrder::Descending,
+            }),
+        };
+
+        let ordering = Ordering::for_model(&user, order_directive);
+        let select = ordering
+            .into_iter()
+            .fold(Select::from_table(user.as_table()).column("id"), |acc, ord| {
+                acc.order_by(ord)
+            });
+
+        let (sql, _) = Sqlite::build(select).unwrap();
+
+        assert!(sql.starts_with("SELECT `id` FROM `test`.`User` ORDER BY (SELECT COUNT(*) FROM `test`.`Post`"));
+        assert!(sql.ends_with(") ASC, `test`.`User`.`name` DESC, `test`.`User`.`id` DESC"));
+    }
+
+    #[test]
+    fn ordering_by_two_fields_keeps_their_order_and_directions() {
+        let datamodel = r#"
+            model User {
+                id        Int    @id
+                lastName  String
+                firstName String
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let user = internal_data_model.find_model("User").unwrap();
+        let last_name = user.fields().find_from_scalar("lastName").unwrap();
+        let first_name = user.fields().find_from_scalar("firstName").unwrap();
+
+        let order_directive = OrderDirections {
+            needs_implicit_id_ordering: true,
+            needs_to_be_reverse_order: false,
+            order_by: vec![
+                OrderBy {
+                    field: last_name,
+                    sort_order: SortOrder::Ascending,
+                },
+                OrderBy {
+                    field: first_name,
+                    sort_order: SortOrder::Descending,
+                },
+            ],
+            aggregate_order_by: None,
+        };
+
+        let ordering = Ordering::for_model(&user, order_directive);
+        let select = ordering
+            .into_iter()
+            .fold(Select::from_table(user.as_table()).column("id"), |acc, ord| {
+                acc.order_by(ord)
+            });
+
+        let (sql, _) = Sqlite::build(select).unwrap();
+
+        assert!(sql.ends_with(
+            "ORDER BY `test`.`User`.`lastName` ASC, `test`.`User`.`firstName` DESC, `test`.`User`.`id` ASC"
+        ));
+    }
 }
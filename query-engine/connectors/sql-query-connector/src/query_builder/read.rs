@@ -1,9 +1,21 @@
-use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering::Ordering};
+use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering::Ordering, SqlError};
 use connector_interface::{filter::Filter, Aggregator, QueryArguments};
 use itertools::Itertools;
+use once_cell::sync::Lazy;
 use prisma_models::*;
-use quaint::ast::*;
-use std::sync::Arc;
+use quaint::{
+    ast::*,
+    prelude::SqlFamily,
+    visitor::{self, Visitor},
+};
+use std::{env, sync::Arc};
+
+/// Translate `skip` into a keyset predicate instead of an `OFFSET` where possible, see `keyset_skip_condition`.
+/// Enabled by setting the `QUERY_KEYSET_SKIP` environment variable to `true`.
+static KEYSET_SKIP: Lazy<bool> = Lazy::new(|| match env::var("QUERY_KEYSET_SKIP") {
+    Ok(flag) => flag.parse().unwrap_or(false),
+    Err(_) => false,
+});
 
 pub trait SelectDefinition {
     fn into_select(self, _: &ModelRef) -> Select<'static>;
@@ -30,37 +42,107 @@ impl SelectDefinition for Select<'static> {
 
 impl SelectDefinition for QueryArguments {
     fn into_select(self, model: &ModelRef) -> Select<'static> {
-        let cursor: ConditionTree = cursor_condition::build(&self, Arc::clone(&model));
-        let ordering_directions = self.ordering_directions();
-        let ordering = Ordering::for_model(&model, ordering_directions);
-
-        let limit = if self.ignore_take { None } else { self.take_abs() };
-        let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
-
-        let filter: ConditionTree = self
-            .filter
-            .map(|f| f.aliased_cond(None))
-            .unwrap_or(ConditionTree::NoCondition);
-
-        let conditions = match (filter, cursor) {
-            (ConditionTree::NoCondition, cursor) => cursor,
-            (filter, ConditionTree::NoCondition) => filter,
-            (filter, cursor) => ConditionTree::and(filter, cursor),
-        };
+        build_select(self, model, *KEYSET_SKIP)
+    }
+}
 
-        let select_ast = Select::from_table(model.as_table())
-            .so_that(conditions)
-            .offset(skip as usize);
+fn build_select(args: QueryArguments, model: &ModelRef, keyset_skip: bool) -> Select<'static> {
+    let cursor: ConditionTree = cursor_condition::build(&args, Arc::clone(&model));
+    let ordering_directions = args.ordering_directions();
+    let ordering = Ordering::for_model(&model, ordering_directions);
 
-        let select_ast = ordering.into_iter().fold(select_ast, |acc, ord| acc.order_by(ord));
+    let limit = if args.ignore_take { None } else { args.take_abs() };
+    let skip = if args.ignore_skip { 0 } else { args.skip.unwrap_or(0) };
 
-        match limit {
-            Some(limit) => select_ast.limit(limit as usize),
-            None => select_ast,
-        }
+    let filter: ConditionTree = args
+        .filter
+        .clone()
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let keyset = if keyset_skip {
+        keyset_skip_condition(&args, model, &filter, skip)
+    } else {
+        None
+    };
+
+    let (cursor, skip) = match keyset {
+        Some(keyset) => (keyset, 0),
+        None => (cursor, skip),
+    };
+
+    let conditions = match (filter, cursor) {
+        (ConditionTree::NoCondition, cursor) => cursor,
+        (filter, ConditionTree::NoCondition) => filter,
+        (filter, cursor) => ConditionTree::and(filter, cursor),
+    };
+
+    let select_ast = Select::from_table(model.as_table())
+        .so_that(conditions)
+        .offset(skip as usize);
+
+    let select_ast = ordering.into_iter().fold(select_ast, |acc, ord| acc.order_by(ord));
+
+    match limit {
+        Some(limit) => select_ast.limit(limit as usize),
+        None => select_ast,
     }
 }
 
+/// `OFFSET` makes the database produce and discard every skipped row. When ordering by a single required unique
+/// field, the skip can instead be expressed as a predicate on that field, looking up the last skipped key in a
+/// query that only reads the key column:
+/// ```sql
+/// WHERE `email` > (SELECT `email` FROM `User` WHERE <filter> ORDER BY `email` ASC LIMIT 1 OFFSET <skip - 1>)
+/// ```
+/// Returns `None` when the arguments don't allow it, e.g. with a cursor, which is a keyset condition already.
+fn keyset_skip_condition(
+    args: &QueryArguments,
+    model: &ModelRef,
+    filter: &ConditionTree<'static>,
+    skip: i64,
+) -> Option<ConditionTree<'static>> {
+    let order_by = match args.order_by.as_slice() {
+        [order_by] => order_by,
+        _ => return None,
+    };
+
+    if skip <= 0 || args.cursor.is_some() || args.order_by_aggregate.is_some() {
+        return None;
+    }
+
+    if !order_by.field.unique() || !order_by.field.is_required {
+        return None;
+    }
+
+    let column = order_by.field.as_column();
+
+    // A negative take reverses the order in the database, so the keys are compared the other way around.
+    let ascending = match (order_by.sort_order, args.needs_reversed_order()) {
+        (SortOrder::Ascending, false) | (SortOrder::Descending, true) => true,
+        (SortOrder::Descending, false) | (SortOrder::Ascending, true) => false,
+    };
+
+    let last_skipped_key = Select::from_table(model.as_table())
+        .column(column.clone())
+        .so_that(filter.clone())
+        .order_by(if ascending {
+            column.clone().ascend()
+        } else {
+            column.clone().descend()
+        })
+        .limit(1)
+        .offset((skip - 1) as usize);
+
+    let compare = if ascending {
+        column.greater_than(last_skipped_key)
+    } else {
+        column.less_than(last_skipped_key)
+    };
+
+    Some(ConditionTree::single(compare))
+}
+
 pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
 where
     T: SelectDefinition,
@@ -120,6 +202,63 @@ pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArgume
         })
 }
 
+/// Checks whether any record of the model matches the filter, the portable form of
+/// `SELECT EXISTS(SELECT 1 ...)`. The database can stop scanning at the first match.
+///
+/// ```sql
+/// SELECT 1 FROM `Table` WHERE (filter) LIMIT 1;
+/// ```
+pub fn exists(model: &ModelRef, filter: Filter) -> Select<'static> {
+    Select::from_table(model.as_table())
+        .value(1)
+        .so_that(filter.aliased_cond(None))
+        .limit(1)
+}
+
+/// Locks taken on the rows returned by a read, until the end of the transaction. Skipping locked
+/// rows lets concurrent workers pick different rows of a job queue table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowLock {
+    /// `FOR UPDATE`: wait for the rows locked by other transactions.
+    ForUpdate,
+    /// `FOR UPDATE SKIP LOCKED`: leave out the rows locked by other transactions.
+    ForUpdateSkipLocked,
+}
+
+impl RowLock {
+    fn as_sql(self) -> &'static str {
+        match self {
+            RowLock::ForUpdate => "FOR UPDATE",
+            RowLock::ForUpdateSkipLocked => "FOR UPDATE SKIP LOCKED",
+        }
+    }
+}
+
+/// Render the select with a locking clause appended. Quaint cannot express locking clauses, so
+/// the query is rendered here. Only Postgres and MySQL (8 for `SKIP LOCKED`) support them.
+///
+/// ```sql
+/// SELECT `id` FROM `Job` ORDER BY `id` ASC LIMIT 1 FOR UPDATE SKIP LOCKED;
+/// ```
+pub fn with_row_lock(
+    select: Select<'_>,
+    lock: RowLock,
+    sql_family: SqlFamily,
+) -> crate::Result<(String, Vec<Value<'_>>)> {
+    let (sql, params) = match sql_family {
+        SqlFamily::Postgres => visitor::Postgres::build(select)?,
+        SqlFamily::Mysql => visitor::Mysql::build(select)?,
+        _ => {
+            return Err(SqlError::RawError {
+                code: String::from("N/A"),
+                message: format!("Row locking is not supported on {}.", sql_family.as_str()),
+            })
+        }
+    };
+
+    Ok((format!("{} {}", sql, lock.as_sql()), params))
+}
+
 fn extract_columns(model: &ModelRef, aggregators: &[Aggregator]) -> Vec<Column<'static>> {
     let fields: Vec<_> = aggregators
         .iter()
@@ -135,3 +274,84 @@ fn extract_columns(model: &ModelRef, aggregators: &[Aggregator]) -> Vec<Column<'
 
     fields.as_columns().collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use connector_interface::ScalarCompare;
+    use quaint::visitor::{Sqlite, Visitor};
+
+    #[test]
+    fn skip_is_translated_to_a_keyset_predicate_when_ordering_by_a_unique_field() {
+        let datamodel = r#"
+            model User {
+                id    Int    @id
+                email String @unique
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let user = internal_data_model.find_model("User").unwrap();
+        let email = user.fields().find_from_scalar("email").unwrap();
+
+        let args = QueryArguments {
+            skip: Some(100),
+            take: Some(10),
+            order_by: vec![OrderBy {
+                field: email,
+                sort_order: SortOrder::Ascending,
+            }],
+            ..Default::default()
+        };
+
+        let keyset_select = build_select(args.clone(), &user, true).column("id");
+        let (sql, params) = Sqlite::build(keyset_select).unwrap();
+
+        assert!(sql.contains(
+            "WHERE `test`.`User`.`email` > (SELECT `test`.`User`.`email` FROM `test`.`User` ORDER BY `test`.`User`.`email` ASC LIMIT ? OFFSET ?)"
+        ));
+        assert!(sql.ends_with("ORDER BY `test`.`User`.`email` ASC LIMIT ?"));
+        assert_eq!(params, vec![Value::from(1), Value::from(99), Value::from(10)]);
+
+        let offset_select = build_select(args, &user, false).column("id");
+        let (sql, _) = Sqlite::build(offset_select).unwrap();
+
+        assert!(sql.ends_with("ORDER BY `test`.`User`.`email` ASC LIMIT ? OFFSET ?"));
+    }
+    #[test]
+    fn exists_selects_at_most_one_row() {
+        let datamodel = r#"
+            model User {
+                id    Int    @id
+                email String @unique
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let user = internal_data_model.find_model("User").unwrap();
+        let email = user.fields().find_from_scalar("email").unwrap();
+
+        let (sql, params) = Sqlite::build(exists(&user, email.equals("julie@example.com"))).unwrap();
+
+        assert!(sql.starts_with("SELECT ? FROM `test`.`User` WHERE"));
+        assert!(sql.contains("`test`.`User`.`email` = ?"));
+        assert!(sql.ends_with("LIMIT ?"));
+        assert_eq!(
+            params,
+            vec![Value::from(1), Value::from("julie@example.com"), Value::from(1)]
+        );
+    }
+
+    #[test]
+    fn row_locks_are_appended_to_the_rendered_select() {
+        let select = Select::from_table("Job").column("id").limit(1);
+
+        let (sql, _) = with_row_lock(select.clone(), RowLock::ForUpdateSkipLocked, SqlFamily::Postgres).unwrap();
+        assert!(sql.ends_with("LIMIT $1 FOR UPDATE SKIP LOCKED"), "{}", sql);
+
+        let (sql, _) = with_row_lock(select.clone(), RowLock::ForUpdate, SqlFamily::Mysql).unwrap();
+        assert!(sql.ends_with("LIMIT ? FOR UPDATE"), "{}", sql);
+
+        assert!(with_row_lock(select, RowLock::ForUpdate, SqlFamily::Sqlite).is_err());
+    }
+}
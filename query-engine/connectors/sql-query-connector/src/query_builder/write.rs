@@ -1,4 +1,5 @@
-use connector_interface::WriteArgs;
+use crate::filter_conversion::AliasedCondition;
+use connector_interface::{filter::Filter, WriteArgs};
 use prisma_models::*;
 use quaint::ast::*;
 
@@ -58,6 +59,33 @@ pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'st
     })
 }
 
+/// Delete at most `batch_size` records matching the filter. The ids are selected through a
+/// derived table, because MySQL rejects both `LIMIT` in `IN` subqueries and subqueries on the
+/// table being deleted from.
+///
+/// ```sql
+/// DELETE FROM `Table` WHERE `Table`.`id` IN (
+///     SELECT `id` FROM (SELECT `Table`.`id` FROM `Table` WHERE (filter) LIMIT ?) AS `batch`
+/// );
+/// ```
+pub fn delete_batch(model: &ModelRef, filter: Filter, batch_size: usize) -> Delete<'static> {
+    let id_columns: Vec<Column<'static>> = model.primary_identifier().as_columns().collect();
+
+    let batch = Select::from_table(model.as_table())
+        .columns(id_columns.clone())
+        .so_that(filter.aliased_cond(None))
+        .limit(batch_size);
+
+    let batch_ids = model
+        .primary_identifier()
+        .db_names()
+        .fold(Select::from_table(Table::from(batch).alias("batch")), |select, name| {
+            select.column(name)
+        });
+
+    Delete::from_table(model.as_table()).so_that(Row::from(id_columns).in_selection(batch_ids))
+}
+
 pub fn create_relation_table_records(
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
@@ -111,3 +139,31 @@ pub fn delete_relation_table_records(
 
     Delete::from_table(relation.as_table()).so_that(parent_id_criteria.and(child_id_criteria))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use connector_interface::ScalarCompare;
+    use quaint::visitor::{Mysql, Visitor};
+
+    #[test]
+    fn delete_batch_selects_the_ids_through_a_limited_derived_table() {
+        let datamodel = r#"
+            model User {
+                id    Int    @id
+                email String
+            }
+        "#;
+
+        let internal_data_model = DatamodelConverter::convert_string(datamodel.to_string()).build("test".to_string());
+        let user = internal_data_model.find_model("User").unwrap();
+        let email = user.fields().find_from_scalar("email").unwrap();
+
+        let (sql, params) = Mysql::build(delete_batch(&user, email.equals("julie@example.com"), 100)).unwrap();
+
+        assert!(sql.starts_with("DELETE FROM `test`.`User` WHERE"));
+        assert!(sql.contains("SELECT `id` FROM (SELECT `test`.`User`.`id` FROM `test`.`User` WHERE"));
+        assert!(sql.contains("LIMIT ?) AS `batch`"));
+        assert_eq!(params, vec![Value::from("julie@example.com"), Value::from(100)]);
+    }
+}
@@ -1,12 +1,22 @@
-use crate::{error::*, AliasedCondition, SqlRow, ToSqlRow};
+use crate::{
+    error::*,
+    query_builder::{
+        read::{self, RowLock},
+        write,
+    },
+    AliasedCondition, SqlRow, ToSqlRow,
+};
 use async_trait::async_trait;
-use connector_interface::{filter::Filter, RecordFilter};
+use connector_interface::{filter::Filter, RawExecution, RecordFilter};
 use datamodel::FieldArity;
-use futures::future::FutureExt;
+use futures::{
+    future::{Future, FutureExt},
+    io::{AsyncWrite, AsyncWriteExt},
+};
 use prisma_models::*;
 use quaint::{
     ast::*,
-    connector::{self, Queryable},
+    connector::{self, Queryable, SqlFamily},
     pooled::PooledConnection,
 };
 
@@ -32,6 +42,26 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
+    /// Like `filter`, but the returned rows stay locked until the end of the
+    /// transaction, see `RowLock`. Only Postgres and MySQL support locking.
+    async fn filter_locked(
+        &self,
+        q: Select<'_>,
+        lock: RowLock,
+        sql_family: SqlFamily,
+        idents: &[(TypeIdentifier, FieldArity)],
+    ) -> crate::Result<Vec<SqlRow>> {
+        let (sql, params) = read::with_row_lock(q, lock, sql_family)?;
+        let result_set = self.query_raw(&sql, &params).await?;
+        let mut sql_rows = Vec::new();
+
+        for row in result_set {
+            sql_rows.push(row.to_sql_row(idents)?);
+        }
+
+        Ok(sql_rows)
+    }
+
     /// Execute a singular SQL query in the database, returning an arbitrary
     /// JSON `Value` as a result.
     async fn raw_json<'a>(
@@ -59,8 +89,40 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(Value::Array(result))
     }
 
+    /// Run the query and write the resulting rows to `writer` as CSV, after a
+    /// header row with the column names. Values are mapped like in `raw_json`.
+    /// `NULL` is written as an empty cell and the empty string as `""`, so the
+    /// two can be told apart. Rows are written one line at a time without
+    /// blocking the executor, and the writer is flushed at the end. Returns the
+    /// number of exported rows.
+    async fn export_csv<W>(&self, q: Select<'_>, writer: &mut W) -> crate::Result<usize>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let result_set = self.query(q.into()).await?;
+
+        let header = result_set.columns().iter().map(|column| csv_quote(column));
+        write_csv_line(writer, header).await?;
+
+        let mut count = 0;
+
+        for row in result_set.into_iter() {
+            let cells = row.into_iter().map(|value| csv_cell(&Value::from(value)));
+            write_csv_line(writer, cells).await?;
+            count += 1;
+        }
+
+        writer.flush().await.map_err(SqlError::ExportWriteError)?;
+
+        Ok(count)
+    }
+
     /// Execute a singular SQL query in the database, returning the number of
     /// affected rows.
+    ///
+    /// Statements without a meaningful affected rows count, like DDL, can
+    /// make some drivers report `-1`, which arrives here as a huge unsigned
+    /// number. These cases are normalized to `0`.
     async fn raw_count<'a>(
         &'a self,
         q: String,
@@ -69,7 +131,40 @@ pub trait QueryExt: Queryable + Send + Sync {
         let params: Vec<_> = params.into_iter().map(quaint::ast::Value::from).collect();
         let changes = AssertUnwindSafe(self.execute_raw(&q, &params)).catch_unwind().await??;
 
-        Ok(changes as usize)
+        let changes = if changes > i64::MAX as u64 {
+            0
+        } else {
+            usize::try_from(changes).unwrap_or(0)
+        };
+
+        Ok(changes)
+    }
+
+    /// Execute a singular SQL statement in the database, returning the number
+    /// of affected rows.
+    ///
+    /// Statements with a `RETURNING` (Postgres, SQLite) or `OUTPUT` (SQL
+    /// Server) clause are sent as queries instead, so that the rows they
+    /// return are kept. The count is then the number of returned rows.
+    async fn raw_execute<'a>(
+        &'a self,
+        q: String,
+        params: Vec<PrismaValue>,
+        sql_family: SqlFamily,
+    ) -> std::result::Result<RawExecution, crate::error::RawError> {
+        if returns_rows(&q, sql_family) {
+            let rows = self.raw_json(q, params).await?;
+            let count = rows.as_array().map(Vec::len).unwrap_or(0);
+
+            Ok(RawExecution {
+                count,
+                rows: Some(rows),
+            })
+        } else {
+            let count = self.raw_count(q, params).await?;
+
+            Ok(RawExecution { count, rows: None })
+        }
     }
 
     /// Select one row from the database.
@@ -112,6 +207,31 @@ pub trait QueryExt: Queryable + Send + Sync {
         }
     }
 
+    /// Check whether any record of the model matches the filter, without
+    /// fetching the matching records.
+    async fn exists(&self, model: &ModelRef, filter: Filter) -> crate::Result<bool> {
+        let select = read::exists(model, filter);
+        let result_set = self.query(select.into()).await?;
+
+        Ok(result_set.into_iter().next().is_some())
+    }
+
+    /// Delete the records of the model matching the filter, at most `batch_size` at a time, so
+    /// that a large deletion does not hold (or escalate to) locks on the whole table. Returns the
+    /// total number of deleted records.
+    async fn delete_in_batches(&self, model: &ModelRef, filter: Filter, batch_size: usize) -> crate::Result<usize> {
+        run_delete_batches(
+            batch_size,
+            || {
+                let delete = write::delete_batch(model, filter.clone(), batch_size);
+
+                async move { Ok(usize::try_from(self.execute(delete.into()).await?).unwrap_or(usize::MAX)) }
+            },
+            || self.exists(model, filter.clone()),
+        )
+        .await
+    }
+
     /// Read the all columns as a (primary) identifier.
     async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<RecordProjection>> {
         let model_id = model.primary_identifier();
@@ -147,3 +267,280 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(result)
     }
 }
+
+/// Run `delete_batch` until a batch deletes fewer than `batch_size` records, or until a full batch
+/// leaves no matching records according to `any_left`. Returns the total number of deleted records.
+async fn run_delete_batches<D, DFut, A, AFut>(
+    batch_size: usize,
+    mut delete_batch: D,
+    mut any_left: A,
+) -> crate::Result<usize>
+where
+    D: FnMut() -> DFut,
+    DFut: Future<Output = crate::Result<usize>>,
+    A: FnMut() -> AFut,
+    AFut: Future<Output = crate::Result<bool>>,
+{
+    if batch_size == 0 {
+        return Err(SqlError::QueryError(
+            "The batch size of a batched deletion must be positive.".into(),
+        ));
+    }
+
+    let mut deleted = 0;
+
+    loop {
+        let batch_count = delete_batch().await?;
+        deleted += batch_count;
+
+        if batch_count < batch_size || !any_left().await? {
+            return Ok(deleted);
+        }
+    }
+}
+
+/// Tokens that put a `RETURNING` or `OUTPUT` word right after them in a column
+/// position, like in `SET output = 1` or `(id, output)`.
+const COLUMN_PRECEDING_TOKENS: &[&str] = &[".", ",", "(", "SELECT", "SET", "BY", "WHERE", "AND", "OR", "ON"];
+
+/// Tokens that put a `RETURNING` or `OUTPUT` word right before them in a
+/// column position, like in `output = 1` or `output FROM`.
+const COLUMN_FOLLOWING_TOKENS: &[&str] = &[
+    ".", ",", ")", "=", "<", ">", "!", ";", "FROM", "AS", "IS", "IN", "LIKE", "BETWEEN",
+];
+
+/// Whether the statement has a `RETURNING` (Postgres, SQLite) or `OUTPUT` (SQL
+/// Server) clause. MySQL has neither. String literals, quoted identifiers and
+/// comments are skipped, and the keyword does not count where it can only be a
+/// column name.
+fn returns_rows(query: &str, sql_family: SqlFamily) -> bool {
+    let keyword = match sql_family {
+        SqlFamily::Postgres | SqlFamily::Sqlite => "RETURNING",
+        SqlFamily::Mssql => "OUTPUT",
+        SqlFamily::Mysql => return false,
+    };
+
+    let tokens = sql_tokens(query);
+    let is_any_of = |token: Option<&&str>, candidates: &[&str]| {
+        token
+            .map(|token| candidates.iter().any(|candidate| token.eq_ignore_ascii_case(candidate)))
+            .unwrap_or(false)
+    };
+
+    tokens.iter().enumerate().any(|(idx, token)| {
+        let previous = idx.checked_sub(1).and_then(|idx| tokens.get(idx));
+        let next = tokens.get(idx + 1);
+
+        token.eq_ignore_ascii_case(keyword)
+            && next.is_some()
+            && !is_any_of(previous, COLUMN_PRECEDING_TOKENS)
+            && !is_any_of(next, COLUMN_FOLLOWING_TOKENS)
+    })
+}
+
+/// Split a statement into words and punctuation characters, leaving out
+/// whitespace and comments. String literals and quoted identifiers become a
+/// single token made of their opening quote.
+fn sql_tokens(query: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let next_char = chars.peek().map(|(_, c)| *c);
+
+        match c {
+            c if c.is_whitespace() => (),
+            '-' if next_char == Some('-') => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if next_char == Some('*') => {
+                chars.next();
+
+                let mut previous = None;
+
+                for (_, c) in chars.by_ref() {
+                    if previous == Some('*') && c == '/' {
+                        break;
+                    }
+
+                    previous = Some(c);
+                }
+            }
+            '\'' | '"' | '`' | '[' => {
+                let closing = if c == '[' { ']' } else { c };
+
+                // A doubled closing quote is an escaped quote, the literal goes on.
+                while let Some((_, c)) = chars.next() {
+                    if c == closing {
+                        match chars.peek() {
+                            Some((_, next)) if *next == closing => {
+                                chars.next();
+                            }
+                            _ => break,
+                        }
+                    }
+                }
+
+                tokens.push(&query[start..start + 1]);
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                while let Some((_, next)) = chars.peek() {
+                    if next.is_alphanumeric() || *next == '_' || *next == '$' {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                let end = chars.peek().map(|(idx, _)| *idx).unwrap_or_else(|| query.len());
+                tokens.push(&query[start..end]);
+            }
+            c => tokens.push(&query[start..start + c.len_utf8()]),
+        }
+    }
+
+    tokens
+}
+
+/// Render a value as a CSV cell. `NULL` is an empty cell, strings are written
+/// as they are and the other values as JSON.
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => csv_quote(s),
+        other => csv_quote(&other.to_string()),
+    }
+}
+
+/// Quote a CSV field as described in RFC 4180, when needed. The empty string is
+/// always quoted, to tell it apart from `NULL`.
+fn csv_quote(field: &str) -> String {
+    if field.is_empty() || field.contains(|c: char| matches!(c, ',' | '"' | '\n' | '\r')) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+async fn write_csv_line<W>(writer: &mut W, cells: impl Iterator<Item = String>) -> crate::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut line = cells.collect::<Vec<String>>().join(",");
+    line.push('\n');
+
+    writer
+        .write_all(line.as_bytes())
+        .await
+        .map_err(SqlError::ExportWriteError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn deleting_500_records_in_batches_of_100_takes_five_statements() {
+        let remaining = Cell::new(500usize);
+        let statements = Cell::new(0);
+
+        let deleted = futures::executor::block_on(run_delete_batches(
+            100,
+            || {
+                statements.set(statements.get() + 1);
+
+                let batch_count = remaining.get().min(100);
+                remaining.set(remaining.get() - batch_count);
+
+                async move { Ok(batch_count) }
+            },
+            || {
+                let any_left = remaining.get() > 0;
+
+                async move { Ok(any_left) }
+            },
+        ))
+        .unwrap();
+
+        assert_eq!(deleted, 500);
+        assert_eq!(statements.get(), 5);
+        assert_eq!(remaining.get(), 0);
+    }
+
+    #[test]
+    fn deleting_in_batches_of_zero_records_is_an_error() {
+        let result = futures::executor::block_on(run_delete_batches(0, || async { Ok(0) }, || async { Ok(false) }));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn returning_clauses_are_detected_per_sql_family() {
+        let insert = r#"INSERT INTO "Todo" ("id") VALUES ('a') RETURNING id"#;
+
+        assert!(returns_rows(insert, SqlFamily::Postgres));
+        assert!(returns_rows(insert, SqlFamily::Sqlite));
+        assert!(!returns_rows(insert, SqlFamily::Mysql));
+        assert!(!returns_rows(insert, SqlFamily::Mssql));
+
+        let output = "INSERT INTO Todo (id) OUTPUT INSERTED.id VALUES ('a')";
+
+        assert!(returns_rows(output, SqlFamily::Mssql));
+        assert!(!returns_rows(output, SqlFamily::Postgres));
+    }
+
+    #[test]
+    fn columns_named_like_the_clause_keywords_do_not_return_rows() {
+        assert!(!returns_rows("UPDATE t SET output = 1", SqlFamily::Mssql));
+        assert!(!returns_rows("UPDATE t SET output = 1", SqlFamily::Mysql));
+        assert!(!returns_rows(
+            "INSERT INTO t (id, output) VALUES (1, 2)",
+            SqlFamily::Mssql
+        ));
+        assert!(!returns_rows("DELETE FROM t WHERE t.output > 1", SqlFamily::Mssql));
+        assert!(!returns_rows(r#"UPDATE t SET "returning" = 1"#, SqlFamily::Postgres));
+        assert!(!returns_rows("UPDATE t SET note = 'returning id'", SqlFamily::Postgres));
+        assert!(!returns_rows(
+            "UPDATE t SET note = 1 -- returning id",
+            SqlFamily::Postgres
+        ));
+    }
+
+    #[test]
+    fn csv_cells_are_quoted_only_when_needed() {
+        assert_eq!(csv_cell(&Value::Null), "");
+        assert_eq!(csv_cell(&Value::from("")), r#""""#);
+        assert_eq!(csv_cell(&Value::from("plain")), "plain");
+        assert_eq!(csv_cell(&Value::from("a,b")), r#""a,b""#);
+        assert_eq!(csv_cell(&Value::from(r#"say "hi""#)), r#""say ""hi""""#);
+        assert_eq!(csv_cell(&Value::from("two\nlines")), "\"two\nlines\"");
+        assert_eq!(csv_cell(&Value::from(42)), "42");
+        assert_eq!(csv_cell(&Value::from(true)), "true");
+    }
+
+    #[test]
+    fn csv_lines_are_written_to_async_writers() {
+        let mut csv = Vec::new();
+        let cells = vec!["a".to_owned(), r#""b""#.to_owned()];
+
+        futures::executor::block_on(write_csv_line(&mut csv, cells.into_iter())).unwrap();
+
+        assert_eq!(String::from_utf8(csv).unwrap(), "a,\"b\"\n");
+    }
+
+    #[test]
+    fn csv_write_failures_are_export_errors() {
+        let mut buffer = [0u8; 2];
+        let mut writer = futures::io::Cursor::new(&mut buffer[..]);
+        let cells = vec!["too long".to_owned()];
+
+        let result = futures::executor::block_on(write_csv_line(&mut writer, cells.into_iter()));
+
+        assert!(matches!(result, Err(SqlError::ExportWriteError(_))));
+    }
+}
@@ -207,6 +207,15 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
             ),
             other => PrismaValue::from(other),
         },
+        TypeIdentifier::Bytes => match p_value {
+            value if value.is_null() => PrismaValue::null(type_identifier.clone()),
+            Value::Bytes(Some(bytes)) => PrismaValue::Bytes(bytes.into_owned()),
+            Value::Text(Some(s)) => PrismaValue::Bytes(s.into_owned().into_bytes()),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as bytes or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::String => match p_value {
             value if value.is_null() => PrismaValue::null(type_identifier.clone()),
             Value::Uuid(Some(uuid)) => PrismaValue::String(uuid.to_string()),
@@ -270,6 +279,16 @@ fn interpret_bytes_as_i64(bytes: &[u8]) -> i64 {
 mod test {
     use super::*;
 
+    #[test]
+    fn bytes_that_are_not_utf8_are_read_as_bytes() {
+        let bytes = vec![0xde, 0xad, 0xbe, 0xef];
+        let value =
+            row_value_to_prisma_value(Value::Bytes(Some(bytes.clone().into())), &TypeIdentifier::Bytes).unwrap();
+
+        assert_eq!(value, PrismaValue::Bytes(bytes));
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""3q2+7w==""#);
+    }
+
     #[test]
     fn quaint_bytes_to_integer_conversion_works() {
         // Negative i64
@@ -44,9 +44,12 @@ async fn execute_raw<'a, 'b>(
     parameters: Vec<PrismaValue>,
 ) -> InterpretationResult<QueryResult> {
     let res = tx.execute_raw(query, parameters).await?;
-    let num = serde_json::Value::Number(serde_json::Number::from(res));
+    let num = serde_json::Value::Number(serde_json::Number::from(res.count));
 
-    Ok(QueryResult::Json(num))
+    match res.rows {
+        Some(rows) => Ok(QueryResult::Json(serde_json::json!({ "count": num, "rows": rows }))),
+        None => Ok(QueryResult::Json(num)),
+    }
 }
 
 async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) -> InterpretationResult<QueryResult> {
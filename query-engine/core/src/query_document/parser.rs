@@ -1,7 +1,7 @@
 use super::*;
 use crate::schema::*;
 use chrono::prelude::*;
-use prisma_value::PrismaValue;
+use prisma_value::{decode_bytes, PrismaValue};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use std::{
     borrow::Borrow,
@@ -179,6 +179,7 @@ impl QueryDocumentParser {
             (QueryValue::String(s), ScalarType::Json) => Ok(PrismaValue::Json(Self::parse_json(&s).map(|_| s)?)),
             (QueryValue::String(s), ScalarType::JsonList) => Self::parse_json_list(&s),
             (QueryValue::String(s), ScalarType::UUID)     => Self::parse_uuid(s.as_str()).map(PrismaValue::Uuid),
+            (QueryValue::String(s), ScalarType::Bytes)    => Self::parse_bytes(s.as_str()).map(PrismaValue::Bytes),
             (QueryValue::Int(i), ScalarType::Float)       => Ok(PrismaValue::Float(Decimal::from(i))),
             (QueryValue::Int(i), ScalarType::Int)         => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float)     => Ok(PrismaValue::Float(f)),
@@ -232,6 +233,12 @@ impl QueryDocumentParser {
         Uuid::parse_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
 
+    pub fn parse_bytes(s: &str) -> QueryParserResult<Vec<u8>> {
+        decode_bytes(s).map_err(|_| {
+            QueryParserError::ValueParseError("Invalid Bytes: expected a base64 encoded string".to_owned())
+        })
+    }
+
     pub fn parse_list(values: Vec<QueryValue>, value_type: &InputType) -> QueryParserResult<Vec<ParsedInputValue>> {
         values
             .into_iter()
@@ -265,9 +272,10 @@ impl QueryDocumentParser {
                 Some(value) => Ok(ParsedInputValue::Single(value)),
                 None => err(&i.name),
             },
-            EnumType::OrderBy(ord) => match ord.value_for(raw.as_str()) {
-                Some(val) => Ok(ParsedInputValue::OrderBy(val.clone())),
-                None => err(&ord.name),
+            EnumType::OrderBy(ord) => match (ord.value_for(raw.as_str()), ord.aggregate_value_for(raw.as_str())) {
+                (Some(val), _) => Ok(ParsedInputValue::OrderBy(val.clone())),
+                (None, Some(val)) => Ok(ParsedInputValue::OrderByAggregate(val.clone())),
+                (None, None) => err(&ord.name),
             },
             EnumType::FieldRef(f) => match f.value_for(raw.as_str()) {
                 Some(value) => Ok(ParsedInputValue::ScalarField(value.clone())),
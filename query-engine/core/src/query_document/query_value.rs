@@ -1,4 +1,4 @@
-use prisma_value::{stringify_date, PrismaValue};
+use prisma_value::{encode_bytes, stringify_date, PrismaValue};
 use rust_decimal::Decimal;
 use std::collections::BTreeMap;
 
@@ -36,6 +36,7 @@ impl From<PrismaValue> for QueryValue {
             PrismaValue::Null(_) => Self::Null,
             PrismaValue::Uuid(u) => Self::String(u.to_hyphenated().to_string()),
             PrismaValue::Json(s) => Self::String(s),
+            PrismaValue::Bytes(b) => Self::String(encode_bytes(&b)),
         }
     }
 }
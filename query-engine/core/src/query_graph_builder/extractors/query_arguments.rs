@@ -4,7 +4,9 @@ use crate::{
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
 use connector::QueryArguments;
-use prisma_models::{Field, ModelProjection, ModelRef, PrismaValue, RecordProjection, ScalarFieldRef};
+use prisma_models::{
+    Field, ModelProjection, ModelRef, OrderBy, OrderByAggregate, PrismaValue, RecordProjection, ScalarFieldRef,
+};
 use std::convert::TryInto;
 
 /// Expects the caller to know that it is structurally guaranteed that query arguments can be extracted,
@@ -31,10 +33,15 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
 
-                    "orderBy" => Ok(QueryArguments {
-                        order_by: Some(arg.value.try_into()?),
-                        ..res
-                    }),
+                    "orderBy" => {
+                        let (order_by, order_by_aggregate) = extract_order_by(arg.value)?;
+
+                        Ok(QueryArguments {
+                            order_by,
+                            order_by_aggregate,
+                            ..res
+                        })
+                    }
 
                     "distinct" => Ok(QueryArguments {
                         distinct: Some(extract_distinct(arg.value)?),
@@ -60,6 +67,38 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
         })
 }
 
+/// Orderings apply in sequence, the later ones breaking ties. The parser coerces a single ordering to
+/// a list of one.
+/// An aggregate ordering can only be the first one, as it is always applied before the field orderings.
+fn extract_order_by(value: ParsedInputValue) -> QueryGraphBuilderResult<(Vec<OrderBy>, Option<OrderByAggregate>)> {
+    let values = match value {
+        ParsedInputValue::List(list) => list,
+        value => vec![value],
+    };
+
+    let mut values = values.into_iter().peekable();
+    let order_by_aggregate = match values.peek() {
+        Some(ParsedInputValue::OrderByAggregate(order_by)) => {
+            let order_by = order_by.clone();
+            values.next();
+            Some(order_by)
+        }
+        _ => None,
+    };
+
+    let order_by = values
+        .map(|value| match value {
+            ParsedInputValue::OrderByAggregate(_) => Err(QueryGraphBuilderError::AssertionError(
+                "Invalid orderBy argument: Ordering by a relation aggregate is only allowed once, as the first ordering."
+                    .to_owned(),
+            )),
+            value => Ok(value.try_into()?),
+        })
+        .collect::<QueryGraphBuilderResult<Vec<OrderBy>>>()?;
+
+    Ok((order_by, order_by_aggregate))
+}
+
 fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelProjection> {
     let fields: Vec<Field> = match value {
         ParsedInputValue::List(list) => list
@@ -1,4 +1,4 @@
-use prisma_models::{InternalEnum, OrderBy, ScalarFieldRef};
+use prisma_models::{InternalEnum, OrderBy, OrderByAggregate, ScalarFieldRef};
 
 #[derive(Debug)]
 pub enum EnumType {
@@ -28,6 +28,9 @@ pub struct OrderByEnumType {
 
     /// E.g. id_ASC -> OrderBy(Id field, ASC sort order)
     pub values: Vec<(String, OrderBy)>,
+
+    /// E.g. posts_count_DESC -> OrderByAggregate(posts relation field, count, DESC sort order)
+    pub aggregate_values: Vec<(String, OrderByAggregate)>,
 }
 
 impl OrderByEnumType {
@@ -38,8 +41,19 @@ impl OrderByEnumType {
             .find_map(|val| if &val.0 == name { Some(&val.1) } else { None })
     }
 
+    /// Attempts to find an aggregate enum value for the given value key.
+    pub fn aggregate_value_for(&self, name: &str) -> Option<&OrderByAggregate> {
+        self.aggregate_values
+            .iter()
+            .find_map(|val| if &val.0 == name { Some(&val.1) } else { None })
+    }
+
     pub fn values(&self) -> Vec<String> {
-        self.values.iter().map(|(name, _)| name.to_owned()).collect()
+        self.values
+            .iter()
+            .map(|(name, _)| name.to_owned())
+            .chain(self.aggregate_values.iter().map(|(name, _)| name.to_owned()))
+            .collect()
     }
 }
 
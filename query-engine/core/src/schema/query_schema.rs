@@ -374,6 +374,10 @@ impl InputType {
     pub fn uuid() -> InputType {
         InputType::Scalar(ScalarType::UUID)
     }
+
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -426,6 +430,10 @@ impl OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
     /// Attempts to recurse through the type until an object type is found.
     /// Returns Some(ObjectTypeStrongRef) if ab object type is found, None otherwise.
     pub fn as_object_type(&self) -> Option<ObjectTypeStrongRef> {
@@ -466,6 +474,8 @@ pub enum ScalarType {
     Json,
     JsonList,
     UUID,
+    /// Base64 encoded on the wire.
+    Bytes,
 }
 
 impl From<&ScalarType> for TypeHint {
@@ -480,6 +490,7 @@ impl From<&ScalarType> for TypeHint {
             ScalarType::Json => TypeHint::Json,
             ScalarType::JsonList => TypeHint::Json,
             ScalarType::UUID => TypeHint::UUID,
+            ScalarType::Bytes => TypeHint::Bytes,
         }
     }
 }
@@ -122,6 +122,7 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
             TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Json => vec![&args.base],
+            TypeIdentifier::Bytes => vec![&args.base, &args.inclusion],
         },
     };
 
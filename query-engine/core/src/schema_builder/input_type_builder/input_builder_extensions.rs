@@ -15,6 +15,7 @@ pub trait InputBuilderExtensions {
             TypeIdentifier::UUID => InputType::uuid(),
             TypeIdentifier::DateTime => InputType::date_time(),
             TypeIdentifier::Json => InputType::json(),
+            TypeIdentifier::Bytes => InputType::bytes(),
             TypeIdentifier::Enum(_) => self.map_enum_input_type(&field),
         };
 
@@ -1,5 +1,5 @@
 use super::*;
-use prisma_models::OrderBy;
+use prisma_models::{AggregationKind, OrderBy, OrderByAggregate};
 
 #[derive(Debug)]
 pub struct ObjectTypeBuilder<'a> {
@@ -113,6 +113,7 @@ impl<'a> ObjectTypeBuilder<'a> {
                 TypeIdentifier::DateTime => OutputType::date_time(),
                 TypeIdentifier::UUID => OutputType::uuid(),
                 TypeIdentifier::Int => OutputType::int(),
+                TypeIdentifier::Bytes => OutputType::bytes(),
             },
         };
 
@@ -183,7 +184,7 @@ impl<'a> ObjectTypeBuilder<'a> {
         argument("where", InputType::opt(InputType::object(where_object)), None)
     }
 
-    // Builds "orderBy" argument.
+    // Builds "orderBy" argument. It takes a list of orderings, a single one is coerced to a list by the parser.
     pub fn order_by_argument(&self, model: &ModelRef) -> Argument {
         let enum_values: Vec<_> = model
             .fields()
@@ -211,10 +212,36 @@ impl<'a> ObjectTypeBuilder<'a> {
             .flatten()
             .collect();
 
+        // Ordering by the number of related records, e.g. posts_count_DESC, for to-many relations.
+        // Names taken by a scalar field value are skipped.
+        let aggregate_values: Vec<_> = model
+            .fields()
+            .relation()
+            .into_iter()
+            .filter(|field| field.is_list)
+            .map(|field| {
+                vec![SortOrder::Ascending, SortOrder::Descending]
+                    .into_iter()
+                    .map(move |sort_order| {
+                        (
+                            format!("{}_count_{}", field.name, sort_order.to_string()),
+                            OrderByAggregate {
+                                field: field.clone(),
+                                aggregation: AggregationKind::Count,
+                                sort_order,
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .flatten()
+            .filter(|(name, _)| !enum_values.iter().any(|(value, _)| value == name))
+            .collect();
+
         let enum_name = format!("{}OrderByInput", model.name);
-        let enum_type = order_by_enum_type(enum_name, enum_values);
+        let enum_type = order_by_enum_type(enum_name, enum_values, aggregate_values);
 
-        argument("orderBy", InputType::opt(enum_type.into()), None)
+        argument("orderBy", InputType::opt(InputType::list(enum_type.into())), None)
     }
 
     pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
@@ -88,6 +88,7 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -165,6 +166,7 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
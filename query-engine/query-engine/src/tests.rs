@@ -1,4 +1,12 @@
+mod csv_export;
+mod delete_in_batches;
 mod dmmf;
 mod execute_raw;
+mod exists;
+mod export_stream;
+mod isolation_level;
+mod order_by_aggregate;
+mod row_locking;
 mod test_api;
 mod type_mappings;
+mod upsert_raw;
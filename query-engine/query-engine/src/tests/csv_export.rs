@@ -0,0 +1,50 @@
+use super::test_api::*;
+use failure::Fail;
+use indoc::indoc;
+use quaint::{
+    ast::*,
+    connector::{Queryable, TransactionCapable},
+    single::Quaint,
+};
+use sql_connector::QueryExt;
+use test_macros::*;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id       Int     @id
+        name     String
+        nickname String?
+    }
+"};
+
+#[test_each_connector(tags("postgres"))]
+async fn csv_exports_have_a_header_and_quote_their_cells(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(&CAT).await?;
+
+    let url = api.datasource().url().value.clone();
+    let conn = Quaint::new(&url).await?;
+
+    conn.raw_cmd(r#"INSERT INTO "Cat" ("id", "name", "nickname") VALUES (1, 'Garfield', 'Lasagna, please'), (2, 'Tom', NULL), (3, 'Felix', '')"#)
+        .await?;
+
+    let select = Select::from_table((api.connection_info().schema_name(), "Cat"))
+        .columns(vec!["id", "name", "nickname"])
+        .order_by("id".ascend());
+
+    let tx = conn.start_transaction().await?;
+    let mut csv = Vec::new();
+    let count = tx.export_csv(select, &mut csv).await.map_err(Fail::compat)?;
+    tx.commit().await?;
+
+    let expected = indoc! {r#"
+        id,name,nickname
+        1,Garfield,"Lasagna, please"
+        2,Tom,
+        3,Felix,""
+    "#};
+
+    assert_eq!(count, 3);
+    assert_eq!(String::from_utf8(csv)?, expected);
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use super::test_api::*;
+use connector::ScalarCompare;
+use failure::Fail;
+use indoc::indoc;
+use prisma_models::DatamodelConverter;
+use quaint::{
+    ast::*,
+    connector::{Queryable, TransactionCapable},
+    single::Quaint,
+};
+use sql_connector::QueryExt;
+use test_macros::*;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id    Int    @id
+        color String
+    }
+"};
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn deleting_in_batches_deletes_all_matching_records(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(&CAT).await?;
+
+    let schema_name = api.connection_info().schema_name().to_owned();
+    let url = api.datasource().url().value.clone();
+    let conn = Quaint::new(&url).await?;
+
+    let insert = (1..=500).fold(
+        Insert::multi_into((schema_name.as_str(), "Cat"), &["id", "color"]),
+        |insert, id| insert.values((id, if id % 5 == 0 { "black" } else { "orange" })),
+    );
+
+    conn.insert(insert.into()).await?;
+
+    let internal_data_model = DatamodelConverter::convert_string(CAT.to_string()).build(schema_name.clone());
+    let cat = internal_data_model.find_model("Cat").unwrap();
+    let color = cat.fields().find_from_scalar("color").unwrap();
+
+    let tx = conn.start_transaction().await?;
+    let deleted = tx
+        .delete_in_batches(&cat, color.equals("orange"), 100)
+        .await
+        .map_err(Fail::compat)?;
+    tx.commit().await?;
+
+    assert_eq!(deleted, 400);
+
+    let remaining = conn
+        .select(Select::from_table((schema_name.as_str(), "Cat")).value(count(asterisk())))
+        .await?;
+
+    assert_eq!(
+        remaining.into_single()?.at(0).and_then(|value| value.as_i64()),
+        Some(100)
+    );
+
+    Ok(())
+}
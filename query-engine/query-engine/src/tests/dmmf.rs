@@ -120,6 +120,38 @@ fn must_not_fail_on_missing_env_vars_in_a_datasource() {
         .expect("finding BlogCreateInput");
 }
 
+#[test]
+#[serial]
+fn order_by_arguments_take_a_list_of_orderings() {
+    let dm = r#"
+        model Blog {
+            blogId String @id
+            title  String
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let find_many_blog = dmmf
+        .schema
+        .output_types
+        .iter()
+        .find(|output| output.name == dmmf.schema.root_query_type)
+        .and_then(|query| query.fields.iter().find(|field| field.name == "findManyBlog"))
+        .expect("finding findManyBlog");
+
+    let order_by = find_many_blog
+        .args
+        .iter()
+        .find(|arg| arg.name == "orderBy")
+        .expect("finding the orderBy argument");
+
+    assert_eq!(order_by.input_type.typ, "BlogOrderByInput");
+    assert!(order_by.input_type.is_list);
+}
+
 fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Datamodel) {
     feature_flags::initialize(&vec![String::from("all")]).unwrap();
 
@@ -204,6 +204,56 @@ async fn inserting_into_model_table(api: &TestApi) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn inserting_with_a_returning_clause_captures_the_returned_rows(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let insert = Insert::single_into("Todo").value("id", "id1").value("title", "title1");
+    let (query, params) = api.to_sql_string(insert)?;
+    let query = format!("{} RETURNING id", query);
+
+    assert_eq!(
+        json!({
+            "data": {
+                "executeRaw": {
+                    "count": 1,
+                    "rows": [{"id": "id1"}]
+                }
+            }
+        }),
+        query_engine.request(execute_raw(&query, params)).await,
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn updating_a_column_named_output_reports_the_affected_rows(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {"
+        model Setting {
+            id     String @id
+            output Int
+        }
+    "};
+    let query_engine = api.create_engine(dm).await?;
+
+    let insert = "INSERT INTO Setting (id, output) VALUES ('a', 1), ('b', 1)";
+    query_engine.request(execute_raw(insert, vec![])).await;
+
+    assert_eq!(
+        json!({
+            "data": {
+                "executeRaw": 2
+            }
+        }),
+        query_engine
+            .request(execute_raw("UPDATE Setting SET output = 2", vec![]))
+            .await,
+    );
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn querying_model_tables_with_alias(api: &TestApi) -> anyhow::Result<()> {
     let query_engine = api.create_engine(&TODO).await?;
@@ -320,3 +370,21 @@ async fn other_errors_bubbling_through_to_the_user(api: &TestApi) -> anyhow::Res
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn ddl_statements_report_zero_affected_rows(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    assert_eq!(
+        json!({
+            "data": {
+                "executeRaw": 0
+            }
+        }),
+        query_engine
+            .request(execute_raw("CREATE TABLE ddl_test (id INTEGER PRIMARY KEY)", vec![]))
+            .await,
+    );
+
+    Ok(())
+}
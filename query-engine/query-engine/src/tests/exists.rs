@@ -0,0 +1,49 @@
+use super::test_api::*;
+use connector::ScalarCompare;
+use failure::Fail;
+use indoc::indoc;
+use prisma_models::DatamodelConverter;
+use quaint::{
+    ast::*,
+    connector::{Queryable, TransactionCapable},
+    single::Quaint,
+};
+use sql_connector::QueryExt;
+use test_macros::*;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id    Int    @id
+        color String
+    }
+"};
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn exists_checks_whether_any_record_matches_the_filter(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(&CAT).await?;
+
+    let schema_name = api.connection_info().schema_name().to_owned();
+    let url = api.datasource().url().value.clone();
+    let conn = Quaint::new(&url).await?;
+
+    let insert = Insert::multi_into((schema_name.as_str(), "Cat"), &["id", "color"])
+        .values((1, "orange"))
+        .values((2, "black"))
+        .values((3, "black"));
+
+    conn.insert(insert.into()).await?;
+
+    let internal_data_model = DatamodelConverter::convert_string(CAT.to_string()).build(schema_name);
+    let cat = internal_data_model.find_model("Cat").unwrap();
+    let color = cat.fields().find_from_scalar("color").unwrap();
+
+    let tx = conn.start_transaction().await?;
+    let black_cat_exists = tx.exists(&cat, color.equals("black")).await.map_err(Fail::compat)?;
+    let white_cat_exists = tx.exists(&cat, color.equals("white")).await.map_err(Fail::compat)?;
+    tx.commit().await?;
+
+    assert!(black_cat_exists);
+    assert!(!white_cat_exists);
+
+    Ok(())
+}
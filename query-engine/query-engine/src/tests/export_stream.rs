@@ -0,0 +1,61 @@
+use super::test_api::*;
+use connector::{filter::Filter, Connector, QueryArguments, ReadOperations, ScalarCompare};
+use failure::Fail;
+use futures::stream::TryStreamExt;
+use indoc::indoc;
+use prisma_models::{DatamodelConverter, OrderBy, PrismaValue, SortOrder};
+use sql_connector::{FromSource, PostgreSql};
+use test_macros::*;
+
+static ITEM: &str = indoc! {"
+    model Item {
+        id Int @id
+    }
+"};
+
+#[test_each_connector(tags("postgres"))]
+async fn export_stream_yields_all_batches_in_order(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(&ITEM).await?;
+
+    let connector = PostgreSql::from_source(&api.datasource()).await.map_err(Fail::compat)?;
+    let conn = connector.get_connection().await.map_err(Fail::compat)?;
+
+    // More rows than fit in one `IN` batch, so the filter is split into several queries.
+    conn.execute_raw(
+        r#"INSERT INTO "Item" ("id") SELECT generate_series(1, 12000)"#.into(),
+        vec![],
+    )
+    .await
+    .map_err(Fail::compat)?;
+
+    let internal_data_model =
+        DatamodelConverter::convert_string(ITEM.to_string()).build(api.connection_info().schema_name().to_owned());
+    let model = internal_data_model.find_model("Item").unwrap();
+    let id = model.fields().find_from_scalar("id").unwrap();
+    let ids: Vec<i64> = (1..=12000).collect();
+
+    let filter: Filter = id.is_in(ids.clone());
+    assert!(filter.can_batch());
+
+    let args = QueryArguments {
+        filter: Some(filter),
+        order_by: vec![OrderBy {
+            field: id,
+            sort_order: SortOrder::Ascending,
+        }],
+        ..Default::default()
+    };
+    let selected_fields = model.primary_identifier();
+
+    let streamed: Vec<PrismaValue> = conn
+        .export_stream(&model, args, &selected_fields)
+        .map_ok(|record| record.values[0].clone())
+        .try_collect()
+        .await
+        .map_err(Fail::compat)?;
+
+    let expected: Vec<PrismaValue> = ids.into_iter().map(PrismaValue::from).collect();
+    assert_eq!(streamed, expected);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use super::test_api::*;
+use connector::{Connector, IsolationLevel};
+use failure::Fail;
+use indoc::indoc;
+use sql_connector::{FromSource, PostgreSql};
+use test_macros::*;
+
+static COUNTER: &str = indoc! {"
+    model Counter {
+        id String @id
+    }
+"};
+
+#[test_each_connector(tags("postgres"))]
+async fn serializable_transactions_fail_on_conflicting_concurrent_writes(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(&COUNTER).await?;
+
+    let connector = PostgreSql::from_source(&api.datasource()).await.map_err(Fail::compat)?;
+    let conn_a = connector.get_connection().await.map_err(Fail::compat)?;
+    let conn_b = connector.get_connection().await.map_err(Fail::compat)?;
+
+    let tx_a = conn_a
+        .start_transaction_with_isolation_level(IsolationLevel::Serializable)
+        .await
+        .map_err(Fail::compat)?;
+    let tx_b = conn_b
+        .start_transaction_with_isolation_level(IsolationLevel::Serializable)
+        .await
+        .map_err(Fail::compat)?;
+
+    // Both transactions decide what to write based on the same read, then write disjoint rows. This is fine under
+    // the default isolation level, but a write skew under serializable.
+    tx_a.query_raw(r#"SELECT COUNT(*) FROM "Counter""#.into(), vec![])
+        .await
+        .map_err(Fail::compat)?;
+    tx_b.query_raw(r#"SELECT COUNT(*) FROM "Counter""#.into(), vec![])
+        .await
+        .map_err(Fail::compat)?;
+
+    tx_a.execute_raw(r#"INSERT INTO "Counter" ("id") VALUES ('a')"#.into(), vec![])
+        .await
+        .map_err(Fail::compat)?;
+    tx_a.commit().await.map_err(Fail::compat)?;
+
+    let result = async {
+        tx_b.execute_raw(r#"INSERT INTO "Counter" ("id") VALUES ('b')"#.into(), vec![])
+            .await
+            .map_err(Fail::compat)?;
+        tx_b.commit().await.map_err(Fail::compat)
+    }
+    .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
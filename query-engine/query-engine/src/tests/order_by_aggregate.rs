@@ -0,0 +1,52 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static USERS_AND_POSTS: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+"};
+
+#[test_each_connector]
+async fn paging_with_a_cursor_ordered_by_a_relation_count(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USERS_AND_POSTS).await?;
+
+    let mutations = [
+        r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }, { id: 2 }] } }) { id } }"#,
+        r#"mutation { createOneUser(data: { id: 2 }) { id } }"#,
+        r#"mutation { createOneUser(data: { id: 3, posts: { create: [{ id: 3 }] } }) { id } }"#,
+        r#"mutation { createOneUser(data: { id: 4, posts: { create: [{ id: 4 }] } }) { id } }"#,
+    ];
+
+    for mutation in mutations.iter() {
+        query_engine.request(*mutation).await;
+    }
+
+    // Users 3 and 4 have the same number of posts, the identifier breaks the tie.
+    let query = r#"query { findManyUser(orderBy: posts_count_DESC, cursor: { id: 3 }, take: 2) { id } }"#;
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 3 }, { "id": 4 }] } }),
+        response
+    );
+
+    let query = r#"query { findManyUser(orderBy: posts_count_DESC, cursor: { id: 3 }, take: -2) { id } }"#;
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 1 }, { "id": 3 }] } }),
+        response
+    );
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use super::test_api::*;
+use datamodel::FieldArity;
+use failure::Fail;
+use indoc::indoc;
+use prisma_models::{PrismaValue, TypeIdentifier};
+use quaint::{
+    ast::*,
+    connector::{Queryable, TransactionCapable},
+    prelude::SqlFamily,
+    single::Quaint,
+};
+use sql_connector::{QueryExt, RowLock};
+use test_macros::*;
+
+static JOB: &str = indoc! {"
+    model Job {
+        id Int @id
+    }
+"};
+
+#[test_each_connector(tags("postgres"))]
+async fn skip_locked_reads_leave_out_the_rows_locked_by_other_transactions(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(&JOB).await?;
+
+    let url = api.datasource().url().value.clone();
+    let conn_a = Quaint::new(&url).await?;
+    let conn_b = Quaint::new(&url).await?;
+
+    conn_a.raw_cmd(r#"INSERT INTO "Job" ("id") VALUES (1), (2)"#).await?;
+
+    let next_job = || {
+        Select::from_table((api.connection_info().schema_name(), "Job"))
+            .column("id")
+            .order_by("id".ascend())
+            .limit(1)
+    };
+    let idents = [(TypeIdentifier::Int, FieldArity::Required)];
+
+    let tx_a = conn_a.start_transaction().await?;
+    let tx_b = conn_b.start_transaction().await?;
+
+    // The first worker locks the first job, so the second one picks the next job instead of waiting.
+    let job_a = tx_a
+        .filter_locked(next_job(), RowLock::ForUpdateSkipLocked, SqlFamily::Postgres, &idents)
+        .await
+        .map_err(Fail::compat)?;
+    let job_b = tx_b
+        .filter_locked(next_job(), RowLock::ForUpdateSkipLocked, SqlFamily::Postgres, &idents)
+        .await
+        .map_err(Fail::compat)?;
+
+    assert_eq!(job_a[0].values, vec![PrismaValue::Int(1)]);
+    assert_eq!(job_b[0].values, vec![PrismaValue::Int(2)]);
+
+    tx_a.commit().await?;
+    tx_b.commit().await?;
+
+    Ok(())
+}
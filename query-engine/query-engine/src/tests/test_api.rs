@@ -83,6 +83,11 @@ impl TestApi {
         &self.connection_info
     }
 
+    pub fn datasource(&self) -> datamodel::Datasource {
+        let config = datamodel::parse_configuration(&self.config).unwrap();
+        config.datasources.into_iter().next().unwrap()
+    }
+
     pub fn to_sql_string<'a>(&'a self, query: impl Into<Query<'a>>) -> quaint::Result<(String, Vec<Value>)> {
         match self.connection_info() {
             ConnectionInfo::Postgres(..) => visitor::Postgres::build(query),
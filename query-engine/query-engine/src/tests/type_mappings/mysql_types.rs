@@ -183,7 +183,7 @@ async fn mysql_types_roundtrip(api: &TestApi) -> TestResult {
             .assert_field_type("numeric_integer_tinyint", ScalarType::Int)?
             .assert_field_type("numeric_integer_smallint", ScalarType::Int)?
             .assert_field_type("numeric_integer_int", ScalarType::Int)?
-            .assert_field_type("numeric_integer_bigint", ScalarType::Int)?
+            .assert_field_type("numeric_integer_bigint", ScalarType::BigInt)?
             .assert_field_type("numeric_floating_decimal", ScalarType::Float)?
             .assert_field_type("numeric_floating_float", ScalarType::Float)?
             .assert_field_type("numeric_fixed_double", ScalarType::Float)?
@@ -195,13 +195,13 @@ async fn postgres_types_roundtrip(api: &TestApi) -> TestResult {
         model
             .assert_field_type("numeric_int2", ScalarType::Int)?
             .assert_field_type("numeric_int4", ScalarType::Int)?
-            .assert_field_type("numeric_int8", ScalarType::Int)?
+            .assert_field_type("numeric_int8", ScalarType::BigInt)?
             .assert_field_type("numeric_decimal", ScalarType::Float)?
             .assert_field_type("numeric_float4", ScalarType::Float)?
             .assert_field_type("numeric_float8", ScalarType::Float)?
             .assert_field_type("numeric_serial2", ScalarType::Int)?
             .assert_field_type("numeric_serial4", ScalarType::Int)?
-            .assert_field_type("numeric_serial8", ScalarType::Int)?
+            .assert_field_type("numeric_serial8", ScalarType::BigInt)?
             .assert_field_type("numeric_money", ScalarType::Float)?
             .assert_field_type("numeric_oid", ScalarType::Int)?
             .assert_field_type("string_char", ScalarType::String)?
@@ -444,7 +444,7 @@ async fn postgres_array_types_roundtrip(api: &TestApi) -> TestResult {
         model
             .assert_field_type("numeric_int2", ScalarType::Int)?
             .assert_field_type("numeric_int4", ScalarType::Int)?
-            .assert_field_type("numeric_int8", ScalarType::Int)?
+            .assert_field_type("numeric_int8", ScalarType::BigInt)?
             .assert_field_type("numeric_decimal", ScalarType::Float)?
             .assert_field_type("numeric_float4", ScalarType::Float)?
             .assert_field_type("numeric_float8", ScalarType::Float)?
@@ -0,0 +1,31 @@
+use super::test_api::*;
+use connector::{Connector, UpsertOutcome, WriteOperations};
+use failure::Fail;
+use indoc::indoc;
+use sql_connector::{FromSource, Mysql};
+use test_macros::*;
+
+static COUNTER: &str = indoc! {"
+    model Counter {
+        id    String @id
+        count Int
+    }
+"};
+
+#[test_each_connector(tags("mysql"))]
+async fn raw_upserts_report_whether_they_inserted_or_updated(api: &TestApi) -> anyhow::Result<()> {
+    api.create_engine(&COUNTER).await?;
+
+    let connector = Mysql::from_source(&api.datasource()).await.map_err(Fail::compat)?;
+    let conn = connector.get_connection().await.map_err(Fail::compat)?;
+
+    let upsert = "INSERT INTO `Counter` (`id`, `count`) VALUES ('a', 1) ON DUPLICATE KEY UPDATE `count` = `count` + 1";
+
+    let first = conn.upsert_raw(upsert.into(), vec![]).await.map_err(Fail::compat)?;
+    assert_eq!(first, UpsertOutcome::Inserted);
+
+    let second = conn.upsert_raw(upsert.into(), vec![]).await.map_err(Fail::compat)?;
+    assert_eq!(second, UpsertOutcome::Updated);
+
+    Ok(())
+}